@@ -0,0 +1,395 @@
+//! Offline batch conversion harness.
+//!
+//! Runs [`crate::convert::convert_vrm_to_gdb`] over a workload of VRM files,
+//! aggregating the results into one [`BatchReport`] — succeeded/failed
+//! counts, total vertices/polygons, total upload fee, and a histogram of
+//! every issue code seen across the run — and can diff a fresh report
+//! against a previously saved baseline to flag correctness regressions in a
+//! corpus of sample VRMs. Intended for a maintainer or CI to run offline
+//! (see `src/bin/convert_batch.rs`), not the interactive IPC path (c.f.
+//! [`crate::ipc::convert_vrm_batch_ipc`], which drives the same files from
+//! the Tauri frontend without aggregation or baseline diffing).
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::convert::{ConvertError, ConvertOptions, Severity, convert_vrm_to_gdb};
+
+/// How far a metric may drift above its baseline value before
+/// [`diff_batch_against_baseline`] flags it, expressed as a percentage.
+const DEFAULT_DRIFT_TOLERANCE_PERCENT: f64 = 5.0;
+
+/// One file to convert within a [`BatchWorkload`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchWorkloadItem {
+    pub input_path: String,
+    pub output_path: String,
+    /// Per-item options, overriding [`BatchWorkload::options`] for this file
+    /// alone. `None` converts with the workload's shared defaults.
+    #[serde(default)]
+    pub options: Option<ConvertOptions>,
+}
+
+/// A batch run's input: the shared [`ConvertOptions`] applied to every item
+/// unless it overrides them, and the list of files to convert.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchWorkload {
+    pub options: ConvertOptions,
+    pub items: Vec<BatchWorkloadItem>,
+}
+
+/// Per-file outcome within a [`BatchReport`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchItemOutcome {
+    pub input_path: String,
+    pub output_path: String,
+    pub report: Option<ConversionReportSnapshot>,
+    pub error: Option<ConvertError>,
+}
+
+/// The subset of a `ConversionReport` a [`BatchReport`] needs to keep
+/// around per item for baseline diffing, rather than the whole report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversionReportSnapshot {
+    pub bone_count: usize,
+    pub total_vertices: usize,
+    pub total_polygons: usize,
+    pub fee_after_resize_linden_dollar: u32,
+    pub issue_codes: Vec<(Severity, String)>,
+}
+
+/// Aggregate outcome of a [`convert_batch`] run, suitable for writing to a
+/// report JSON file and later re-loading as a baseline for
+/// [`diff_batch_against_baseline`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BatchReport {
+    pub succeeded_count: usize,
+    pub failed_count: usize,
+    pub total_vertices: usize,
+    pub total_polygons: usize,
+    pub total_fee_after_resize_linden_dollar: u64,
+    /// Count of every issue/error code seen across every item in the run,
+    /// successful or not.
+    pub issue_code_histogram: HashMap<String, usize>,
+    pub items: Vec<BatchItemOutcome>,
+}
+
+/// One regression flagged by [`diff_batch_against_baseline`].
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchRegressionFlag {
+    pub input_path: String,
+    pub kind: String,
+    pub detail: String,
+}
+
+/// Convert every item in `workload`, aggregating the results into one
+/// [`BatchReport`]. A single file's failure is recorded as that item's
+/// outcome and does not abort the rest of the run, unlike
+/// [`crate::bench::run_benchmark`], since a corpus run is exactly how a
+/// newly-broken file gets noticed in the first place.
+pub fn convert_batch(workload: &BatchWorkload) -> BatchReport {
+    let mut report = BatchReport::default();
+
+    for item in &workload.items {
+        let options = item
+            .options
+            .clone()
+            .unwrap_or_else(|| workload.options.clone());
+        let input_path = PathBuf::from(&item.input_path);
+        let output_path = PathBuf::from(&item.output_path);
+
+        let outcome = match convert_vrm_to_gdb(&input_path, &output_path, options, None, None) {
+            Ok(conversion_report) => {
+                report.succeeded_count += 1;
+                report.total_vertices += conversion_report.total_vertices;
+                report.total_polygons += conversion_report.total_polygons;
+                report.total_fee_after_resize_linden_dollar +=
+                    u64::from(conversion_report.fee_estimate.after_resize_linden_dollar);
+
+                let issue_codes: Vec<(Severity, String)> = conversion_report
+                    .issues
+                    .iter()
+                    .map(|issue| (issue.severity, issue.code.clone()))
+                    .collect();
+                for (_, code) in &issue_codes {
+                    *report.issue_code_histogram.entry(code.clone()).or_insert(0) += 1;
+                }
+
+                BatchItemOutcome {
+                    input_path: item.input_path.clone(),
+                    output_path: item.output_path.clone(),
+                    report: Some(ConversionReportSnapshot {
+                        bone_count: conversion_report.bone_count,
+                        total_vertices: conversion_report.total_vertices,
+                        total_polygons: conversion_report.total_polygons,
+                        fee_after_resize_linden_dollar: conversion_report
+                            .fee_estimate
+                            .after_resize_linden_dollar,
+                        issue_codes,
+                    }),
+                    error: None,
+                }
+            }
+            Err(error) => {
+                report.failed_count += 1;
+                let error = ConvertError::from(error);
+                *report
+                    .issue_code_histogram
+                    .entry(error.code.clone())
+                    .or_insert(0) += 1;
+
+                BatchItemOutcome {
+                    input_path: item.input_path.clone(),
+                    output_path: item.output_path.clone(),
+                    report: None,
+                    error: Some(error),
+                }
+            }
+        };
+
+        report.items.push(outcome);
+    }
+
+    report
+}
+
+/// Compare `current` against `baseline`, flagging any item present in both
+/// where conversion newly fails, a new Error-severity issue code appears, or
+/// `total_vertices`/`bone_count`/`fee_after_resize_linden_dollar` grows by
+/// more than `drift_tolerance_percent`. Items only present in one of the two
+/// runs are skipped rather than treated as an infinite regression.
+pub fn diff_batch_against_baseline(
+    baseline: &BatchReport,
+    current: &BatchReport,
+    drift_tolerance_percent: f64,
+) -> Vec<BatchRegressionFlag> {
+    let baseline_items: HashMap<&str, &BatchItemOutcome> = baseline
+        .items
+        .iter()
+        .map(|item| (item.input_path.as_str(), item))
+        .collect();
+
+    let mut flags = Vec::new();
+
+    for current_item in &current.items {
+        let Some(baseline_item) = baseline_items.get(current_item.input_path.as_str()) else {
+            continue;
+        };
+
+        let (Some(baseline_snapshot), Some(current_snapshot)) =
+            (&baseline_item.report, &current_item.report)
+        else {
+            if baseline_item.report.is_some() && current_item.report.is_none() {
+                flags.push(BatchRegressionFlag {
+                    input_path: current_item.input_path.clone(),
+                    kind: "NEWLY_FAILING".to_string(),
+                    detail: current_item
+                        .error
+                        .as_ref()
+                        .map(|error| error.message.clone())
+                        .unwrap_or_default(),
+                });
+            }
+            continue;
+        };
+
+        let baseline_error_codes: HashSet<&str> = baseline_snapshot
+            .issue_codes
+            .iter()
+            .filter(|(severity, _)| *severity == Severity::Error)
+            .map(|(_, code)| code.as_str())
+            .collect();
+        for (severity, code) in &current_snapshot.issue_codes {
+            if *severity == Severity::Error && !baseline_error_codes.contains(code.as_str()) {
+                flags.push(BatchRegressionFlag {
+                    input_path: current_item.input_path.clone(),
+                    kind: "NEW_ERROR_ISSUE".to_string(),
+                    detail: format!("new error issue: {code}"),
+                });
+            }
+        }
+
+        for (metric, baseline_value, current_value) in [
+            (
+                "fee_after_resize_linden_dollar",
+                f64::from(baseline_snapshot.fee_after_resize_linden_dollar),
+                f64::from(current_snapshot.fee_after_resize_linden_dollar),
+            ),
+            (
+                "total_vertices",
+                baseline_snapshot.total_vertices as f64,
+                current_snapshot.total_vertices as f64,
+            ),
+            (
+                "bone_count",
+                baseline_snapshot.bone_count as f64,
+                current_snapshot.bone_count as f64,
+            ),
+        ] {
+            if baseline_value <= 0.0 {
+                continue;
+            }
+            let percent_change = ((current_value - baseline_value) / baseline_value) * 100.0;
+            if percent_change > drift_tolerance_percent {
+                flags.push(BatchRegressionFlag {
+                    input_path: current_item.input_path.clone(),
+                    kind: format!("{}_INCREASED", metric.to_uppercase()),
+                    detail: format!(
+                        "{metric} drifted from {baseline_value:.1} to {current_value:.1} ({percent_change:+.1}%)"
+                    ),
+                });
+            }
+        }
+    }
+
+    flags
+}
+
+/// The drift tolerance [`diff_batch_against_baseline`] uses when a caller
+/// (e.g. `src/bin/convert_batch.rs`) doesn't specify one explicitly.
+pub fn default_drift_tolerance_percent() -> f64 {
+    DEFAULT_DRIFT_TOLERANCE_PERCENT
+}
+
+/// Load a [`BatchWorkload`] from a JSON file.
+pub fn load_workload(path: &Path) -> Result<BatchWorkload> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read batch workload: {}", path.display()))?;
+    serde_json::from_str(&content).context("failed to parse batch workload JSON")
+}
+
+/// Load a previously recorded [`BatchReport`] baseline from a JSON file.
+pub fn load_report(path: &Path) -> Result<BatchReport> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read batch report: {}", path.display()))?;
+    serde_json::from_str(&content).context("failed to parse batch report JSON")
+}
+
+/// Write a [`BatchReport`] run to a JSON file.
+pub fn save_report(path: &Path, report: &BatchReport) -> Result<()> {
+    let content = serde_json::to_string_pretty(report)
+        .context("failed to serialize batch report as JSON")?;
+    fs::write(path, content)
+        .with_context(|| format!("failed to write batch report: {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(
+        input_path: &str,
+        bone_count: usize,
+        total_vertices: usize,
+        fee: u32,
+        error_codes: &[&str],
+    ) -> BatchItemOutcome {
+        BatchItemOutcome {
+            input_path: input_path.to_string(),
+            output_path: format!("{input_path}.glb"),
+            report: Some(ConversionReportSnapshot {
+                bone_count,
+                total_vertices,
+                total_polygons: total_vertices / 2,
+                fee_after_resize_linden_dollar: fee,
+                issue_codes: error_codes
+                    .iter()
+                    .map(|code| (Severity::Error, code.to_string()))
+                    .collect(),
+            }),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn given_new_error_issue_when_diffing_against_baseline_then_it_is_flagged() {
+        let baseline = BatchReport {
+            items: vec![outcome("avatar.vrm", 60, 10_000, 10, &[])],
+            ..BatchReport::default()
+        };
+        let current = BatchReport {
+            items: vec![outcome(
+                "avatar.vrm",
+                60,
+                10_000,
+                10,
+                &["SKIN_EXPLOSION_SUSPECTED"],
+            )],
+            ..BatchReport::default()
+        };
+
+        let flags = diff_batch_against_baseline(&baseline, &current, DEFAULT_DRIFT_TOLERANCE_PERCENT);
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags[0].kind, "NEW_ERROR_ISSUE");
+    }
+
+    #[test]
+    fn given_vertex_count_drift_beyond_tolerance_when_diffing_then_it_is_flagged() {
+        let baseline = BatchReport {
+            items: vec![outcome("avatar.vrm", 60, 10_000, 10, &[])],
+            ..BatchReport::default()
+        };
+        let current = BatchReport {
+            items: vec![outcome("avatar.vrm", 60, 12_000, 10, &[])],
+            ..BatchReport::default()
+        };
+
+        let flags = diff_batch_against_baseline(&baseline, &current, DEFAULT_DRIFT_TOLERANCE_PERCENT);
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags[0].kind, "TOTAL_VERTICES_INCREASED");
+    }
+
+    #[test]
+    fn given_matching_run_when_diffing_against_baseline_then_nothing_is_flagged() {
+        let baseline = BatchReport {
+            items: vec![outcome("avatar.vrm", 60, 10_000, 10, &[])],
+            ..BatchReport::default()
+        };
+        let current = BatchReport {
+            items: vec![outcome("avatar.vrm", 60, 10_050, 10, &[])],
+            ..BatchReport::default()
+        };
+
+        let flags = diff_batch_against_baseline(&baseline, &current, DEFAULT_DRIFT_TOLERANCE_PERCENT);
+        assert!(flags.is_empty());
+    }
+
+    #[test]
+    fn given_file_missing_from_baseline_when_diffing_then_it_is_skipped() {
+        let baseline = BatchReport::default();
+        let current = BatchReport {
+            items: vec![outcome("new_avatar.vrm", 60, 10_000, 10, &[])],
+            ..BatchReport::default()
+        };
+
+        let flags = diff_batch_against_baseline(&baseline, &current, DEFAULT_DRIFT_TOLERANCE_PERCENT);
+        assert!(flags.is_empty());
+    }
+
+    #[test]
+    fn given_previously_succeeding_file_when_it_starts_failing_then_it_is_flagged() {
+        let baseline = BatchReport {
+            items: vec![outcome("avatar.vrm", 60, 10_000, 10, &[])],
+            ..BatchReport::default()
+        };
+        let current = BatchReport {
+            items: vec![BatchItemOutcome {
+                input_path: "avatar.vrm".to_string(),
+                output_path: "avatar.vrm.glb".to_string(),
+                report: None,
+                error: Some(ConvertError::new("CONVERSION_FAILED", "boom")),
+            }],
+            ..BatchReport::default()
+        };
+
+        let flags = diff_batch_against_baseline(&baseline, &current, DEFAULT_DRIFT_TOLERANCE_PERCENT);
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags[0].kind, "NEWLY_FAILING");
+    }
+}
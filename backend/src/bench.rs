@@ -0,0 +1,302 @@
+//! Offline conversion benchmark/regression harness.
+//!
+//! Runs [`crate::convert::convert_vrm_to_gdb`] over a manifest of workload
+//! VRM files, records wall-clock/size/fee metrics per model, and can diff a
+//! fresh run against a previously committed baseline to flag regressions
+//! beyond a threshold. Intended for a maintainer or CI to run offline (see
+//! `src/bin/convert_bench.rs`), not the interactive IPC path.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::convert::{ConvertOptions, convert_vrm_to_gdb};
+
+/// One workload entry in a [`BenchmarkManifest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkWorkload {
+    /// Stable identifier used to match this workload against a baseline run,
+    /// independent of `input_path` so moving the sample file doesn't break
+    /// the comparison.
+    pub name: String,
+    pub input_path: String,
+}
+
+/// A benchmark run's input: the shared [`ConvertOptions`] every workload is
+/// converted with, and the list of sample files to convert.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkManifest {
+    pub options: ConvertOptions,
+    pub workloads: Vec<BenchmarkWorkload>,
+}
+
+/// Metrics recorded for one workload in one benchmark run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkMetrics {
+    pub name: String,
+    pub duration_ms: u128,
+    pub output_bytes: u64,
+    pub output_texture_count: usize,
+    pub fee_after_resize_linden_dollar: u32,
+}
+
+/// A full benchmark run, suitable for writing to a results JSON file and
+/// later re-loading as a baseline for [`diff_against_baseline`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BenchmarkResults {
+    /// Free-form label describing why this run was taken (e.g. a release
+    /// tag or the change under test), carried through to the results JSON
+    /// so a saved baseline is still self-explanatory months later.
+    #[serde(default)]
+    pub reason: Option<String>,
+    pub metrics: Vec<BenchmarkMetrics>,
+}
+
+/// One metric that regressed beyond the configured threshold when comparing
+/// a fresh [`BenchmarkResults`] against a baseline.
+#[derive(Debug, Clone, Serialize)]
+pub struct RegressionFlag {
+    pub name: String,
+    pub metric: String,
+    pub baseline: f64,
+    pub current: f64,
+    pub percent_change: f64,
+}
+
+/// Convert every workload in `manifest` into `output_dir`, recording
+/// per-model metrics. A single failing workload aborts the whole run rather
+/// than silently skipping it, since a benchmark with missing data points
+/// can't be compared against a baseline. `reason` is carried through
+/// unchanged into the returned [`BenchmarkResults`].
+pub fn run_benchmark(
+    manifest: &BenchmarkManifest,
+    output_dir: &Path,
+    reason: Option<&str>,
+) -> Result<BenchmarkResults> {
+    fs::create_dir_all(output_dir)
+        .with_context(|| format!("failed to create output dir: {}", output_dir.display()))?;
+
+    let mut metrics = Vec::with_capacity(manifest.workloads.len());
+    for workload in &manifest.workloads {
+        let input_path = PathBuf::from(&workload.input_path);
+        let output_path = output_dir.join(format!("{}.glb", workload.name));
+
+        let started_at = Instant::now();
+        let report = convert_vrm_to_gdb(
+            &input_path,
+            &output_path,
+            manifest.options.clone(),
+            None,
+            None,
+        )
+        .with_context(|| format!("benchmark workload '{}' failed to convert", workload.name))?;
+        let duration_ms = started_at.elapsed().as_millis();
+
+        let output_bytes = fs::metadata(&output_path)
+            .with_context(|| format!("failed to stat output: {}", output_path.display()))?
+            .len();
+
+        metrics.push(BenchmarkMetrics {
+            name: workload.name.clone(),
+            duration_ms,
+            output_bytes,
+            output_texture_count: report.output_texture_infos.len(),
+            fee_after_resize_linden_dollar: report.fee_estimate.after_resize_linden_dollar,
+        });
+    }
+
+    Ok(BenchmarkResults {
+        reason: reason.map(ToOwned::to_owned),
+        metrics,
+    })
+}
+
+/// Build a [`BenchmarkWorkload`] list from every `.vrm` file directly inside
+/// `dir`, named after its file stem, so a benchmark run can point at a
+/// directory of sample models instead of hand-authoring a manifest. Entries
+/// are sorted by name for a deterministic run order.
+pub fn discover_workloads_from_dir(dir: &Path) -> Result<Vec<BenchmarkWorkload>> {
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("failed to read workload directory: {}", dir.display()))?;
+
+    let mut workloads: Vec<BenchmarkWorkload> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| ext.eq_ignore_ascii_case("vrm"))
+                .unwrap_or(false)
+        })
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_string();
+            Some(BenchmarkWorkload {
+                name,
+                input_path: path.to_string_lossy().into_owned(),
+            })
+        })
+        .collect();
+
+    workloads.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(workloads)
+}
+
+/// Compare `current` against `baseline`, flagging any workload present in
+/// both where a metric grew by more than `threshold_percent` — a drop (e.g.
+/// a smaller, faster, cheaper output) is never a regression and is not
+/// flagged. Workloads only present in one of the two runs are skipped
+/// rather than treated as an infinite regression.
+pub fn diff_against_baseline(
+    baseline: &BenchmarkResults,
+    current: &BenchmarkResults,
+    threshold_percent: f64,
+) -> Vec<RegressionFlag> {
+    let mut flags = Vec::new();
+
+    for current_metrics in &current.metrics {
+        let Some(baseline_metrics) = baseline
+            .metrics
+            .iter()
+            .find(|metrics| metrics.name == current_metrics.name)
+        else {
+            continue;
+        };
+
+        let comparisons: [(&str, f64, f64); 3] = [
+            (
+                "duration_ms",
+                baseline_metrics.duration_ms as f64,
+                current_metrics.duration_ms as f64,
+            ),
+            (
+                "output_bytes",
+                baseline_metrics.output_bytes as f64,
+                current_metrics.output_bytes as f64,
+            ),
+            (
+                "fee_after_resize_linden_dollar",
+                baseline_metrics.fee_after_resize_linden_dollar as f64,
+                current_metrics.fee_after_resize_linden_dollar as f64,
+            ),
+        ];
+
+        for (metric, baseline_value, current_value) in comparisons {
+            if baseline_value <= 0.0 {
+                continue;
+            }
+            let percent_change = ((current_value - baseline_value) / baseline_value) * 100.0;
+            if percent_change > threshold_percent {
+                flags.push(RegressionFlag {
+                    name: current_metrics.name.clone(),
+                    metric: metric.to_string(),
+                    baseline: baseline_value,
+                    current: current_value,
+                    percent_change,
+                });
+            }
+        }
+    }
+
+    flags
+}
+
+/// Load a [`BenchmarkManifest`] from a JSON file.
+pub fn load_manifest(path: &Path) -> Result<BenchmarkManifest> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read benchmark manifest: {}", path.display()))?;
+    serde_json::from_str(&content).context("failed to parse benchmark manifest JSON")
+}
+
+/// Load a previously recorded [`BenchmarkResults`] baseline from a JSON file.
+pub fn load_results(path: &Path) -> Result<BenchmarkResults> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read benchmark results: {}", path.display()))?;
+    serde_json::from_str(&content).context("failed to parse benchmark results JSON")
+}
+
+/// Write a [`BenchmarkResults`] run to a JSON file.
+pub fn save_results(path: &Path, results: &BenchmarkResults) -> Result<()> {
+    let content = serde_json::to_string_pretty(results)
+        .context("failed to serialize benchmark results as JSON")?;
+    fs::write(path, content)
+        .with_context(|| format!("failed to write benchmark results: {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(name: &str, duration_ms: u128, output_bytes: u64, fee: u32) -> BenchmarkMetrics {
+        BenchmarkMetrics {
+            name: name.to_string(),
+            duration_ms,
+            output_bytes,
+            output_texture_count: 1,
+            fee_after_resize_linden_dollar: fee,
+        }
+    }
+
+    #[test]
+    fn given_slower_run_when_diffing_against_baseline_then_duration_regression_is_flagged() {
+        let baseline = BenchmarkResults {
+            metrics: vec![metrics("avatar", 1000, 500_000, 10)],
+            ..BenchmarkResults::default()
+        };
+        let current = BenchmarkResults {
+            metrics: vec![metrics("avatar", 1400, 500_000, 10)],
+            ..BenchmarkResults::default()
+        };
+
+        let flags = diff_against_baseline(&baseline, &current, 30.0);
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags[0].metric, "duration_ms");
+    }
+
+    #[test]
+    fn given_faster_smaller_run_when_diffing_against_baseline_then_nothing_is_flagged() {
+        let baseline = BenchmarkResults {
+            metrics: vec![metrics("avatar", 1000, 500_000, 10)],
+            ..BenchmarkResults::default()
+        };
+        let current = BenchmarkResults {
+            metrics: vec![metrics("avatar", 800, 400_000, 8)],
+            ..BenchmarkResults::default()
+        };
+
+        let flags = diff_against_baseline(&baseline, &current, 10.0);
+        assert!(flags.is_empty());
+    }
+
+    #[test]
+    fn given_workload_missing_from_baseline_when_diffing_then_it_is_skipped() {
+        let baseline = BenchmarkResults::default();
+        let current = BenchmarkResults {
+            metrics: vec![metrics("new_avatar", 1000, 500_000, 10)],
+            ..BenchmarkResults::default()
+        };
+
+        let flags = diff_against_baseline(&baseline, &current, 10.0);
+        assert!(flags.is_empty());
+    }
+
+    #[test]
+    fn given_directory_of_vrm_files_when_discovering_workloads_then_names_are_derived_and_sorted() {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("vrm2sl-bench-discover-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("failed to create test directory");
+        fs::write(dir.join("zebra.vrm"), b"").expect("failed to write sample file");
+        fs::write(dir.join("avatar.vrm"), b"").expect("failed to write sample file");
+        fs::write(dir.join("notes.txt"), b"").expect("failed to write unrelated file");
+
+        let workloads = discover_workloads_from_dir(&dir).expect("discovery should succeed");
+
+        let _ = fs::remove_dir_all(&dir);
+        let names: Vec<&str> = workloads.iter().map(|workload| workload.name.as_str()).collect();
+        assert_eq!(names, vec!["avatar", "zebra"]);
+    }
+}
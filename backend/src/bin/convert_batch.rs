@@ -0,0 +1,89 @@
+//! Offline CLI entry point for the batch conversion regression harness.
+//!
+//! Not a Tauri command — run by a maintainer or CI outside the app:
+//!
+//! ```text
+//! convert_batch run <workload.json> <report.json>
+//! convert_batch diff <baseline.json> <current.json> [drift_tolerance_percent]
+//! ```
+
+use std::{path::PathBuf, process::ExitCode};
+
+use vrm2sl_tauri_lib::batch::{
+    convert_batch, default_drift_tolerance_percent, diff_batch_against_baseline, load_report,
+    load_workload, save_report,
+};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run_command(&args[1..]),
+        Some("diff") => diff_command(&args[1..]),
+        _ => Err(usage()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage() -> String {
+    "usage:\n  convert_batch run <workload.json> <report.json>\n  convert_batch diff <baseline.json> <current.json> [drift_tolerance_percent]".to_string()
+}
+
+fn run_command(args: &[String]) -> Result<(), String> {
+    let [workload_path, report_path] = args else {
+        return Err(usage());
+    };
+
+    let workload = load_workload(&PathBuf::from(workload_path)).map_err(|err| err.to_string())?;
+    let report = convert_batch(&workload);
+    save_report(&PathBuf::from(report_path), &report).map_err(|err| err.to_string())?;
+
+    println!(
+        "Converted {} file(s): {} succeeded, {} failed. Wrote report to {}",
+        report.succeeded_count + report.failed_count,
+        report.succeeded_count,
+        report.failed_count,
+        report_path
+    );
+    if report.failed_count > 0 {
+        return Err(format!("{} file(s) failed to convert", report.failed_count));
+    }
+    Ok(())
+}
+
+fn diff_command(args: &[String]) -> Result<(), String> {
+    let (baseline_path, current_path, drift_tolerance_percent) = match args {
+        [baseline_path, current_path] => {
+            (baseline_path, current_path, default_drift_tolerance_percent())
+        }
+        [baseline_path, current_path, drift_tolerance_percent] => {
+            let drift_tolerance_percent = drift_tolerance_percent
+                .parse::<f64>()
+                .map_err(|_| format!("invalid drift_tolerance_percent: {drift_tolerance_percent}"))?;
+            (baseline_path, current_path, drift_tolerance_percent)
+        }
+        _ => return Err(usage()),
+    };
+
+    let baseline = load_report(&PathBuf::from(baseline_path)).map_err(|err| err.to_string())?;
+    let current = load_report(&PathBuf::from(current_path)).map_err(|err| err.to_string())?;
+
+    let flags = diff_batch_against_baseline(&baseline, &current, drift_tolerance_percent);
+    if flags.is_empty() {
+        println!("No regressions beyond {drift_tolerance_percent}%");
+        return Ok(());
+    }
+
+    println!("{} regression(s) found:", flags.len());
+    for flag in &flags {
+        println!("  {} [{}]: {}", flag.input_path, flag.kind, flag.detail);
+    }
+    Err(format!("{} regression(s) found", flags.len()))
+}
@@ -0,0 +1,126 @@
+//! Offline CLI entry point for the conversion benchmark/regression harness.
+//!
+//! Not a Tauri command — run by a maintainer or CI outside the app:
+//!
+//! ```text
+//! convert_bench run <manifest.json> <output_dir> <results.json> [--reason <text>]
+//! convert_bench run-dir <input_dir> <output_dir> <results.json> [--reason <text>]
+//! convert_bench diff <baseline.json> <current.json> [threshold_percent]
+//! ```
+
+use std::{path::PathBuf, process::ExitCode};
+
+use vrm2sl_tauri_lib::bench::{
+    BenchmarkManifest, diff_against_baseline, discover_workloads_from_dir, load_manifest,
+    load_results, run_benchmark, save_results,
+};
+use vrm2sl_tauri_lib::convert::ConvertOptions;
+
+const DEFAULT_THRESHOLD_PERCENT: f64 = 20.0;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run_command(&args[1..]),
+        Some("run-dir") => run_dir_command(&args[1..]),
+        Some("diff") => diff_command(&args[1..]),
+        _ => Err(usage()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage() -> String {
+    "usage:\n  convert_bench run <manifest.json> <output_dir> <results.json> [--reason <text>]\n  convert_bench run-dir <input_dir> <output_dir> <results.json> [--reason <text>]\n  convert_bench diff <baseline.json> <current.json> [threshold_percent]".to_string()
+}
+
+/// Split a command's positional args from a trailing `--reason <text>` flag.
+fn split_reason_flag(args: &[String]) -> (&[String], Option<&str>) {
+    if args.len() >= 2 && args[args.len() - 2] == "--reason" {
+        (&args[..args.len() - 2], Some(args[args.len() - 1].as_str()))
+    } else {
+        (args, None)
+    }
+}
+
+fn run_command(args: &[String]) -> Result<(), String> {
+    let (positional, reason) = split_reason_flag(args);
+    let [manifest_path, output_dir, results_path] = positional else {
+        return Err(usage());
+    };
+
+    let manifest = load_manifest(&PathBuf::from(manifest_path)).map_err(|err| err.to_string())?;
+    let results = run_benchmark(&manifest, &PathBuf::from(output_dir), reason)
+        .map_err(|err| err.to_string())?;
+    save_results(&PathBuf::from(results_path), &results).map_err(|err| err.to_string())?;
+
+    println!(
+        "Benchmarked {} workload(s), wrote results to {}",
+        results.metrics.len(),
+        results_path
+    );
+    Ok(())
+}
+
+fn run_dir_command(args: &[String]) -> Result<(), String> {
+    let (positional, reason) = split_reason_flag(args);
+    let [input_dir, output_dir, results_path] = positional else {
+        return Err(usage());
+    };
+
+    let workloads =
+        discover_workloads_from_dir(&PathBuf::from(input_dir)).map_err(|err| err.to_string())?;
+    let manifest = BenchmarkManifest {
+        options: ConvertOptions::default(),
+        workloads,
+    };
+    let results = run_benchmark(&manifest, &PathBuf::from(output_dir), reason)
+        .map_err(|err| err.to_string())?;
+    save_results(&PathBuf::from(results_path), &results).map_err(|err| err.to_string())?;
+
+    println!(
+        "Benchmarked {} workload(s) from {}, wrote results to {}",
+        results.metrics.len(),
+        input_dir,
+        results_path
+    );
+    Ok(())
+}
+
+fn diff_command(args: &[String]) -> Result<(), String> {
+    let (baseline_path, current_path, threshold_percent) = match args {
+        [baseline_path, current_path] => (baseline_path, current_path, DEFAULT_THRESHOLD_PERCENT),
+        [baseline_path, current_path, threshold_percent] => {
+            let threshold_percent = threshold_percent
+                .parse::<f64>()
+                .map_err(|_| format!("invalid threshold_percent: {threshold_percent}"))?;
+            (baseline_path, current_path, threshold_percent)
+        }
+        _ => return Err(usage()),
+    };
+
+    let baseline = load_results(&PathBuf::from(baseline_path)).map_err(|err| err.to_string())?;
+    let current = load_results(&PathBuf::from(current_path)).map_err(|err| err.to_string())?;
+
+    let flags = diff_against_baseline(&baseline, &current, threshold_percent);
+    if flags.is_empty() {
+        println!("No regressions beyond {threshold_percent}%");
+        return Ok(());
+    }
+
+    println!("{} regression(s) beyond {threshold_percent}%:", flags.len());
+    for flag in &flags {
+        println!(
+            "  {} / {}: {:.1} -> {:.1} ({:+.1}%)",
+            flag.name, flag.metric, flag.baseline, flag.current, flag.percent_change
+        );
+    }
+    Err(format!("{} regression(s) found", flags.len()))
+}
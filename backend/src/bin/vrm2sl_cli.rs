@@ -0,0 +1,329 @@
+//! Standalone CLI entry point for analyzing/converting VRM files and
+//! managing project settings outside the Tauri app:
+//!
+//! ```text
+//! vrm2sl_cli analyze <input.vrm> [--target-height <cm>] [--manual-scale <factor>] [--resize-method <method>]
+//! vrm2sl_cli convert <input.vrm> <output.glb> [--target-height <cm>] [--manual-scale <factor>] [--resize-method <method>] [--load-settings <file>] [--save-settings <file>] [--report <file>]
+//! vrm2sl_cli settings show <settings.json>
+//! ```
+
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::{Parser, Subcommand};
+use vrm2sl_tauri_lib::convert::{ConvertOptions, analyze_vrm, convert_vrm_to_gdb};
+use vrm2sl_tauri_lib::notify::send_desktop_notification;
+use vrm2sl_tauri_lib::project::{ProjectSettings, load_project_settings, save_project_settings};
+use vrm2sl_tauri_lib::texture::{ResizeInterpolation, parse_resize_method};
+
+#[derive(Parser)]
+#[command(name = "vrm2sl_cli", about = "Analyze and convert VRM avatars for Second Life")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Analyze a VRM file and print a validation/diagnostic report.
+    Analyze {
+        input: PathBuf,
+        #[command(flatten)]
+        options: OptionArgs,
+    },
+    /// Convert a VRM file to a Second Life-oriented `.glb`.
+    Convert {
+        input: PathBuf,
+        output: PathBuf,
+        #[command(flatten)]
+        options: OptionArgs,
+        /// Write the full machine-readable conversion report as JSON.
+        #[arg(long)]
+        report: Option<PathBuf>,
+    },
+    /// Inspect a persisted project settings file.
+    Settings {
+        #[command(subcommand)]
+        action: SettingsAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum SettingsAction {
+    /// Print a project settings file as pretty JSON.
+    Show { path: PathBuf },
+}
+
+/// Conversion options shared by `analyze` and `convert`, with validators that
+/// reject non-physical inputs up front instead of surfacing them as a deep
+/// conversion failure.
+#[derive(clap::Args)]
+struct OptionArgs {
+    /// Target avatar height in centimeters (1-500). Defaults to 200 unless
+    /// `--load-settings` supplies a value and this flag is omitted.
+    #[arg(long, value_parser = parse_target_height_cm)]
+    target_height: Option<f32>,
+    /// Additional manual scale multiplier, must be greater than 0. Defaults
+    /// to 1.0 unless `--load-settings` supplies a value and this flag is
+    /// omitted.
+    #[arg(long, value_parser = parse_manual_scale)]
+    manual_scale: Option<f32>,
+    /// Texture resize interpolation: nearest, bilinear, bicubic, gaussian,
+    /// lanczos3. Defaults to bilinear unless `--load-settings` supplies a
+    /// value and this flag is omitted.
+    #[arg(long, value_parser = parse_resize_method)]
+    resize_method: Option<ResizeInterpolation>,
+    /// Load a saved project settings file as the base, overridden by any
+    /// other flag explicitly passed on the command line.
+    #[arg(long)]
+    load_settings: Option<PathBuf>,
+    /// Save the resulting options as a project settings file.
+    #[arg(long)]
+    save_settings: Option<PathBuf>,
+}
+
+fn parse_target_height_cm(value: &str) -> Result<f32, String> {
+    let height = value
+        .parse::<f32>()
+        .map_err(|_| format!("invalid target height '{value}': not a number"))?;
+    if !height.is_finite() || !(1.0..=500.0).contains(&height) {
+        return Err(format!(
+            "invalid target height '{value}': must be a finite value between 1 and 500 cm"
+        ));
+    }
+    Ok(height)
+}
+
+fn parse_manual_scale(value: &str) -> Result<f32, String> {
+    let scale = value
+        .parse::<f32>()
+        .map_err(|_| format!("invalid manual scale '{value}': not a number"))?;
+    if !scale.is_finite() || scale <= 0.0 {
+        return Err(format!(
+            "invalid manual scale '{value}': must be a finite value greater than 0"
+        ));
+    }
+    Ok(scale)
+}
+
+impl OptionArgs {
+    /// Build [`ConvertOptions`] from `--load-settings` (if given) overridden
+    /// by this invocation's flags, and write `--save-settings` (if given).
+    ///
+    /// `target_height`/`manual_scale`/`resize_method` are `Option`s with no
+    /// clap `default_value`, so a flag left off the command line is `None`
+    /// here and leaves the loaded (or, with no `--load-settings`, the
+    /// [`ProjectSettings::default`]) value in place rather than clobbering it
+    /// with a hardcoded default.
+    fn resolve(&self) -> Result<ConvertOptions, String> {
+        let mut settings = match &self.load_settings {
+            Some(path) => load_project_settings(path).map_err(|err| err.to_string())?,
+            None => ProjectSettings::default(),
+        };
+        if let Some(target_height) = self.target_height {
+            settings.target_height_cm = target_height;
+        }
+        if let Some(manual_scale) = self.manual_scale {
+            settings.manual_scale = manual_scale;
+        }
+        if let Some(resize_method) = self.resize_method {
+            settings.texture_resize_method = resize_method;
+        }
+
+        if let Some(path) = &self.save_settings {
+            save_project_settings(path, &settings).map_err(|err| err.to_string())?;
+        }
+
+        Ok(ConvertOptions {
+            target_height_cm: settings.target_height_cm,
+            manual_scale: settings.manual_scale,
+            texture_auto_resize: settings.texture_auto_resize,
+            texture_resize_method: settings.texture_resize_method,
+            enable_texture_atlas: settings.enable_texture_atlas,
+            bone_remap_rules: settings.bone_remap_rules,
+            ..ConvertOptions::default()
+        })
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Analyze { input, options } => analyze_command(&input, options),
+        Command::Convert { input, output, options, report } => {
+            convert_command(&input, &output, options, report.as_deref())
+        }
+        Command::Settings { action } => settings_command(action),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn analyze_command(input: &PathBuf, options: OptionArgs) -> Result<(), String> {
+    let convert_options = options.resolve()?;
+    let report =
+        analyze_vrm(input, convert_options, None, None).map_err(|err| err.to_string())?;
+
+    println!("Model: {}", report.model_name);
+    println!("Estimated height: {:.1} cm", report.estimated_height_cm);
+    println!("Bones: {}, meshes: {}", report.bone_count, report.mesh_count);
+    println!(
+        "Vertices: {}, polygons: {}",
+        report.total_vertices, report.total_polygons
+    );
+    if !report.missing_required_bones.is_empty() {
+        println!("Missing required bones: {}", report.missing_required_bones.join(", "));
+    }
+    for issue in &report.issues {
+        println!("  {}", issue.message);
+    }
+    if report.issues.iter().any(|issue| matches!(issue.severity, vrm2sl_tauri_lib::convert::Severity::Error)) {
+        return Err("analysis found one or more errors".to_string());
+    }
+    Ok(())
+}
+
+fn convert_command(
+    input: &PathBuf,
+    output: &PathBuf,
+    options: OptionArgs,
+    report_path: Option<&std::path::Path>,
+) -> Result<(), String> {
+    let convert_options = options.resolve()?;
+    let result = convert_vrm_to_gdb(input, output, convert_options, None, None);
+
+    // A batch run of large avatars can take long enough that the user has
+    // switched away from the terminal, so notify on both outcomes rather
+    // than only success.
+    let report = match result {
+        Ok(report) => {
+            let _ = send_desktop_notification("vrm2sl", &format!("Conversion of '{}' completed", input.display()));
+            report
+        }
+        Err(err) => {
+            let _ = send_desktop_notification("vrm2sl", &format!("Conversion of '{}' failed", input.display()));
+            return Err(err.to_string());
+        }
+    };
+
+    println!(
+        "Converted '{}' -> '{}' (scale factor {:.3})",
+        report.model_name,
+        output.display(),
+        report.computed_scale_factor
+    );
+
+    if let Some(report_path) = report_path {
+        let content = serde_json::to_string_pretty(&report)
+            .map_err(|err| format!("failed to serialize conversion report: {err}"))?;
+        std::fs::write(report_path, content)
+            .map_err(|err| format!("failed to write conversion report: {err}"))?;
+    }
+    Ok(())
+}
+
+fn settings_command(action: SettingsAction) -> Result<(), String> {
+    match action {
+        SettingsAction::Show { path } => {
+            let settings = load_project_settings(&path).map_err(|err| err.to_string())?;
+            let content = serde_json::to_string_pretty(&settings)
+                .map_err(|err| format!("failed to serialize project settings: {err}"))?;
+            println!("{content}");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_boundary_values_when_parsing_target_height_then_range_is_enforced() {
+        assert_eq!(parse_target_height_cm("1.0"), Ok(1.0));
+        assert_eq!(parse_target_height_cm("500.0"), Ok(500.0));
+        assert!(parse_target_height_cm("0.999").is_err());
+        assert!(parse_target_height_cm("500.001").is_err());
+        assert!(parse_target_height_cm("not-a-number").is_err());
+        assert!(parse_target_height_cm("nan").is_err());
+        assert!(parse_target_height_cm("inf").is_err());
+    }
+
+    #[test]
+    fn given_boundary_values_when_parsing_manual_scale_then_only_positive_finite_values_are_accepted()
+     {
+        assert_eq!(parse_manual_scale("1.0"), Ok(1.0));
+        assert_eq!(parse_manual_scale("0.001"), Ok(0.001));
+        assert!(parse_manual_scale("0.0").is_err());
+        assert!(parse_manual_scale("-1.0").is_err());
+        assert!(parse_manual_scale("not-a-number").is_err());
+        assert!(parse_manual_scale("nan").is_err());
+        assert!(parse_manual_scale("inf").is_err());
+    }
+
+    #[test]
+    fn given_loaded_settings_when_resolving_then_explicit_command_line_flags_take_precedence() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("vrm2sl-cli-test-resolve-explicit-{}.json", std::process::id()));
+
+        let loaded = ProjectSettings {
+            target_height_cm: 150.0,
+            manual_scale: 2.0,
+            texture_resize_method: ResizeInterpolation::Nearest,
+            ..ProjectSettings::default()
+        };
+        save_project_settings(&path, &loaded).expect("failed to save project settings");
+
+        let options = OptionArgs {
+            target_height: Some(180.0),
+            manual_scale: Some(1.5),
+            resize_method: Some(ResizeInterpolation::Lanczos3),
+            load_settings: Some(path.clone()),
+            save_settings: None,
+        };
+        let resolved = options.resolve();
+
+        let _ = std::fs::remove_file(&path);
+        let resolved = resolved.expect("resolve should succeed");
+
+        assert_eq!(resolved.target_height_cm, 180.0);
+        assert_eq!(resolved.manual_scale, 1.5);
+        assert_eq!(resolved.texture_resize_method, ResizeInterpolation::Lanczos3);
+    }
+
+    #[test]
+    fn given_loaded_settings_when_flags_are_omitted_then_loaded_values_are_preserved() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("vrm2sl-cli-test-resolve-omitted-{}.json", std::process::id()));
+
+        let loaded = ProjectSettings {
+            target_height_cm: 150.0,
+            manual_scale: 2.0,
+            texture_resize_method: ResizeInterpolation::Nearest,
+            ..ProjectSettings::default()
+        };
+        save_project_settings(&path, &loaded).expect("failed to save project settings");
+
+        let options = OptionArgs {
+            target_height: None,
+            manual_scale: None,
+            resize_method: None,
+            load_settings: Some(path.clone()),
+            save_settings: None,
+        };
+        let resolved = options.resolve();
+
+        let _ = std::fs::remove_file(&path);
+        let resolved = resolved.expect("resolve should succeed");
+
+        assert_eq!(resolved.target_height_cm, 150.0);
+        assert_eq!(resolved.manual_scale, 2.0);
+        assert_eq!(resolved.texture_resize_method, ResizeInterpolation::Nearest);
+    }
+}
@@ -0,0 +1,78 @@
+//! Cooperative cancellation for long-running conversion commands.
+//!
+//! A conversion checks its [`CancellationToken`] at safe checkpoints (skin
+//! boundaries in the skinning passes) rather than being forcibly aborted
+//! mid-write, so a cancelled conversion never leaves a partially-written
+//! output file behind. Tokens are registered here by request id so a
+//! separate `cancel_conversion_command` invocation, which has no other
+//! handle on the in-flight command, can still signal it.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex, OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// Shared cancellation flag. Cloning shares the same underlying flag, so the
+/// registry and the in-flight conversion can each hold their own handle to
+/// it.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicBool::new(false)))
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+fn registry() -> &'static Mutex<HashMap<String, CancellationToken>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, CancellationToken>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Register a fresh token for `request_id`, overwriting any stale token left
+/// behind by an earlier request that reused the same id without calling
+/// [`unregister`] (e.g. after a crash).
+pub fn register(request_id: &str) -> CancellationToken {
+    let token = CancellationToken::new();
+    registry()
+        .lock()
+        .expect("cancellation token registry poisoned")
+        .insert(request_id.to_string(), token.clone());
+    token
+}
+
+/// Signal cancellation for `request_id`. Returns `false` if no conversion is
+/// currently registered under that id (e.g. it already finished).
+pub fn cancel(request_id: &str) -> bool {
+    match registry()
+        .lock()
+        .expect("cancellation token registry poisoned")
+        .get(request_id)
+    {
+        Some(token) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Remove `request_id`'s token once its conversion has finished, successfully
+/// or not, so the registry doesn't grow unbounded across a long session.
+pub fn unregister(request_id: &str) {
+    registry()
+        .lock()
+        .expect("cancellation token registry poisoned")
+        .remove(request_id);
+}
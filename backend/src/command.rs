@@ -2,29 +2,61 @@
 //!
 //! This module contains all Tauri commands that can be invoked from the frontend.
 
-use tauri::AppHandle;
+use std::collections::HashMap;
+
+use tauri::{AppHandle, Emitter};
 use vrm2sl_tauri_lib::{
     LogLevel,
-    convert::{AnalysisReport, ConversionReport},
+    convert::{
+        AnalysisReport, ConversionReport, ConversionStage, ConvertError, SkinDumpReport,
+        SkinRepairStats, ValidationIssue,
+    },
     ipc::{
-        AnalyzeRequest, ConvertRequest, LoadSettingsRequest, SaveSettingsRequest, analyze_vrm_ipc,
-        convert_vrm_to_gdb_ipc, load_project_settings_ipc, save_project_settings_ipc,
+        AnalyzeRequest, BatchConversionOutcome, ConvertBatchRequest, ConvertRequest,
+        LoadSettingsRequest, RepairSkinningRequest, SaveSettingsRequest, SkinDiagnosticsRequest,
+        analyze_vrm_ipc, cancel_conversion_ipc, convert_vrm_batch_ipc, convert_vrm_to_gdb_ipc,
+        dump_bone_map_ipc, dump_skinning_ipc, load_project_settings_ipc, repair_skinning_ipc,
+        save_project_settings_ipc, validate_skinning_ipc,
     },
     project::ProjectSettings,
     send_log_with_handle,
 };
 
+/// Emit a `convert-progress` event for one file's stage/fraction within a
+/// (possibly single-file) conversion, mirroring `send_log_with_handle`'s
+/// best-effort-emit-and-log-failure pattern.
+fn emit_conversion_progress(
+    app: &AppHandle,
+    file_index: usize,
+    total_files: usize,
+    stage: ConversionStage,
+    fraction: f32,
+) {
+    let payload = serde_json::json!({
+        "file_index": file_index,
+        "total_files": total_files,
+        "stage": stage,
+        "fraction": fraction,
+    });
+    if let Err(err) = app.emit("convert-progress", &payload) {
+        eprintln!("Failed to send convert-progress event: {}", err);
+    }
+}
+
 #[tauri::command]
 pub async fn analyze_vrm_command(
     request: AnalyzeRequest,
     app: AppHandle,
-) -> Result<AnalysisReport, String> {
+) -> Result<AnalysisReport, ConvertError> {
     send_log_with_handle(
         &app,
         LogLevel::Info,
         &format!("Analyze VRM request: {}", request.input_path),
     );
-    let result = analyze_vrm_ipc(request);
+    let mut on_progress = |stage: ConversionStage, fraction: f32| {
+        emit_conversion_progress(&app, 0, 1, stage, fraction)
+    };
+    let result = analyze_vrm_ipc(request, Some(&mut on_progress));
     if result.is_ok() {
         send_log_with_handle(&app, LogLevel::Info, "Analyze VRM completed");
     }
@@ -44,7 +76,7 @@ pub async fn get_app_version() -> Result<String, String> {
 pub async fn convert_vrm_command(
     request: ConvertRequest,
     app: AppHandle,
-) -> Result<ConversionReport, String> {
+) -> Result<ConversionReport, ConvertError> {
     send_log_with_handle(
         &app,
         LogLevel::Info,
@@ -53,13 +85,117 @@ pub async fn convert_vrm_command(
             request.input_path, request.output_path
         ),
     );
-    let result = convert_vrm_to_gdb_ipc(request);
+    let mut on_progress = |stage: ConversionStage, fraction: f32| {
+        emit_conversion_progress(&app, 0, 1, stage, fraction)
+    };
+    let result = convert_vrm_to_gdb_ipc(request, Some(&mut on_progress));
     if result.is_ok() {
         send_log_with_handle(&app, LogLevel::Info, "Convert VRM completed");
     }
     result
 }
 
+#[tauri::command]
+pub async fn cancel_conversion_command(request_id: String, app: AppHandle) -> bool {
+    let cancelled = cancel_conversion_ipc(&request_id);
+    send_log_with_handle(
+        &app,
+        LogLevel::Info,
+        &format!("Cancel conversion request: {request_id} (found: {cancelled})"),
+    );
+    cancelled
+}
+
+#[tauri::command]
+pub async fn convert_vrm_batch_command(
+    request: ConvertBatchRequest,
+    app: AppHandle,
+) -> Result<Vec<BatchConversionOutcome>, ConvertError> {
+    send_log_with_handle(
+        &app,
+        LogLevel::Info,
+        &format!("Convert VRM batch request: {} file(s)", request.files.len()),
+    );
+    let mut on_progress =
+        |file_index: usize, total_files: usize, stage: ConversionStage, fraction: f32| {
+            emit_conversion_progress(&app, file_index, total_files, stage, fraction)
+        };
+    let outcomes = convert_vrm_batch_ipc(request, Some(&mut on_progress));
+    send_log_with_handle(&app, LogLevel::Info, "Convert VRM batch completed");
+    Ok(outcomes)
+}
+
+#[tauri::command]
+pub async fn validate_skinning_command(
+    request: SkinDiagnosticsRequest,
+    app: AppHandle,
+) -> Result<Vec<ValidationIssue>, ConvertError> {
+    send_log_with_handle(
+        &app,
+        LogLevel::Info,
+        &format!("Validate skinning request: {}", request.input_path),
+    );
+    let result = validate_skinning_ipc(request);
+    if result.is_ok() {
+        send_log_with_handle(&app, LogLevel::Info, "Validate skinning completed");
+    }
+    result
+}
+
+#[tauri::command]
+pub async fn repair_skinning_command(
+    request: RepairSkinningRequest,
+    app: AppHandle,
+) -> Result<SkinRepairStats, ConvertError> {
+    send_log_with_handle(
+        &app,
+        LogLevel::Info,
+        &format!(
+            "Repair skinning request: {} -> {}",
+            request.input_path, request.output_path
+        ),
+    );
+    let result = repair_skinning_ipc(request);
+    if result.is_ok() {
+        send_log_with_handle(&app, LogLevel::Info, "Repair skinning completed");
+    }
+    result
+}
+
+#[tauri::command]
+pub async fn dump_skinning_command(
+    request: SkinDiagnosticsRequest,
+    app: AppHandle,
+) -> Result<SkinDumpReport, ConvertError> {
+    send_log_with_handle(
+        &app,
+        LogLevel::Info,
+        &format!("Dump skinning request: {}", request.input_path),
+    );
+    let result = dump_skinning_ipc(request);
+    if result.is_ok() {
+        send_log_with_handle(&app, LogLevel::Info, "Dump skinning completed");
+    }
+    result
+}
+
+#[tauri::command]
+pub async fn dump_bone_map_command(
+    request: SkinDiagnosticsRequest,
+    app: AppHandle,
+) -> Result<HashMap<String, String>, ConvertError> {
+    send_log_with_handle(
+        &app,
+        LogLevel::Info,
+        &format!("Dump bone map request: {}", request.input_path),
+    );
+    let result = dump_bone_map_ipc(request);
+    if result.is_ok() {
+        send_log_with_handle(&app, LogLevel::Info, "Dump bone map completed");
+    }
+    result
+}
+
 #[tauri::command]
 pub async fn save_project_settings_command(
     request: SaveSettingsRequest,
@@ -0,0 +1,138 @@
+//! Compiled, user-configurable bone-remapping rules consulted by
+//! [`super::skinning::rebake_skin_weights_for_sl_compliance`] in place of
+//! always collapsing an unmapped VRM bone's weight onto its nearest mapped
+//! SL ancestor. Rules are compiled once per conversion into a
+//! `node_idx -> RemapTarget` table before the per-skin rebake loop runs, so
+//! the hot per-vertex path only does a `HashMap` lookup, never a name match.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde_json::Value;
+
+use super::profile::SkeletonProfile;
+use super::types::{BoneRemapAction, BoneRemapMatcher, BoneRemapRule};
+
+enum CompiledMatcher {
+    Exact(String),
+    Prefix(String),
+    Regex(Regex),
+}
+
+impl CompiledMatcher {
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            CompiledMatcher::Exact(expected) => name == expected,
+            CompiledMatcher::Prefix(prefix) => name.starts_with(prefix.as_str()),
+            CompiledMatcher::Regex(regex) => regex.is_match(name),
+        }
+    }
+}
+
+/// A [`BoneRemapRule`] with its matcher pre-compiled, e.g. a
+/// [`BoneRemapMatcher::Regex`] pattern built into a [`Regex`] once rather
+/// than on every node it's tested against.
+pub(super) struct CompiledRule {
+    matcher: CompiledMatcher,
+    action: BoneRemapAction,
+}
+
+/// The resolved remap policy for a node outside the mapped SL skeleton, as
+/// decided by [`build_remap_target_table`].
+pub(super) enum RemapTarget {
+    /// Bind onto this node's joint slot in the active skin, falling back to
+    /// [`RemapTarget::CollapseToAncestor`] when the node isn't one of the
+    /// skin's `joints`.
+    MapToNode(usize),
+    /// Re-bind onto the node's nearest mapped-SL ancestor.
+    CollapseToAncestor,
+    /// Drop the influence entirely.
+    Drop,
+}
+
+/// Compile the user's rule list once, failing fast on an invalid regex
+/// pattern instead of on the first vertex that happens to hit it.
+pub(super) fn compile_rules(rules: &[BoneRemapRule]) -> Result<Vec<CompiledRule>> {
+    rules
+        .iter()
+        .map(|rule| {
+            let matcher = match &rule.matcher {
+                BoneRemapMatcher::Exact(name) => CompiledMatcher::Exact(name.clone()),
+                BoneRemapMatcher::Prefix(prefix) => CompiledMatcher::Prefix(prefix.clone()),
+                BoneRemapMatcher::Regex(pattern) => CompiledMatcher::Regex(
+                    Regex::new(pattern)
+                        .with_context(|| format!("invalid bone remap rule regex: {}", pattern))?,
+                ),
+            };
+            Ok(CompiledRule {
+                matcher,
+                action: rule.action.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Resolve a [`BoneRemapAction::MapTo`] target SL bone name to a node index:
+/// mapped humanoid bones first, then spring/secondary bones, so a rule can
+/// redirect weight onto any bone the active profile knows about.
+fn resolve_sl_bone_node(
+    sl_bone_name: &str,
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    secondary_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
+) -> Option<usize> {
+    profile
+        .bone_pairs()
+        .find(|(_, target)| *target == sl_bone_name)
+        .and_then(|(vrm_name, _)| humanoid_bone_nodes.get(vrm_name).copied())
+        .or_else(|| {
+            profile
+                .secondary_bone_pairs()
+                .find(|(_, target)| *target == sl_bone_name)
+                .and_then(|(vrm_name, _)| secondary_bone_nodes.get(vrm_name).copied())
+        })
+}
+
+/// Precompute a `node_idx -> RemapTarget` table by evaluating the compiled
+/// rule list against every node's name once, up front. A node matching no
+/// rule defaults to [`RemapTarget::CollapseToAncestor`], preserving the
+/// behavior every unmapped bone got before rules existed.
+pub(super) fn build_remap_target_table(
+    json: &Value,
+    compiled_rules: &[CompiledRule],
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    secondary_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
+) -> HashMap<usize, RemapTarget> {
+    let Some(nodes) = json.get("nodes").and_then(Value::as_array) else {
+        return HashMap::new();
+    };
+
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(node_index, node)| {
+            let name = node.get("name").and_then(Value::as_str).unwrap_or("");
+            let action = compiled_rules
+                .iter()
+                .find(|rule| rule.matcher.matches(name))
+                .map(|rule| &rule.action);
+            let target = match action {
+                None | Some(BoneRemapAction::CollapseToAncestor) => {
+                    RemapTarget::CollapseToAncestor
+                }
+                Some(BoneRemapAction::Drop) => RemapTarget::Drop,
+                Some(BoneRemapAction::MapTo(sl_bone_name)) => resolve_sl_bone_node(
+                    sl_bone_name,
+                    humanoid_bone_nodes,
+                    secondary_bone_nodes,
+                    profile,
+                )
+                .map(RemapTarget::MapToNode)
+                .unwrap_or(RemapTarget::CollapseToAncestor),
+            };
+            (node_index, target)
+        })
+        .collect()
+}
@@ -0,0 +1,423 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use nalgebra::{Matrix4, Quaternion, UnitQuaternion, Vector3};
+use serde_json::Value;
+
+use super::gltf_utils::{
+    AccessorMeta, accessor_meta, collect_parent_index_map_from_json, read_f32_element,
+};
+use super::skeleton::{SkeletonPose, nearest_mapped_ancestor};
+use super::types::{BENTO_BONE_MAP, BONE_MAP};
+
+/// Frames per second baked into the `MOTION` block when the source VRM
+/// carries animation samplers. Arbitrary but generous for SL viewer playback;
+/// this crate has no existing animation-rate convention to match.
+const BVH_SAMPLE_RATE_HZ: f32 = 30.0;
+
+pub(super) fn bvh_path_for_output(output_path: &Path) -> PathBuf {
+    output_path.with_extension("bvh")
+}
+
+struct JointNode {
+    node_index: usize,
+    name: String,
+    children: Vec<JointNode>,
+}
+
+/// Write the converted, SL-named skeleton (rooted at `mPelvis`) out as a BVH
+/// file alongside the converted `.glb`, for import into SL's animation
+/// uploader. Must run before [`super::validation::remove_unsupported_features`]
+/// strips `animations` from `json`, and after the bind pose has been
+/// finalized (rotation-normalized, scale-baked) so the rest-pose `OFFSET`s
+/// match the shipped mesh.
+///
+/// Animation channels, when present, are sampled directly from the source
+/// VRM's authored bone-local rotation curves: this is a best-effort bake,
+/// not a full retarget onto the zero-rotation SL rest pose reconstructed
+/// below, so baked motion may drift slightly from the original VRM clip.
+pub(super) fn export_skeleton_to_bvh(
+    json: &Value,
+    bin: &[u8],
+    output_path: &Path,
+    humanoid_bone_nodes: &HashMap<String, usize>,
+) -> Result<()> {
+    let sl_node_indices: HashSet<usize> = BONE_MAP
+        .iter()
+        .chain(BENTO_BONE_MAP.iter())
+        .filter_map(|(source, _)| humanoid_bone_nodes.get(*source).copied())
+        .collect();
+    let parent_of = collect_parent_index_map_from_json(json);
+
+    let mut sl_names = HashMap::<usize, String>::new();
+    let mut sl_children = HashMap::<usize, Vec<usize>>::new();
+    let mut root_index = None;
+    for (source, target) in BONE_MAP.iter().chain(BENTO_BONE_MAP.iter()) {
+        let Some(node_index) = humanoid_bone_nodes.get(*source).copied() else {
+            continue;
+        };
+        sl_names.insert(node_index, target.to_string());
+        match nearest_mapped_ancestor(node_index, &parent_of, &sl_node_indices) {
+            Some(parent_index) => sl_children.entry(parent_index).or_default().push(node_index),
+            None => root_index = Some(node_index),
+        }
+    }
+    let Some(root_index) = root_index else {
+        bail!("no mapped SL skeleton root (mPelvis) to export as BVH");
+    };
+
+    let tree = build_joint_tree(root_index, &sl_names, &sl_children);
+
+    let mut pose = SkeletonPose::from_json(json);
+    let mut order = Vec::new();
+    let mut hierarchy = String::from("HIERARCHY\n");
+    write_joint_block(&tree, None, &mut pose, 0, &mut hierarchy, &mut order);
+
+    let curves = collect_node_curves(json, bin, &sl_node_indices);
+    let motion = build_motion_block(&order, &curves, &mut pose);
+
+    let mut bvh = hierarchy;
+    bvh.push_str(&motion);
+
+    fs::write(output_path, bvh)
+        .with_context(|| format!("failed to write BVH file: {}", output_path.display()))?;
+    Ok(())
+}
+
+fn build_joint_tree(
+    node_index: usize,
+    sl_names: &HashMap<usize, String>,
+    sl_children: &HashMap<usize, Vec<usize>>,
+) -> JointNode {
+    let children = sl_children
+        .get(&node_index)
+        .map(|kids| {
+            kids.iter()
+                .map(|&child| build_joint_tree(child, sl_names, sl_children))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    JointNode {
+        node_index,
+        name: sl_names
+            .get(&node_index)
+            .cloned()
+            .unwrap_or_else(|| format!("node{node_index}")),
+        children,
+    }
+}
+
+/// Recursively emit one `ROOT`/`JOINT`/`End Site` block and record `order`:
+/// the depth-first node visitation order the `MOTION` block's per-frame
+/// channel values must follow.
+fn write_joint_block(
+    joint: &JointNode,
+    parent_index: Option<usize>,
+    pose: &mut SkeletonPose,
+    depth: usize,
+    out: &mut String,
+    order: &mut Vec<usize>,
+) {
+    let indent = "\t".repeat(depth);
+    let is_root = parent_index.is_none();
+
+    let offset = match parent_index {
+        None => Vector3::new(0.0, 0.0, 0.0),
+        Some(parent_index) => {
+            let child_world = pose.world(joint.node_index).unwrap_or_else(Matrix4::identity);
+            let parent_world = pose.world(parent_index).unwrap_or_else(Matrix4::identity);
+            Vector3::new(
+                child_world[(0, 3)] - parent_world[(0, 3)],
+                child_world[(1, 3)] - parent_world[(1, 3)],
+                child_world[(2, 3)] - parent_world[(2, 3)],
+            )
+        }
+    };
+
+    if is_root {
+        out.push_str(&format!("ROOT {}\n", joint.name));
+    } else {
+        out.push_str(&format!("{indent}JOINT {}\n", joint.name));
+    }
+    out.push_str(&format!("{indent}{{\n"));
+    out.push_str(&format!(
+        "{indent}\tOFFSET {:.6} {:.6} {:.6}\n",
+        offset.x, offset.y, offset.z
+    ));
+    if is_root {
+        out.push_str(&format!(
+            "{indent}\tCHANNELS 6 Xposition Yposition Zposition Zrotation Xrotation Yrotation\n"
+        ));
+    } else {
+        out.push_str(&format!("{indent}\tCHANNELS 3 Zrotation Xrotation Yrotation\n"));
+    }
+    order.push(joint.node_index);
+
+    if joint.children.is_empty() {
+        let leaf_indent = "\t".repeat(depth + 1);
+        out.push_str(&format!("{leaf_indent}End Site\n{leaf_indent}{{\n"));
+        out.push_str(&format!("{leaf_indent}\tOFFSET 0.000000 0.000000 0.000000\n"));
+        out.push_str(&format!("{leaf_indent}}}\n"));
+    } else {
+        for child in &joint.children {
+            write_joint_block(child, Some(joint.node_index), pose, depth + 1, out, order);
+        }
+    }
+    out.push_str(&format!("{indent}}}\n"));
+}
+
+/// Sampled translation/rotation animation curve for a single node, read from
+/// whichever glTF animation channels target it. Keyframes are sorted by time;
+/// only `LINEAR`/`STEP` interpolation is supported — `CUBICSPLINE` channels
+/// are skipped and that node falls back to its rest pose for the whole clip.
+#[derive(Default)]
+struct NodeCurve {
+    translation: Vec<(f32, Vector3<f32>)>,
+    rotation: Vec<(f32, UnitQuaternion<f32>)>,
+}
+
+fn read_time_keyframes(bin: &[u8], meta: &AccessorMeta) -> Vec<f32> {
+    (0..meta.count)
+        .filter_map(|key| read_f32_element(bin, meta, key).and_then(|v| v.first().copied()))
+        .collect()
+}
+
+fn collect_node_curves(
+    json: &Value,
+    bin: &[u8],
+    node_indices: &HashSet<usize>,
+) -> HashMap<usize, NodeCurve> {
+    let mut curves = HashMap::<usize, NodeCurve>::new();
+
+    let Some(animations) = json.get("animations").and_then(Value::as_array) else {
+        return curves;
+    };
+    // Only the first animation clip is baked into the BVH's single MOTION
+    // block; BVH has no concept of multiple named clips in one file.
+    let Some(animation) = animations.first() else {
+        return curves;
+    };
+    let Some(samplers) = animation.get("samplers").and_then(Value::as_array) else {
+        return curves;
+    };
+    let Some(channels) = animation.get("channels").and_then(Value::as_array) else {
+        return curves;
+    };
+
+    for channel in channels {
+        let Some(target) = channel.get("target") else {
+            continue;
+        };
+        let Some(node_index) = target.get("node").and_then(Value::as_u64).map(|v| v as usize)
+        else {
+            continue;
+        };
+        if !node_indices.contains(&node_index) {
+            continue;
+        }
+        let Some(path) = target.get("path").and_then(Value::as_str) else {
+            continue;
+        };
+        if path != "translation" && path != "rotation" {
+            continue;
+        }
+        let Some(sampler_index) = channel
+            .get("sampler")
+            .and_then(Value::as_u64)
+            .map(|v| v as usize)
+        else {
+            continue;
+        };
+        let Some(sampler) = samplers.get(sampler_index) else {
+            continue;
+        };
+        if sampler.get("interpolation").and_then(Value::as_str) == Some("CUBICSPLINE") {
+            continue;
+        }
+        let Some(input_index) = sampler
+            .get("input")
+            .and_then(Value::as_u64)
+            .map(|v| v as usize)
+        else {
+            continue;
+        };
+        let Some(output_index) = sampler
+            .get("output")
+            .and_then(Value::as_u64)
+            .map(|v| v as usize)
+        else {
+            continue;
+        };
+        let Some(input_meta) = accessor_meta(json, input_index) else {
+            continue;
+        };
+        let Some(output_meta) = accessor_meta(json, output_index) else {
+            continue;
+        };
+
+        let times = read_time_keyframes(bin, &input_meta);
+        let keyframe_count = times.len().min(output_meta.count);
+        let curve = curves.entry(node_index).or_default();
+
+        for (key, &time) in times.iter().enumerate().take(keyframe_count) {
+            let Some(value) = read_f32_element(bin, &output_meta, key) else {
+                continue;
+            };
+            match path {
+                "translation" if value.len() == 3 => {
+                    curve
+                        .translation
+                        .push((time, Vector3::new(value[0], value[1], value[2])));
+                }
+                "rotation" if value.len() == 4 => {
+                    curve.rotation.push((
+                        time,
+                        UnitQuaternion::from_quaternion(Quaternion::new(
+                            value[3], value[0], value[1], value[2],
+                        )),
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for curve in curves.values_mut() {
+        curve
+            .translation
+            .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        curve
+            .rotation
+            .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    }
+
+    curves
+}
+
+/// Linearly sample a keyframed vector curve at `time`, clamping to the first/
+/// last keyframe outside its range.
+fn sample_translation(keyframes: &[(f32, Vector3<f32>)], time: f32) -> Option<Vector3<f32>> {
+    if keyframes.is_empty() {
+        return None;
+    }
+    if time <= keyframes[0].0 {
+        return Some(keyframes[0].1);
+    }
+    if time >= keyframes[keyframes.len() - 1].0 {
+        return Some(keyframes[keyframes.len() - 1].1);
+    }
+    let next = keyframes.partition_point(|(t, _)| *t <= time);
+    let (t0, v0) = keyframes[next - 1];
+    let (t1, v1) = keyframes[next];
+    let span = (t1 - t0).max(1e-6);
+    let alpha = (time - t0) / span;
+    Some(v0 + (v1 - v0) * alpha)
+}
+
+/// Spherically sample a keyframed rotation curve at `time`, clamping to the
+/// first/last keyframe outside its range.
+fn sample_rotation(
+    keyframes: &[(f32, UnitQuaternion<f32>)],
+    time: f32,
+) -> Option<UnitQuaternion<f32>> {
+    if keyframes.is_empty() {
+        return None;
+    }
+    if time <= keyframes[0].0 {
+        return Some(keyframes[0].1);
+    }
+    if time >= keyframes[keyframes.len() - 1].0 {
+        return Some(keyframes[keyframes.len() - 1].1);
+    }
+    let next = keyframes.partition_point(|(t, _)| *t <= time);
+    let (t0, q0) = keyframes[next - 1];
+    let (t1, q1) = keyframes[next];
+    let span = (t1 - t0).max(1e-6);
+    let alpha = (time - t0) / span;
+    Some(q0.slerp(&q1, alpha))
+}
+
+/// Decompose a rotation into the BVH channel order this exporter declares
+/// (`Zrotation Xrotation Yrotation`), i.e. the angles of `R = Rz * Rx * Ry`.
+fn euler_zxy_degrees(rotation: &UnitQuaternion<f32>) -> (f32, f32, f32) {
+    let m = rotation.to_rotation_matrix();
+    let m = m.matrix();
+    let x = m[(2, 1)].clamp(-1.0, 1.0).asin();
+    let z = (-m[(0, 1)]).atan2(m[(1, 1)]);
+    let y = (-m[(2, 0)]).atan2(m[(2, 2)]);
+    (z.to_degrees(), x.to_degrees(), y.to_degrees())
+}
+
+fn build_motion_block(
+    order: &[usize],
+    curves: &HashMap<usize, NodeCurve>,
+    pose: &mut SkeletonPose,
+) -> String {
+    let end_time = curves
+        .values()
+        .flat_map(|curve| {
+            curve
+                .translation
+                .last()
+                .map(|(t, _)| *t)
+                .into_iter()
+                .chain(curve.rotation.last().map(|(t, _)| *t))
+        })
+        .fold(0.0f32, f32::max);
+
+    let frame_count = if end_time > 0.0 {
+        (end_time * BVH_SAMPLE_RATE_HZ).ceil() as usize + 1
+    } else {
+        1
+    };
+    let frame_time = 1.0 / BVH_SAMPLE_RATE_HZ;
+
+    let mut out = format!(
+        "MOTION\nFrames: {frame_count}\nFrame Time: {:.6}\n",
+        frame_time
+    );
+
+    for frame in 0..frame_count {
+        let time = frame as f32 * frame_time;
+        let mut values = Vec::with_capacity(order.len() * 3 + 3);
+
+        for (position, &node_index) in order.iter().enumerate() {
+            let rest_world = pose.world(node_index).unwrap_or_else(Matrix4::identity);
+            let curve = curves.get(&node_index);
+
+            if position == 0 {
+                let translation = curve
+                    .and_then(|c| sample_translation(&c.translation, time))
+                    .unwrap_or_else(|| {
+                        Vector3::new(rest_world[(0, 3)], rest_world[(1, 3)], rest_world[(2, 3)])
+                    });
+                values.push(translation.x);
+                values.push(translation.y);
+                values.push(translation.z);
+            }
+
+            let rotation = curve
+                .and_then(|c| sample_rotation(&c.rotation, time))
+                .unwrap_or_else(UnitQuaternion::identity);
+            let (z, x, y) = euler_zxy_degrees(&rotation);
+            values.push(z);
+            values.push(x);
+            values.push(y);
+        }
+
+        let line = values
+            .iter()
+            .map(|v| format!("{v:.6}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    out
+}
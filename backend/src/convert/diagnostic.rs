@@ -1,4 +1,5 @@
 use std::{
+    collections::{HashMap, HashSet},
     fs,
     path::{Path, PathBuf},
 };
@@ -13,7 +14,7 @@ use super::gltf_utils::{
     accessor_meta, collect_parent_index_map_from_json, compute_node_world_matrices,
     node_to_local_matrix, read_mat4_from_accessor,
 };
-use super::types::TextureInfo;
+use super::types::{Severity, TextureInfo, ValidationIssue};
 
 // ─── Diagnostic structs ────────────────────────────────────────────────────────
 
@@ -49,6 +50,29 @@ pub(super) struct SkinDiagnostic {
     joints: Vec<JointDiagnostic>,
 }
 
+/// Counts of [`ConversionDiagnosticLog::validations`] by severity, so a
+/// caller can decide pass/fail without re-walking the findings list.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub(super) struct ValidationSummary {
+    error_count: usize,
+    warning_count: usize,
+    info_count: usize,
+}
+
+impl ValidationSummary {
+    fn from_issues(issues: &[ValidationIssue]) -> Self {
+        let mut summary = Self::default();
+        for issue in issues {
+            match issue.severity {
+                Severity::Error => summary.error_count += 1,
+                Severity::Warning => summary.warning_count += 1,
+                Severity::Info => summary.info_count += 1,
+            }
+        }
+        summary
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub(super) struct ConversionDiagnosticLog {
     output_path: String,
@@ -57,6 +81,8 @@ pub(super) struct ConversionDiagnosticLog {
     skin_count: usize,
     mesh_nodes_with_skin: Vec<MeshSkinLinkDiagnostic>,
     skins: Vec<SkinDiagnostic>,
+    validations: Vec<ValidationIssue>,
+    validation_summary: ValidationSummary,
 }
 
 // ─── Path helper ──────────────────────────────────────────────────────────────
@@ -65,13 +91,76 @@ pub(super) fn diagnostic_log_path_for_output(output_path: &Path) -> PathBuf {
     output_path.with_extension("diagnostic.json")
 }
 
+// ─── Skin/joint validation ────────────────────────────────────────────────────
+
+/// Default `world_bind_distance` beyond which a joint's bind pose is flagged
+/// as mismatched with its rest-pose world transform. In output scene units
+/// (post scale-bake), so a centimeter-scale slip on a roughly human-sized
+/// avatar.
+pub(super) const DEFAULT_WORLD_BIND_DISTANCE_WARN_THRESHOLD: f32 = 0.01;
+
+/// Node indices reachable from a root of the first scene by walking `children`,
+/// i.e. nodes that actually appear in the rendered hierarchy rather than
+/// sitting unreferenced in the `nodes` array.
+fn reachable_node_indices(json: &Value) -> HashSet<usize> {
+    let root_node_indices: Vec<usize> = json
+        .get("scenes")
+        .and_then(Value::as_array)
+        .and_then(|scenes| scenes.first())
+        .and_then(|scene| scene.get("nodes"))
+        .and_then(Value::as_array)
+        .map(|roots| {
+            roots
+                .iter()
+                .filter_map(|index| index.as_u64().map(|v| v as usize))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let nodes = json.get("nodes").and_then(Value::as_array);
+    let mut reachable = HashSet::<usize>::new();
+    let mut stack = root_node_indices;
+    while let Some(node_index) = stack.pop() {
+        if !reachable.insert(node_index) {
+            continue;
+        }
+        let Some(children) = nodes
+            .and_then(|nodes| nodes.get(node_index))
+            .and_then(|node| node.get("children"))
+            .and_then(Value::as_array)
+        else {
+            continue;
+        };
+        stack.extend(children.iter().filter_map(|index| index.as_u64().map(|v| v as usize)));
+    }
+    reachable
+}
+
+/// Whether `ancestor` is `node`'s parent, grandparent, etc. (cycle-safe:
+/// bails out rather than looping forever on a malformed hierarchy).
+fn is_ancestor_of(parent_map: &HashMap<usize, usize>, ancestor: usize, node: usize) -> bool {
+    let mut current = node;
+    let mut seen = HashSet::from([current]);
+    while let Some(&parent) = parent_map.get(&current) {
+        if parent == ancestor {
+            return true;
+        }
+        if !seen.insert(parent) {
+            return false;
+        }
+        current = parent;
+    }
+    false
+}
+
 // ─── Diagnostic writer ────────────────────────────────────────────────────────
 
 pub(super) fn write_conversion_diagnostic_log(
     output_path: &Path,
     diagnostic_path: &Path,
     scale_factor: f32,
-) -> Result<()> {
+    world_bind_distance_warn_threshold: f32,
+) -> Result<Vec<ValidationIssue>> {
     let bytes = fs::read(output_path)
         .with_context(|| format!("failed to read output file: {}", output_path.display()))?;
     let glb = Glb::from_slice(&bytes).context("output file is not a GLB container")?;
@@ -102,7 +191,10 @@ pub(super) fn write_conversion_diagnostic_log(
         })
         .collect::<Vec<_>>();
 
+    let reachable_nodes = reachable_node_indices(&json);
+
     let mut skins_out = Vec::<SkinDiagnostic>::new();
+    let mut validations = Vec::<ValidationIssue>::new();
     if let Some(skins) = json.get("skins").and_then(Value::as_array) {
         for (skin_index, skin) in skins.iter().enumerate() {
             let skeleton_index = skin
@@ -129,12 +221,71 @@ pub(super) fn write_conversion_diagnostic_log(
                 .cloned()
                 .unwrap_or_default();
 
+            if let Some(inverse_bind_meta) = &inverse_bind_meta
+                && inverse_bind_meta.count != joints.len()
+            {
+                validations.push(ValidationIssue {
+                    severity: Severity::Error,
+                    code: "IBM_COUNT_MISMATCH".to_string(),
+                    message: format!(
+                        "[ERROR] Skin {skin_index} has {} inverse bind matrices for {} joints",
+                        inverse_bind_meta.count,
+                        joints.len()
+                    ),
+                });
+            }
+
+            let joint_node_indices: Vec<usize> = joints
+                .iter()
+                .filter_map(|joint_value| joint_value.as_u64().map(|v| v as usize))
+                .collect();
+            let unique_joint_node_indices: HashSet<usize> =
+                joint_node_indices.iter().copied().collect();
+            if unique_joint_node_indices.len() != joint_node_indices.len() {
+                validations.push(ValidationIssue {
+                    severity: Severity::Error,
+                    code: "DUPLICATE_JOINT_NODE".to_string(),
+                    message: format!(
+                        "[ERROR] Skin {skin_index} references the same node more than once across its joints array"
+                    ),
+                });
+            }
+
+            if let Some(skeleton_index) = skeleton_index {
+                let non_descendant_joint = joint_node_indices
+                    .iter()
+                    .copied()
+                    .find(|&node_index| {
+                        node_index != skeleton_index
+                            && !is_ancestor_of(&parent_map, skeleton_index, node_index)
+                    });
+                if let Some(node_index) = non_descendant_joint {
+                    validations.push(ValidationIssue {
+                        severity: Severity::Warning,
+                        code: "SKELETON_NOT_ANCESTOR_OF_JOINT".to_string(),
+                        message: format!(
+                            "[WARN] Skin {skin_index}'s skeleton node {skeleton_index} is not an ancestor of joint node {node_index}"
+                        ),
+                    });
+                }
+            }
+
             let mut joint_out = Vec::<JointDiagnostic>::new();
             for (slot, joint_value) in joints.iter().enumerate() {
                 let Some(node_index) = joint_value.as_u64().map(|v| v as usize) else {
                     continue;
                 };
 
+                if !reachable_nodes.contains(&node_index) {
+                    validations.push(ValidationIssue {
+                        severity: Severity::Error,
+                        code: "JOINT_NODE_UNREACHABLE".to_string(),
+                        message: format!(
+                            "[ERROR] Skin {skin_index} joint slot {slot} references node {node_index}, which is not reachable from any scene root"
+                        ),
+                    });
+                }
+
                 let node = nodes.get(node_index);
                 let node_name = node
                     .and_then(|n| n.get("name"))
@@ -193,6 +344,18 @@ pub(super) fn write_conversion_diagnostic_log(
                     (world_v - bind_v).norm()
                 });
 
+                if let Some(distance) = world_bind_distance
+                    && distance > world_bind_distance_warn_threshold
+                {
+                    validations.push(ValidationIssue {
+                        severity: Severity::Warning,
+                        code: "WORLD_BIND_DISTANCE_EXCEEDS_THRESHOLD".to_string(),
+                        message: format!(
+                            "[WARN] Skin {skin_index} joint slot {slot} (node {node_index}) bind pose is {distance:.4} units from its rest-pose world transform, exceeding the {world_bind_distance_warn_threshold:.4} threshold"
+                        ),
+                    });
+                }
+
                 joint_out.push(JointDiagnostic {
                     slot,
                     node_index,
@@ -219,6 +382,7 @@ pub(super) fn write_conversion_diagnostic_log(
         }
     }
 
+    let validation_summary = ValidationSummary::from_issues(&validations);
     let diagnostic = ConversionDiagnosticLog {
         output_path: output_path.display().to_string(),
         scale_factor,
@@ -226,6 +390,8 @@ pub(super) fn write_conversion_diagnostic_log(
         skin_count: skins_out.len(),
         mesh_nodes_with_skin,
         skins: skins_out,
+        validations: validations.clone(),
+        validation_summary,
     };
 
     let json_bytes = serde_json::to_vec_pretty(&diagnostic)
@@ -237,12 +403,120 @@ pub(super) fn write_conversion_diagnostic_log(
         )
     })?;
 
-    Ok(())
+    Ok(validations)
 }
 
 // ─── Post-export helpers ──────────────────────────────────────────────────────
 
-/// Collect texture dimensions from an exported GLB output file.
+/// Side length of the fixed sampling grid used by [`analyze_texture_content`].
+/// A 4K atlas is scanned at this resolution rather than pixel-by-pixel, which
+/// is plenty to characterize alpha usage and average color cheaply.
+const TEXTURE_ANALYSIS_GRID: u32 = 32;
+
+/// Decode one channel value at `offset` into a `u8`, regardless of the
+/// source's underlying component width (8-bit raw, 16-bit scaled down, or
+/// `f32` in `0.0..=1.0` scaled up). Out-of-bounds reads return `0` rather
+/// than panicking, since this only feeds a best-effort coarse analysis.
+fn decode_channel_u8(pixels: &[u8], offset: usize, bytes_per_channel: u8) -> u8 {
+    match bytes_per_channel {
+        2 => pixels
+            .get(offset..offset + 2)
+            .map(|bytes| (u16::from_le_bytes([bytes[0], bytes[1]]) >> 8) as u8)
+            .unwrap_or(0),
+        4 => pixels
+            .get(offset..offset + 4)
+            .map(|bytes| {
+                let value = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                (value.clamp(0.0, 1.0) * 255.0).round() as u8
+            })
+            .unwrap_or(0),
+        _ => pixels.get(offset).copied().unwrap_or(0),
+    }
+}
+
+/// Coarse content analysis of one decoded glTF image: `(channel_count,
+/// has_alpha_channel, alpha_channel_used, average_color)`. Sampled on a fixed
+/// grid (see [`TEXTURE_ANALYSIS_GRID`]) rather than every pixel, so a 4K
+/// atlas stays cheap to inspect.
+pub(super) fn analyze_texture_content(image: &gltf::image::Data) -> (u8, bool, bool, [u8; 3]) {
+    let (channel_count, bytes_per_channel, has_alpha_channel): (u8, u8, bool) = match image.format
+    {
+        gltf::image::Format::R8 => (1, 1, false),
+        gltf::image::Format::R8G8 => (2, 1, false),
+        gltf::image::Format::R8G8B8 => (3, 1, false),
+        gltf::image::Format::R8G8B8A8 => (4, 1, true),
+        gltf::image::Format::R16 => (1, 2, false),
+        gltf::image::Format::R16G16 => (2, 2, false),
+        gltf::image::Format::R16G16B16 => (3, 2, false),
+        gltf::image::Format::R16G16B16A16 => (4, 2, true),
+        gltf::image::Format::R32G32B32FLOAT => (3, 4, false),
+        gltf::image::Format::R32G32B32A32FLOAT => (4, 4, true),
+    };
+
+    let pixel_stride = channel_count as usize * bytes_per_channel as usize;
+    let row_stride = image.width as usize * pixel_stride;
+    let sample_columns = image.width.max(1).min(TEXTURE_ANALYSIS_GRID);
+    let sample_rows = image.height.max(1).min(TEXTURE_ANALYSIS_GRID);
+
+    let mut alpha_channel_used = false;
+    let mut color_sum = [0u64; 3];
+    let mut sample_count = 0u64;
+
+    for sample_row in 0..sample_rows {
+        let y = sample_row * image.height / sample_rows;
+        for sample_column in 0..sample_columns {
+            let x = sample_column * image.width / sample_columns;
+            let offset = y as usize * row_stride + x as usize * pixel_stride;
+
+            let red = decode_channel_u8(&image.pixels, offset, bytes_per_channel);
+            let green = if channel_count >= 2 {
+                decode_channel_u8(
+                    &image.pixels,
+                    offset + bytes_per_channel as usize,
+                    bytes_per_channel,
+                )
+            } else {
+                red
+            };
+            let blue = if channel_count >= 3 {
+                decode_channel_u8(
+                    &image.pixels,
+                    offset + 2 * bytes_per_channel as usize,
+                    bytes_per_channel,
+                )
+            } else {
+                red
+            };
+            if has_alpha_channel {
+                let alpha_offset = offset + (channel_count as usize - 1) * bytes_per_channel as usize;
+                if decode_channel_u8(&image.pixels, alpha_offset, bytes_per_channel) < 255 {
+                    alpha_channel_used = true;
+                }
+            }
+
+            color_sum[0] += red as u64;
+            color_sum[1] += green as u64;
+            color_sum[2] += blue as u64;
+            sample_count += 1;
+        }
+    }
+
+    let average_color = if sample_count > 0 {
+        [
+            (color_sum[0] / sample_count) as u8,
+            (color_sum[1] / sample_count) as u8,
+            (color_sum[2] / sample_count) as u8,
+        ]
+    } else {
+        [0, 0, 0]
+    };
+
+    (channel_count, has_alpha_channel, alpha_channel_used, average_color)
+}
+
+/// Collect texture dimensions and a coarse content analysis from an exported
+/// GLB output file, so a downstream material optimizer can decide when an
+/// alpha channel, a full RGBA image, or a whole texture is unnecessary.
 pub(super) fn collect_output_texture_infos(output_path: &Path) -> Result<Vec<TextureInfo>> {
     let (_, _, images) = import(output_path)
         .with_context(|| format!("failed to read output VRM/glTF: {}", output_path.display()))?;
@@ -250,10 +524,19 @@ pub(super) fn collect_output_texture_infos(output_path: &Path) -> Result<Vec<Tex
     Ok(images
         .iter()
         .enumerate()
-        .map(|(index, image)| TextureInfo {
-            index,
-            width: image.width,
-            height: image.height,
+        .map(|(index, image)| {
+            let (channel_count, has_alpha_channel, alpha_channel_used, average_color) =
+                analyze_texture_content(image);
+            TextureInfo {
+                index,
+                width: image.width,
+                height: image.height,
+                channel_count,
+                has_alpha_channel,
+                alpha_channel_used,
+                is_opaque: !has_alpha_channel || !alpha_channel_used,
+                average_color,
+            }
         })
         .collect())
 }
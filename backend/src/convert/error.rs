@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use super::types::{Severity, ValidationIssue};
+
+/// A structured, coded error surfaced across the Tauri IPC boundary in place
+/// of a bare `String`, so the frontend can react to a specific failure class
+/// (e.g. missing required bones) instead of pattern-matching error text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertError {
+    pub code: String,
+    pub severity: Severity,
+    pub message: String,
+    /// Human-readable pointer at the offending glTF object, e.g.
+    /// `"skin[2].accessors[14, 15]"`. `None` when the failure isn't tied to
+    /// one specific object.
+    pub location: Option<String>,
+    /// The individual issues rolled up into this error, if any (e.g. one
+    /// `MISSING_REQUIRED_BONE` issue per bone that a conversion aborted on).
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ConvertError {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            severity: Severity::Error,
+            message: message.into(),
+            location: None,
+            issues: Vec::new(),
+        }
+    }
+
+    /// Builds an error from a set of [`ValidationIssue`]s that blocked
+    /// conversion, keeping them alongside the summary `message` so the
+    /// frontend can still react to each one's `code`.
+    pub fn from_issues(
+        code: impl Into<String>,
+        message: impl Into<String>,
+        issues: Vec<ValidationIssue>,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            severity: Severity::Error,
+            message: message.into(),
+            location: None,
+            issues,
+        }
+    }
+
+    /// A conversion stopped early because its
+    /// [`crate::cancellation::CancellationToken`] was signalled, distinct
+    /// from an unexpected failure so the frontend doesn't show it as one.
+    pub fn cancelled() -> Self {
+        Self {
+            code: "CONVERSION_CANCELLED".to_string(),
+            severity: Severity::Info,
+            message: "Conversion was cancelled".to_string(),
+            location: None,
+            issues: Vec::new(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "[{}] {} ({})", self.code, self.message, location),
+            None => write!(f, "[{}] {}", self.code, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ConvertError {}
+
+impl From<anyhow::Error> for ConvertError {
+    /// Recovers a [`ConvertError`] already carried inside an `anyhow::Error`
+    /// (e.g. one raised via `bail!` deeper in the pipeline), falling back to
+    /// a generic, uncoded error wrapping its display text otherwise.
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<ConvertError>() {
+            Ok(convert_error) => convert_error,
+            Err(error) => ConvertError::new("CONVERSION_FAILED", error.to_string()),
+        }
+    }
+}
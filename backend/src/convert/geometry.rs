@@ -1,17 +1,30 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
 use gltf::{Document, Semantic};
 use nalgebra::Vector3;
 use serde_json::Value;
 
+use super::gltf_utils::{AccessorMeta, accessor_meta, read_sparse_index};
 use super::types::{Severity, ValidationIssue};
 
+/// Second Life's per-primitive vertex ceiling. A VRoid head or clothing mesh
+/// with enough subdivision routinely exceeds this, which is why
+/// [`split_oversized_primitives`] exists as an opt-in repair instead of a
+/// hard failure.
+pub(super) const MAX_SL_PRIMITIVE_VERTICES: usize = 65_535;
+
 // ─── Mesh statistics ──────────────────────────────────────────────────────────
 
 /// Collect total mesh statistics and hard-limit validation issues.
+///
+/// When `split_oversized_primitives` is set, an over-limit primitive is
+/// reported as an `Info` notice instead of a fatal `Error`, since
+/// [`split_oversized_primitives`] repairs it during conversion rather than
+/// leaving the model unconvertible.
 pub(super) fn collect_mesh_statistics(
     document: &Document,
+    split_oversized_primitives: bool,
 ) -> (usize, usize, Vec<ValidationIssue>) {
     let mut total_vertices = 0usize;
     let mut total_polygons = 0usize;
@@ -27,13 +40,22 @@ pub(super) fn collect_mesh_statistics(
 
             total_vertices += vertex_count;
 
-            if vertex_count > 65_535 {
+            if vertex_count > MAX_SL_PRIMITIVE_VERTICES && split_oversized_primitives {
+                issues.push(ValidationIssue {
+                    severity: Severity::Info,
+                    code: "VERTEX_LIMIT_EXCEEDED".to_string(),
+                    message: format!(
+                        "[INFO] Vertex limit exceeded (mesh: {}, primitive: {}, current: {} / limit: {}) — will be split into sub-meshes during conversion",
+                        mesh_name, primitive_index, vertex_count, MAX_SL_PRIMITIVE_VERTICES
+                    ),
+                });
+            } else if vertex_count > MAX_SL_PRIMITIVE_VERTICES {
                 issues.push(ValidationIssue {
                     severity: Severity::Error,
                     code: "VERTEX_LIMIT_EXCEEDED".to_string(),
                     message: format!(
-                        "⛔ Vertex limit exceeded (mesh: {}, primitive: {}, current: {} / limit: 65535)",
-                        mesh_name, primitive_index, vertex_count
+                        "⛔ Vertex limit exceeded (mesh: {}, primitive: {}, current: {} / limit: {})",
+                        mesh_name, primitive_index, vertex_count, MAX_SL_PRIMITIVE_VERTICES
                     ),
                 });
             }
@@ -54,15 +76,379 @@ pub(super) fn collect_mesh_statistics(
     (total_vertices, total_polygons, issues)
 }
 
-/// Estimate avatar height in centimeters from mesh Y extents.
+// ─── Oversized primitive splitting ─────────────────────────────────────────────
+
+/// Partition every primitive whose vertex count exceeds
+/// [`MAX_SL_PRIMITIVE_VERTICES`] into sibling primitives that each stay
+/// within the limit, sharing the original material, instead of leaving the
+/// model unconvertible per [`collect_mesh_statistics`]'s `Error`.
+///
+/// Only indexed `TRIANGLES`-mode primitives can be split; anything else (no
+/// `indices`, or a non-triangle-list `mode`) is left untouched and over the
+/// limit. Its issue from `collect_mesh_statistics` is still the `Info`
+/// notice, not an `Error`: this pass only runs when
+/// `split_oversized_primitives` is enabled, and `collect_mesh_statistics`
+/// downgrades that issue under the same flag regardless of whether a given
+/// primitive can actually be split.
+pub(super) fn split_oversized_primitives(json: &mut Value, bin: &mut Vec<u8>) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mesh_count = json["meshes"].as_array().map(Vec::len).unwrap_or(0);
+
+    for mesh_index in 0..mesh_count {
+        let mesh_name = json["meshes"][mesh_index]
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or("unnamed_mesh")
+            .to_string();
+        let primitives = json["meshes"][mesh_index]["primitives"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+
+        let mut rebuilt_primitives = Vec::with_capacity(primitives.len());
+        let mut sub_mesh_count = 0usize;
+        let mut split_any = false;
+
+        for primitive in primitives {
+            let vertex_count = primitive
+                .pointer("/attributes/POSITION")
+                .and_then(Value::as_u64)
+                .and_then(|index| accessor_meta(json, index as usize))
+                .map(|meta| meta.count)
+                .unwrap_or(0);
+
+            if vertex_count <= MAX_SL_PRIMITIVE_VERTICES {
+                rebuilt_primitives.push(primitive);
+                continue;
+            }
+
+            match split_primitive(json, bin, &primitive) {
+                Some(chunks) => {
+                    split_any = true;
+                    sub_mesh_count += chunks.len();
+                    rebuilt_primitives.extend(chunks);
+                }
+                None => rebuilt_primitives.push(primitive),
+            }
+        }
+
+        if split_any {
+            json["meshes"][mesh_index]["primitives"] = Value::Array(rebuilt_primitives);
+            issues.push(ValidationIssue {
+                severity: Severity::Info,
+                code: "PRIMITIVE_SPLIT".to_string(),
+                message: format!(
+                    "[INFO] Split oversized primitive(s) in mesh '{}' into {} sub-mesh(es) to stay within Second Life's {}-vertex limit",
+                    mesh_name, sub_mesh_count, MAX_SL_PRIMITIVE_VERTICES
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Greedily partition one over-limit primitive's triangle list into chunks of
+/// at most [`MAX_SL_PRIMITIVE_VERTICES`] referenced vertices, remapping each
+/// chunk's indices to a compacted local vertex range and slicing every
+/// attribute accessor down to just the vertices that chunk uses. Returns
+/// `None` (leaving the primitive untouched) when it isn't an indexed
+/// `TRIANGLES`-mode primitive, since a non-indexed or non-triangle-list
+/// vertex stream can't be safely repartitioned this way.
+fn split_primitive(json: &mut Value, bin: &mut Vec<u8>, primitive: &Value) -> Option<Vec<Value>> {
+    let mode = primitive.get("mode").and_then(Value::as_u64).unwrap_or(4);
+    if mode != 4 {
+        return None;
+    }
+
+    let indices_accessor_index = primitive.get("indices").and_then(Value::as_u64)? as usize;
+    let indices_meta = accessor_meta(json, indices_accessor_index)?;
+    let triangle_indices = read_indices(bin, &indices_meta);
+    if triangle_indices.is_empty() || triangle_indices.len() % 3 != 0 {
+        return None;
+    }
+
+    // Resolve every attribute's accessor metadata up front rather than
+    // inside the per-chunk loop below, so a malformed accessor aborts the
+    // whole split (leaving the primitive untouched) instead of producing
+    // sub-meshes where some attributes were sliced and others still point at
+    // the original, now-mismatched-length accessor.
+    let attributes: Vec<(String, AccessorMeta)> = primitive
+        .get("attributes")?
+        .as_object()?
+        .iter()
+        .map(|(name, value)| {
+            let accessor_index = value.as_u64()? as usize;
+            Some((name.clone(), accessor_meta(json, accessor_index)?))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let chunks = partition_triangles_into_chunks(&triangle_indices);
+
+    let mut new_primitives = Vec::with_capacity(chunks.len());
+    for chunk in &chunks {
+        let mut new_primitive = primitive.clone();
+        for (attribute_name, meta) in &attributes {
+            let new_accessor_index =
+                append_sliced_accessor(json, bin, meta, &chunk.original_vertex_indices);
+            new_primitive["attributes"][attribute_name] = Value::from(new_accessor_index as u64);
+        }
+        let new_indices_accessor_index =
+            append_indices_accessor(json, bin, &chunk.local_triangle_indices);
+        new_primitive["indices"] = Value::from(new_indices_accessor_index as u64);
+        new_primitives.push(new_primitive);
+    }
+
+    Some(new_primitives)
+}
+
+/// One sub-mesh worth of an oversized primitive's split: the original
+/// (pre-split) vertex index each local vertex slot corresponds to, and the
+/// triangle list already remapped to those local slots.
+struct PrimitiveChunk {
+    original_vertex_indices: Vec<u32>,
+    local_triangle_indices: Vec<u32>,
+}
+
+/// Walk `triangle_indices` three at a time, greedily grouping triangles into
+/// [`PrimitiveChunk`]s so that no chunk ever references more than
+/// [`MAX_SL_PRIMITIVE_VERTICES`] distinct original vertices.
+fn partition_triangles_into_chunks(triangle_indices: &[u32]) -> Vec<PrimitiveChunk> {
+    let mut chunks = Vec::new();
+    let mut vertex_map = HashMap::<u32, u32>::new();
+    let mut original_vertex_indices = Vec::<u32>::new();
+    let mut local_triangle_indices = Vec::<u32>::new();
+
+    for triangle in triangle_indices.chunks_exact(3) {
+        let mut distinct_vertices: Vec<u32> = triangle.to_vec();
+        distinct_vertices.sort_unstable();
+        distinct_vertices.dedup();
+        let new_vertex_count = distinct_vertices
+            .iter()
+            .filter(|vertex| !vertex_map.contains_key(vertex))
+            .count();
+
+        if !original_vertex_indices.is_empty()
+            && original_vertex_indices.len() + new_vertex_count > MAX_SL_PRIMITIVE_VERTICES
+        {
+            chunks.push(PrimitiveChunk {
+                original_vertex_indices: std::mem::take(&mut original_vertex_indices),
+                local_triangle_indices: std::mem::take(&mut local_triangle_indices),
+            });
+            vertex_map.clear();
+        }
+
+        for &vertex in triangle {
+            let local_index = *vertex_map.entry(vertex).or_insert_with(|| {
+                let local_index = original_vertex_indices.len() as u32;
+                original_vertex_indices.push(vertex);
+                local_index
+            });
+            local_triangle_indices.push(local_index);
+        }
+    }
+
+    if !original_vertex_indices.is_empty() {
+        chunks.push(PrimitiveChunk {
+            original_vertex_indices,
+            local_triangle_indices,
+        });
+    }
+
+    chunks
+}
+
+/// Read every element of an (unsigned byte/short/int) `SCALAR` indices
+/// accessor as `u32`.
+fn read_indices(bin: &[u8], meta: &AccessorMeta) -> Vec<u32> {
+    (0..meta.count)
+        .filter_map(|index| {
+            let offset = meta.base_offset + index * meta.stride;
+            match meta.component_type {
+                5121 => bin.get(offset).map(|&value| value as u32),
+                5123 => {
+                    let bytes = bin.get(offset..offset + 2)?;
+                    Some(u16::from_le_bytes([bytes[0], bytes[1]]) as u32)
+                }
+                5125 => {
+                    let bytes = bin.get(offset..offset + 4)?;
+                    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Append a new `bufferView` spanning `bytes`, 4-byte aligned as every other
+/// append in this codebase does, returning its index.
+fn append_buffer_view(bin: &mut Vec<u8>, json: &mut Value, bytes: Vec<u8>) -> usize {
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+    let offset = bin.len();
+    let length = bytes.len();
+    bin.extend(bytes);
+
+    if let Some(buffers) = json.get_mut("buffers").and_then(Value::as_array_mut) {
+        if let Some(first_buffer) = buffers.first_mut() {
+            first_buffer["byteLength"] = Value::from(bin.len() as u64);
+        }
+    }
+
+    let buffer_views = json
+        .get_mut("bufferViews")
+        .and_then(Value::as_array_mut)
+        .expect("split glTF has a bufferViews array");
+    let view_index = buffer_views.len();
+    buffer_views.push(serde_json::json!({
+        "buffer": 0,
+        "byteOffset": offset,
+        "byteLength": length,
+    }));
+    view_index
+}
+
+/// Append a new accessor+bufferView holding `original_vertex_indices.len()`
+/// elements sliced out of the accessor described by `meta`, one per entry of
+/// `original_vertex_indices` in order, preserving `meta`'s component/type so
+/// downstream readers (skin rebake, pose correction, ...) see the same data
+/// shape as the source accessor. Ignores any `sparse` override on `meta`,
+/// consistent with this module's other generic per-element accessor helpers
+/// (e.g. [`super::gltf_utils::read_f32_element`]).
+fn append_sliced_accessor(
+    json: &mut Value,
+    bin: &mut Vec<u8>,
+    meta: &AccessorMeta,
+    original_vertex_indices: &[u32],
+) -> usize {
+    let component_size = component_byte_size(meta.component_type);
+    let element_count = element_count(meta.accessor_type);
+    let element_size = component_size * element_count;
+
+    let mut bytes = Vec::with_capacity(original_vertex_indices.len() * element_size);
+    for &vertex in original_vertex_indices {
+        let offset = meta.base_offset + vertex as usize * meta.stride;
+        match bin.get(offset..offset + element_size) {
+            Some(slice) => bytes.extend_from_slice(slice),
+            None => bytes.extend(std::iter::repeat_n(0u8, element_size)),
+        }
+    }
+
+    let view_index = append_buffer_view(bin, json, bytes);
+    append_accessor(
+        json,
+        view_index,
+        meta.component_type,
+        meta.accessor_type,
+        original_vertex_indices.len(),
+        meta.normalized,
+    )
+}
+
+/// Append a new `SCALAR` `UNSIGNED_SHORT` indices accessor. Local indices
+/// always fit `u16` since each chunk is capped at
+/// [`MAX_SL_PRIMITIVE_VERTICES`] (equal to `u16::MAX`) vertices, so the
+/// largest local index is `u16::MAX - 1`.
+fn append_indices_accessor(
+    json: &mut Value,
+    bin: &mut Vec<u8>,
+    local_triangle_indices: &[u32],
+) -> usize {
+    let mut bytes = Vec::with_capacity(local_triangle_indices.len() * 2);
+    for &index in local_triangle_indices {
+        bytes.extend_from_slice(&(index as u16).to_le_bytes());
+    }
+
+    let view_index = append_buffer_view(bin, json, bytes);
+    append_accessor(json, view_index, 5123, "SCALAR", local_triangle_indices.len(), false)
+}
+
+fn append_accessor(
+    json: &mut Value,
+    buffer_view_index: usize,
+    component_type: u64,
+    accessor_type: &str,
+    count: usize,
+    normalized: bool,
+) -> usize {
+    let accessors = json
+        .get_mut("accessors")
+        .and_then(Value::as_array_mut)
+        .expect("split glTF has an accessors array");
+    let index = accessors.len();
+    let mut accessor = serde_json::json!({
+        "bufferView": buffer_view_index,
+        "componentType": component_type,
+        "type": accessor_type,
+        "count": count,
+    });
+    if normalized {
+        accessor["normalized"] = Value::from(true);
+    }
+    accessors.push(accessor);
+    index
+}
+
+fn component_byte_size(component_type: u64) -> usize {
+    match component_type {
+        5120 | 5121 => 1,
+        5122 | 5123 => 2,
+        5125 | 5126 => 4,
+        _ => 4,
+    }
+}
+
+fn element_count(accessor_type: &str) -> usize {
+    match accessor_type {
+        "SCALAR" => 1,
+        "VEC2" => 2,
+        "VEC3" => 3,
+        "VEC4" => 4,
+        "MAT4" => 16,
+        _ => 1,
+    }
+}
+
+/// Mesh indices reachable from the default scene (falling back to the first
+/// scene), so stray off-scene geometry doesn't skew the height estimate.
+fn reachable_mesh_indices_from_first_scene(document: &Document) -> HashSet<usize> {
+    let Some(scene) = document.default_scene().or_else(|| document.scenes().next()) else {
+        return HashSet::new();
+    };
+
+    let mut visited = HashSet::new();
+    let mut stack: Vec<gltf::Node> = scene.nodes().collect();
+    let mut mesh_indices = HashSet::new();
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.index()) {
+            continue;
+        }
+        if let Some(mesh) = node.mesh() {
+            mesh_indices.insert(mesh.index());
+        }
+        stack.extend(node.children());
+    }
+
+    mesh_indices
+}
+
+/// Estimate avatar height in centimeters from mesh Y extents, considering
+/// only meshes reachable from the default scene (or the first scene when
+/// none is set).
 pub(super) fn estimate_height_cm(
     document: &Document,
     buffers: &[gltf::buffer::Data],
 ) -> Option<f32> {
+    let reachable_meshes = reachable_mesh_indices_from_first_scene(document);
+
     let mut min_y = f32::INFINITY;
     let mut max_y = f32::NEG_INFINITY;
 
     for mesh in document.meshes() {
+        if !reachable_meshes.contains(&mesh.index()) {
+            continue;
+        }
         for primitive in mesh.primitives() {
             let reader =
                 primitive.reader(|buffer| buffers.get(buffer.index()).map(|b| &b.0[..]));
@@ -160,34 +546,16 @@ pub(super) fn bake_scale_into_geometry(
         }
     }
 
-    let accessors = json["accessors"].as_array().cloned().unwrap_or_default();
-    let buffer_views = json["bufferViews"].as_array().cloned().unwrap_or_default();
-
     for acc_idx in pos_accessor_indices {
-        let Some(accessor) = accessors.get(acc_idx) else {
+        let Some(meta) = accessor_meta(json, acc_idx) else {
             continue;
         };
-        if accessor["componentType"].as_u64().unwrap_or(0) != 5126 {
-            continue;
-        }
-        if accessor["type"].as_str().unwrap_or("") != "VEC3" {
+        if meta.component_type != 5126 || meta.accessor_type != "VEC3" {
             continue;
         }
-        let count = accessor["count"].as_u64().unwrap_or(0) as usize;
-        let bv_idx = match accessor["bufferView"].as_u64().map(|v| v as usize) {
-            Some(i) => i,
-            None => continue,
-        };
-        let Some(bv) = buffer_views.get(bv_idx) else {
-            continue;
-        };
-        let view_offset = bv["byteOffset"].as_u64().unwrap_or(0) as usize;
-        let acc_offset = accessor["byteOffset"].as_u64().unwrap_or(0) as usize;
-        let stride = bv["byteStride"].as_u64().map(|v| v as usize).unwrap_or(12);
-        let base = view_offset + acc_offset;
 
-        for i in 0..count {
-            let offset = base + i * stride;
+        for i in 0..meta.count {
+            let offset = meta.base_offset + i * meta.stride;
             if offset + 12 > bin.len() {
                 break;
             }
@@ -198,6 +566,31 @@ pub(super) fn bake_scale_into_geometry(
                 bin[byte_pos..byte_pos + 4].copy_from_slice(&scaled.to_le_bytes());
             }
         }
+
+        // A sparse POSITION accessor patches specific elements out-of-band on
+        // top of the dense base scaled above; scale those overrides too so
+        // they don't end up sitting unscaled on top of already-scaled data.
+        let Some(sparse) = &meta.sparse else {
+            continue;
+        };
+        for entry in 0..sparse.count {
+            let Some(slot) = read_sparse_index(bin, sparse, entry) else {
+                continue;
+            };
+            if slot >= meta.count {
+                continue;
+            }
+            let value_offset = sparse.values_base_offset + entry * sparse.values_stride;
+            if value_offset + 12 > bin.len() {
+                break;
+            }
+            for component in 0..3usize {
+                let byte_pos = value_offset + component * 4;
+                let v = f32::from_le_bytes(bin[byte_pos..byte_pos + 4].try_into().unwrap());
+                let scaled = v * scale_factor;
+                bin[byte_pos..byte_pos + 4].copy_from_slice(&scaled.to_le_bytes());
+            }
+        }
     }
 
     Ok(())
@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use nalgebra::{Matrix3, Matrix4, Quaternion, Translation3, UnitQuaternion, Vector3};
+use nalgebra::{Matrix3, Matrix4, Quaternion, Translation3, UnitQuaternion, Vector3, Vector4};
 use serde_json::Value;
 
 // ─── Accessor metadata ────────────────────────────────────────────────────────
@@ -12,6 +12,29 @@ pub(super) struct AccessorMeta {
     pub(super) count: usize,
     pub(super) component_type: u64,
     pub(super) accessor_type: &'static str,
+    /// The accessor's own `normalized` flag (glTF default `false`): integer
+    /// component values represent `value / max` rather than a raw integer.
+    /// Every `WEIGHTS_n` accessor the spec allows to use an integer
+    /// component type is required to set this, but it's read here rather
+    /// than assumed so a non-conformant file degrades to raw integers
+    /// instead of being silently rescaled.
+    pub(super) normalized: bool,
+    /// Set when the accessor carries a `sparse` override block on top of its
+    /// dense base data (used by some VRM exporters for morph-target
+    /// positions, and occasionally IBMs).
+    pub(super) sparse: Option<SparseMeta>,
+}
+
+/// Layout of an accessor's `sparse` override block: a dense array of
+/// replaced-element indices plus a dense array of replacement values.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct SparseMeta {
+    pub(super) count: usize,
+    pub(super) indices_base_offset: usize,
+    pub(super) indices_stride: usize,
+    pub(super) indices_component_type: u64,
+    pub(super) values_base_offset: usize,
+    pub(super) values_stride: usize,
 }
 
 /// Primitive skin binding: indices of the JOINTS_0 and WEIGHTS_0 accessors.
@@ -23,6 +46,11 @@ pub(super) struct PrimitiveSkinBinding {
 
 // ─── Accessor I/O ─────────────────────────────────────────────────────────────
 
+/// Note: a sparse accessor with no base `bufferView` at all (dense data
+/// implicitly all-zero, every element coming from the override set) is
+/// rejected here rather than supported — every accessor this converter
+/// encounters in practice is backed by a dense `bufferView`, with `sparse`
+/// layered on top of it.
 pub(super) fn accessor_meta(json: &Value, accessor_index: usize) -> Option<AccessorMeta> {
     let accessors = json.get("accessors")?.as_array()?;
     let accessor = accessors.get(accessor_index)?;
@@ -63,6 +91,11 @@ pub(super) fn accessor_meta(json: &Value, accessor_index: usize) -> Option<Acces
         .map(|value| value as usize)
         .unwrap_or(default_stride);
 
+    let sparse = accessor
+        .get("sparse")
+        .and_then(|sparse| sparse_meta(sparse, buffer_views, element_count, component_size));
+    let normalized = accessor.get("normalized").and_then(Value::as_bool).unwrap_or(false);
+
     Some(AccessorMeta {
         base_offset: view_offset + accessor_offset,
         stride,
@@ -76,23 +109,117 @@ pub(super) fn accessor_meta(json: &Value, accessor_index: usize) -> Option<Acces
             "MAT4" => "MAT4",
             _ => return None,
         },
+        normalized,
+        sparse,
+    })
+}
+
+fn sparse_meta(
+    sparse: &Value,
+    buffer_views: &[Value],
+    element_count: usize,
+    component_size: usize,
+) -> Option<SparseMeta> {
+    let count = sparse.get("count")?.as_u64()? as usize;
+
+    let indices = sparse.get("indices")?;
+    let indices_buffer_view = buffer_views.get(indices.get("bufferView")?.as_u64()? as usize)?;
+    let indices_component_type = indices.get("componentType")?.as_u64()?;
+    let indices_component_size = match indices_component_type {
+        5121 => 1,
+        5123 => 2,
+        5125 => 4,
+        _ => return None,
+    };
+    let indices_view_offset = indices_buffer_view
+        .get("byteOffset")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as usize;
+    let indices_accessor_offset = indices.get("byteOffset").and_then(Value::as_u64).unwrap_or(0) as usize;
+    let indices_stride = indices_buffer_view
+        .get("byteStride")
+        .and_then(Value::as_u64)
+        .map(|value| value as usize)
+        .unwrap_or(indices_component_size);
+
+    let values = sparse.get("values")?;
+    let values_buffer_view = buffer_views.get(values.get("bufferView")?.as_u64()? as usize)?;
+    let values_view_offset = values_buffer_view
+        .get("byteOffset")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as usize;
+    let values_accessor_offset = values.get("byteOffset").and_then(Value::as_u64).unwrap_or(0) as usize;
+    let default_values_stride = element_count * component_size;
+    let values_stride = values_buffer_view
+        .get("byteStride")
+        .and_then(Value::as_u64)
+        .map(|value| value as usize)
+        .unwrap_or(default_values_stride);
+
+    Some(SparseMeta {
+        count,
+        indices_base_offset: indices_view_offset + indices_accessor_offset,
+        indices_stride,
+        indices_component_type,
+        values_base_offset: values_view_offset + values_accessor_offset,
+        values_stride,
     })
 }
 
+/// Read a single element index out of a sparse override block's `indices`
+/// array, decoding per `indices_component_type` (glTF permits unsigned byte,
+/// short, or int for sparse indices).
+pub(super) fn read_sparse_index(bin: &[u8], sparse: &SparseMeta, entry: usize) -> Option<usize> {
+    let offset = sparse.indices_base_offset + entry * sparse.indices_stride;
+    match sparse.indices_component_type {
+        5121 => bin.get(offset).map(|&value| value as usize),
+        5123 => {
+            let bytes = bin.get(offset..offset + 2)?;
+            Some(u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+        }
+        5125 => {
+            let bytes = bin.get(offset..offset + 4)?;
+            Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+        }
+        _ => None,
+    }
+}
+
+/// Byte offset of a sparse override block's replacement element for
+/// `logical_index`, if the override set replaces that element. Indices are
+/// supposed to be strictly increasing per spec, but this scans rather than
+/// assumes it so a non-conformant file still resolves correctly instead of
+/// silently reading the wrong override.
+fn sparse_override_offset(bin: &[u8], sparse: &SparseMeta, logical_index: usize) -> Option<usize> {
+    for entry in 0..sparse.count {
+        if read_sparse_index(bin, sparse, entry)? == logical_index {
+            return Some(sparse.values_base_offset + entry * sparse.values_stride);
+        }
+    }
+    None
+}
+
+/// Base byte offset of accessor element `index`: the matching sparse
+/// override slot if one replaces it, otherwise the dense base.
+fn resolved_element_offset(bin: &[u8], meta: &AccessorMeta, index: usize) -> usize {
+    meta.sparse
+        .as_ref()
+        .and_then(|sparse| sparse_override_offset(bin, sparse, index))
+        .unwrap_or(meta.base_offset + index * meta.stride)
+}
+
 pub(super) fn read_joint_slot(
     bin: &[u8],
     meta: &AccessorMeta,
     vertex: usize,
     lane: usize,
 ) -> Option<u16> {
-    let offset = meta.base_offset
-        + vertex * meta.stride
-        + lane
-            * match meta.component_type {
-                5121 => 1,
-                5123 => 2,
-                _ => return None,
-            };
+    let component_size = match meta.component_type {
+        5121 => 1,
+        5123 => 2,
+        _ => return None,
+    };
+    let offset = resolved_element_offset(bin, meta, vertex) + lane * component_size;
 
     match meta.component_type {
         5121 => bin.get(offset).copied().map(|value| value as u16),
@@ -104,6 +231,10 @@ pub(super) fn read_joint_slot(
     }
 }
 
+/// Write a joint slot. If `vertex` falls inside a sparse override set, the
+/// write lands in the override's `values` block so the next read (which
+/// prefers the override) sees it; elements outside the override set write
+/// straight to the dense base, which is already the effective value for them.
 pub(super) fn write_joint_slot(
     bin: &mut [u8],
     meta: &AccessorMeta,
@@ -111,14 +242,12 @@ pub(super) fn write_joint_slot(
     lane: usize,
     value: u16,
 ) {
-    let offset = meta.base_offset
-        + vertex * meta.stride
-        + lane
-            * match meta.component_type {
-                5121 => 1,
-                5123 => 2,
-                _ => return,
-            };
+    let component_size = match meta.component_type {
+        5121 => 1,
+        5123 => 2,
+        _ => return,
+    };
+    let offset = resolved_element_offset(bin, meta, vertex) + lane * component_size;
 
     match meta.component_type {
         5121 => {
@@ -135,20 +264,46 @@ pub(super) fn write_joint_slot(
     }
 }
 
+/// Whether `component_type` is one of the three component types glTF permits
+/// for a skin `WEIGHTS_n` accessor: `FLOAT`, normalized `UNSIGNED_BYTE`, or
+/// normalized `UNSIGNED_SHORT`.
+pub(super) fn is_weight_component_type(component_type: u64) -> bool {
+    matches!(component_type, 5121 | 5123 | 5126)
+}
+
+/// Read a skin weight. A normalized (`accessor.normalized == true`)
+/// `UNSIGNED_BYTE`/`UNSIGNED_SHORT` lane decodes as `value / 255.0` /
+/// `value / 65535.0` to match `FLOAT` lanes, since some VRM exporters pack
+/// weights into smaller component types to shrink buffers; a non-normalized
+/// integer lane (non-conformant for `WEIGHTS_n`, but tolerated rather than
+/// silently mis-scaled) reads back as the raw integer value instead.
 pub(super) fn read_weight_f32(
     bin: &[u8],
     meta: &AccessorMeta,
     vertex: usize,
     lane: usize,
 ) -> Option<f32> {
-    if meta.component_type != 5126 {
-        return None;
+    let offset = resolved_element_offset(bin, meta, vertex);
+    match meta.component_type {
+        5126 => {
+            let bytes = bin.get(offset + lane * 4..offset + lane * 4 + 4)?;
+            Some(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        }
+        5121 => {
+            let value = *bin.get(offset + lane)? as f32;
+            Some(if meta.normalized { value / u8::MAX as f32 } else { value })
+        }
+        5123 => {
+            let bytes = bin.get(offset + lane * 2..offset + lane * 2 + 2)?;
+            let value = u16::from_le_bytes([bytes[0], bytes[1]]) as f32;
+            Some(if meta.normalized { value / u16::MAX as f32 } else { value })
+        }
+        _ => None,
     }
-    let offset = meta.base_offset + vertex * meta.stride + lane * 4;
-    let bytes = bin.get(offset..offset + 4)?;
-    Some(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
 }
 
+/// Write a skin weight, quantizing back into the accessor's original
+/// component type (see [`read_weight_f32`]) so stride/offsets stay valid.
 pub(super) fn write_weight_f32(
     bin: &mut [u8],
     meta: &AccessorMeta,
@@ -156,12 +311,89 @@ pub(super) fn write_weight_f32(
     lane: usize,
     value: f32,
 ) {
-    if meta.component_type != 5126 {
-        return;
+    let offset = resolved_element_offset(bin, meta, vertex);
+    match meta.component_type {
+        5126 => {
+            if let Some(slice) = bin.get_mut(offset + lane * 4..offset + lane * 4 + 4) {
+                slice.copy_from_slice(&value.to_le_bytes());
+            }
+        }
+        5121 => {
+            if let Some(byte) = bin.get_mut(offset + lane) {
+                *byte = if meta.normalized {
+                    (value.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8
+                } else {
+                    value.round() as u8
+                };
+            }
+        }
+        5123 => {
+            if let Some(slice) = bin.get_mut(offset + lane * 2..offset + lane * 2 + 2) {
+                let quantized = if meta.normalized {
+                    (value.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16
+                } else {
+                    value.round() as u16
+                };
+                slice.copy_from_slice(&quantized.to_le_bytes());
+            }
+        }
+        _ => {}
     }
-    let offset = meta.base_offset + vertex * meta.stride + lane * 4;
-    if let Some(slice) = bin.get_mut(offset..offset + 4) {
-        slice.copy_from_slice(&value.to_le_bytes());
+}
+
+/// Write four already-renormalized (summing to ~1.0) weight lanes for one
+/// vertex via [`write_weight_f32`], except for normalized
+/// `UNSIGNED_BYTE`/`UNSIGNED_SHORT` accessors: there, quantizing each lane
+/// independently can leave the stored integers summing to one off the
+/// type's max (e.g. 254 or 256 instead of 255) due to independent rounding,
+/// which a strict SL/glTF consumer may reject. The rounding remainder is
+/// folded into the largest lane instead, so the quantized weights always
+/// sum to exactly `u8::MAX`/`u16::MAX`.
+pub(super) fn write_weight_lanes_f32(
+    bin: &mut [u8],
+    meta: &AccessorMeta,
+    vertex: usize,
+    weights: [f32; 4],
+) {
+    let quantized_max: i64 = match (meta.component_type, meta.normalized) {
+        (5121, true) => u8::MAX as i64,
+        (5123, true) => u16::MAX as i64,
+        _ => {
+            for (lane, &weight) in weights.iter().enumerate() {
+                write_weight_f32(bin, meta, vertex, lane, weight);
+            }
+            return;
+        }
+    };
+
+    let mut quantized: [i64; 4] =
+        weights.map(|weight| (weight.clamp(0.0, 1.0) * quantized_max as f32).round() as i64);
+    let remainder = quantized_max - quantized.iter().sum::<i64>();
+    if remainder != 0 {
+        let largest_lane = weights
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(lane, _)| lane)
+            .unwrap_or(0);
+        quantized[largest_lane] = (quantized[largest_lane] + remainder).clamp(0, quantized_max);
+    }
+
+    let offset = resolved_element_offset(bin, meta, vertex);
+    for (lane, &value) in quantized.iter().enumerate() {
+        match meta.component_type {
+            5121 => {
+                if let Some(byte) = bin.get_mut(offset + lane) {
+                    *byte = value as u8;
+                }
+            }
+            5123 => {
+                if let Some(slice) = bin.get_mut(offset + lane * 2..offset + lane * 2 + 2) {
+                    slice.copy_from_slice(&(value as u16).to_le_bytes());
+                }
+            }
+            _ => {}
+        }
     }
 }
 
@@ -179,6 +411,56 @@ pub(super) fn write_mat4_f32_le(bin: &mut [u8], offset: usize, matrix: &Matrix4<
     }
 }
 
+/// Read one element of any f32-component accessor (SCALAR/VEC2/VEC3/VEC4) as
+/// a flat little-endian float slice, e.g. an animation sampler's `input`
+/// (time) or `output` (translation/rotation/scale) accessor.
+pub(super) fn read_f32_element(bin: &[u8], meta: &AccessorMeta, index: usize) -> Option<Vec<f32>> {
+    if meta.component_type != 5126 || index >= meta.count {
+        return None;
+    }
+    let element_count = match meta.accessor_type {
+        "SCALAR" => 1,
+        "VEC2" => 2,
+        "VEC3" => 3,
+        "VEC4" => 4,
+        _ => return None,
+    };
+
+    let offset = meta.base_offset + index * meta.stride;
+    let mut values = Vec::with_capacity(element_count);
+    for lane in 0..element_count {
+        let byte_offset = offset + lane * 4;
+        let bytes = bin.get(byte_offset..byte_offset + 4)?;
+        values.push(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+    }
+    Some(values)
+}
+
+/// Write one element back into an f32-component accessor (c.f.
+/// [`read_f32_element`]), e.g. a remapped `TEXCOORD_0` UV pair after atlas
+/// packing. `values` must match the accessor's element count; a mismatch is
+/// a no-op rather than a panic.
+pub(super) fn write_f32_element(bin: &mut [u8], meta: &AccessorMeta, index: usize, values: &[f32]) {
+    let element_count = match meta.accessor_type {
+        "SCALAR" => 1,
+        "VEC2" => 2,
+        "VEC3" => 3,
+        "VEC4" => 4,
+        _ => return,
+    };
+    if meta.component_type != 5126 || index >= meta.count || values.len() != element_count {
+        return;
+    }
+
+    let offset = meta.base_offset + index * meta.stride;
+    for (lane, value) in values.iter().enumerate() {
+        let byte_offset = offset + lane * 4;
+        if let Some(slice) = bin.get_mut(byte_offset..byte_offset + 4) {
+            slice.copy_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
 pub(super) fn read_mat4_from_accessor(
     bin: &[u8],
     meta: &AccessorMeta,
@@ -191,7 +473,7 @@ pub(super) fn read_mat4_from_accessor(
         return None;
     }
 
-    let offset = meta.base_offset + index * meta.stride;
+    let offset = resolved_element_offset(bin, meta, index);
     if offset + 64 > bin.len() {
         return None;
     }
@@ -229,6 +511,54 @@ pub(super) fn collect_parent_index_map_from_json(json: &Value) -> HashMap<usize,
     parent_map
 }
 
+/// Index of the `scenes[]` entry whose node tree (following `children`
+/// transitively from its root nodes) contains `node_index`. Falls back to
+/// `0` if no scene contains it, so a document with only one scene (the
+/// overwhelmingly common case) still gets a sane answer.
+pub(super) fn scene_containing_node(json: &Value, node_index: usize) -> usize {
+    let Some(scenes) = json.get("scenes").and_then(Value::as_array) else {
+        return 0;
+    };
+    let nodes = json.get("nodes").and_then(Value::as_array);
+
+    for (scene_index, scene) in scenes.iter().enumerate() {
+        let Some(roots) = scene.get("nodes").and_then(Value::as_array) else {
+            continue;
+        };
+        let mut stack: Vec<usize> = roots
+            .iter()
+            .filter_map(|value| value.as_u64().map(|v| v as usize))
+            .collect();
+        let mut visited = HashSet::new();
+        while let Some(current) = stack.pop() {
+            if current == node_index {
+                return scene_index;
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            if let Some(children) = nodes
+                .and_then(|nodes| nodes.get(current))
+                .and_then(|node| node.get("children"))
+                .and_then(Value::as_array)
+            {
+                stack.extend(children.iter().filter_map(|value| value.as_u64().map(|v| v as usize)));
+            }
+        }
+    }
+
+    0
+}
+
+/// Find the index of the node carrying the exact `name`, or `None` if no
+/// node does (the first match if more than one does).
+pub(super) fn node_index_by_name(json: &Value, name: &str) -> Option<usize> {
+    json.get("nodes")
+        .and_then(Value::as_array)?
+        .iter()
+        .position(|node| node.get("name").and_then(Value::as_str) == Some(name))
+}
+
 /// Collect all node names from the glTF JSON nodes array.
 pub(super) fn collect_node_name_set_from_json(json: &Value) -> std::collections::HashSet<String> {
     json.get("nodes")
@@ -298,16 +628,125 @@ pub(super) fn node_to_local_matrix(node: &Value) -> Matrix4<f32> {
         })
         .unwrap_or(Vector3::new(1.0, 1.0, 1.0));
 
+    compose_trs(translation, rotation, scale)
+}
+
+/// Compose a translation/rotation/scale triple back into a local transform matrix.
+pub(super) fn compose_trs(
+    translation: Vector3<f32>,
+    rotation: UnitQuaternion<f32>,
+    scale: Vector3<f32>,
+) -> Matrix4<f32> {
     let translation_matrix = Translation3::from(translation).to_homogeneous();
     let rotation_matrix = rotation.to_homogeneous();
     let scale_matrix = Matrix4::new_nonuniform_scaling(&scale);
     translation_matrix * rotation_matrix * scale_matrix
 }
 
+/// Result of Gram-Schmidt orthogonalizing an affine matrix's basis columns:
+/// the shear-free scale/rotation, plus the off-diagonal shear coefficients
+/// that orthogonalization pulled out of the original (possibly sheared)
+/// basis.
+struct OrthogonalizedBasis {
+    scale: Vector3<f32>,
+    rotation: UnitQuaternion<f32>,
+    /// Off-diagonal shear coefficients in x→y, x→z, y→z order.
+    shear: Vector3<f32>,
+}
+
+/// Gram-Schmidt orthogonalize a matrix's `x, y, z` basis columns (in that
+/// order), recording the off-diagonal shear coefficients removed at each
+/// step instead of silently discarding them, then polar-decompose the
+/// resulting orthonormal frame into a [`UnitQuaternion`]. A negative z-scale
+/// is recovered when the orthogonalized frame is left-handed.
+fn orthogonalize_affine_basis(matrix: &Matrix4<f32>) -> OrthogonalizedBasis {
+    let basis_x = Vector3::new(matrix[(0, 0)], matrix[(1, 0)], matrix[(2, 0)]);
+    let basis_y = Vector3::new(matrix[(0, 1)], matrix[(1, 1)], matrix[(2, 1)]);
+    let basis_z = Vector3::new(matrix[(0, 2)], matrix[(1, 2)], matrix[(2, 2)]);
+
+    let scale_x = basis_x.norm();
+    let axis_x = if scale_x > 1e-8 {
+        basis_x / scale_x
+    } else {
+        Vector3::new(1.0, 0.0, 0.0)
+    };
+
+    let shear_xy = axis_x.dot(&basis_y);
+    let ortho_y = basis_y - axis_x * shear_xy;
+    let scale_y = ortho_y.norm();
+    let axis_y = if scale_y > 1e-8 {
+        ortho_y / scale_y
+    } else {
+        Vector3::new(0.0, 1.0, 0.0)
+    };
+
+    let shear_xz = axis_x.dot(&basis_z);
+    let shear_yz = axis_y.dot(&basis_z);
+    let ortho_z = basis_z - axis_x * shear_xz - axis_y * shear_yz;
+    let mut scale_z = ortho_z.norm();
+    let mut axis_z = if scale_z > 1e-8 {
+        ortho_z / scale_z
+    } else {
+        axis_x.cross(&axis_y)
+    };
+
+    if axis_x.cross(&axis_y).dot(&axis_z) < 0.0 {
+        scale_z = -scale_z;
+        axis_z = -axis_z;
+    }
+
+    let rotation_matrix = Matrix3::from_columns(&[axis_x, axis_y, axis_z]);
+    let rotation = UnitQuaternion::from_matrix(&rotation_matrix);
+
+    OrthogonalizedBasis {
+        scale: Vector3::new(scale_x, scale_y, scale_z),
+        rotation,
+        shear: Vector3::new(shear_xy, shear_xz, shear_yz),
+    }
+}
+
+/// Decompose a local transform matrix into translation, rotation and scale
+/// via proper Gram-Schmidt orthogonalization of the basis columns (see
+/// [`orthogonalize_affine_basis`]), rather than naively normalizing each
+/// column independently, which silently discards any shear a non-uniformly
+/// scaled parent space introduces. Callers that need to know whether shear
+/// was actually present should use [`shear_magnitude`] instead/in addition.
+pub(super) fn decompose_trs(
+    matrix: &Matrix4<f32>,
+) -> (Vector3<f32>, UnitQuaternion<f32>, Vector3<f32>) {
+    let translation = Vector3::new(matrix[(0, 3)], matrix[(1, 3)], matrix[(2, 3)]);
+    let basis = orthogonalize_affine_basis(matrix);
+    (translation, basis.rotation, basis.scale)
+}
+
+/// Magnitude of the shear [`decompose_trs`] discards when reducing a local
+/// transform matrix down to translation/rotation/scale. Near zero for a pure
+/// TRS matrix; non-negligible when a non-uniformly scaled parent space (or a
+/// hand-authored rig) has introduced real shear into this node's basis.
+pub(super) fn shear_magnitude(matrix: &Matrix4<f32>) -> f32 {
+    orthogonalize_affine_basis(matrix).shear.norm()
+}
+
 /// Compute world matrices from local transforms and parent links.
 pub(super) fn compute_node_world_matrices(
     local_matrices: &[Matrix4<f32>],
     parent_map: &HashMap<usize, usize>,
+) -> Vec<Matrix4<f32>> {
+    compute_node_world_matrices_with_scale_isolation(local_matrices, parent_map, &HashSet::new())
+}
+
+/// Compute world matrices from local transforms and parent links, forcing
+/// each node in `scale_isolated_nodes` to ignore its parent's scale — the
+/// node still inherits the parent's translation and rotation, but is
+/// composed against a parent world matrix with scale reset to 1, so a
+/// non-uniformly scaled ancestor doesn't multiply down into it. Mirrors the
+/// "don't inherit scale" bone flag skeletal runtimes expose, for joints
+/// (e.g. SL collision volumes) whose own authored size must stay stable
+/// regardless of what an ancestor happens to be scaled to.
+pub(super) fn compute_node_world_matrices_with_scale_isolation(
+    local_matrices: &[Matrix4<f32>],
+    parent_map: &HashMap<usize, usize>,
+    scale_isolated_nodes: &HashSet<usize>,
 ) -> Vec<Matrix4<f32>> {
     let mut worlds = vec![Matrix4::<f32>::identity(); local_matrices.len()];
     let mut resolved = vec![false; local_matrices.len()];
@@ -317,6 +756,7 @@ pub(super) fn compute_node_world_matrices(
             index,
             local_matrices,
             parent_map,
+            scale_isolated_nodes,
             &mut worlds,
             &mut resolved,
         );
@@ -325,10 +765,38 @@ pub(super) fn compute_node_world_matrices(
     worlds
 }
 
+/// Transform a point from a node's local space into scene (world) space,
+/// given the node's already-resolved world matrix (e.g. from
+/// [`compute_node_world_matrices`]).
+pub(super) fn local_to_world(world_matrix: &Matrix4<f32>, local_point: &Vector3<f32>) -> Vector3<f32> {
+    let homogeneous =
+        world_matrix * Vector4::new(local_point.x, local_point.y, local_point.z, 1.0);
+    Vector3::new(homogeneous.x, homogeneous.y, homogeneous.z)
+}
+
+/// Transform a point from scene (world) space back into a node's local
+/// space — the inverse of [`local_to_world`]. `None` if the world matrix is
+/// singular.
+pub(super) fn world_to_local(
+    world_matrix: &Matrix4<f32>,
+    world_point: &Vector3<f32>,
+) -> Option<Vector3<f32>> {
+    let inverse = world_matrix.try_inverse()?;
+    Some(local_to_world(&inverse, world_point))
+}
+
+/// Reconstruct `world` with its scale forced to 1, preserving translation
+/// and rotation, for a child that declines to inherit it.
+fn without_scale(world: Matrix4<f32>) -> Matrix4<f32> {
+    let (translation, rotation, _scale) = decompose_trs(&world);
+    compose_trs(translation, rotation, Vector3::new(1.0, 1.0, 1.0))
+}
+
 fn resolve_world_matrix(
     index: usize,
     local_matrices: &[Matrix4<f32>],
     parent_map: &HashMap<usize, usize>,
+    scale_isolated_nodes: &HashSet<usize>,
     worlds: &mut [Matrix4<f32>],
     resolved: &mut [bool],
 ) {
@@ -337,8 +805,20 @@ fn resolve_world_matrix(
     }
 
     let world = if let Some(parent_index) = parent_map.get(&index).copied() {
-        resolve_world_matrix(parent_index, local_matrices, parent_map, worlds, resolved);
-        worlds[parent_index] * local_matrices[index]
+        resolve_world_matrix(
+            parent_index,
+            local_matrices,
+            parent_map,
+            scale_isolated_nodes,
+            worlds,
+            resolved,
+        );
+        let parent_world = if scale_isolated_nodes.contains(&index) {
+            without_scale(worlds[parent_index])
+        } else {
+            worlds[parent_index]
+        };
+        parent_world * local_matrices[index]
     } else {
         local_matrices[index]
     };
@@ -355,39 +835,8 @@ pub(super) fn set_node_local_matrix(node: &mut Value, matrix: &Matrix4<f32>) {
         return;
     };
 
-    let translation = Vector3::new(matrix[(0, 3)], matrix[(1, 3)], matrix[(2, 3)]);
-
-    let basis_x = Vector3::new(matrix[(0, 0)], matrix[(1, 0)], matrix[(2, 0)]);
-    let basis_y = Vector3::new(matrix[(0, 1)], matrix[(1, 1)], matrix[(2, 1)]);
-    let basis_z = Vector3::new(matrix[(0, 2)], matrix[(1, 2)], matrix[(2, 2)]);
-
-    let mut scale_x = basis_x.norm();
-    let scale_y = basis_y.norm();
-    let scale_z = basis_z.norm();
-
-    let mut rot_x = if scale_x > 1e-8 {
-        basis_x / scale_x
-    } else {
-        Vector3::new(1.0, 0.0, 0.0)
-    };
-    let rot_y = if scale_y > 1e-8 {
-        basis_y / scale_y
-    } else {
-        Vector3::new(0.0, 1.0, 0.0)
-    };
-    let rot_z = if scale_z > 1e-8 {
-        basis_z / scale_z
-    } else {
-        Vector3::new(0.0, 0.0, 1.0)
-    };
-
-    if rot_x.cross(&rot_y).dot(&rot_z) < 0.0 {
-        scale_x = -scale_x;
-        rot_x = -rot_x;
-    }
-
-    let rotation_matrix = Matrix3::from_columns(&[rot_x, rot_y, rot_z]);
-    let rotation = UnitQuaternion::from_matrix(&rotation_matrix);
+    let (translation, rotation, scale) = decompose_trs(matrix);
+    let (scale_x, scale_y, scale_z) = (scale.x, scale.y, scale.z);
 
     object.remove("translation");
     object.remove("rotation");
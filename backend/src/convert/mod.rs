@@ -1,43 +1,74 @@
+mod bone_remap;
+mod bvh;
 mod diagnostic;
+mod error;
 mod geometry;
 mod gltf_utils;
+mod profile;
 mod skeleton;
+mod skin_diagnostics;
 mod skinning;
+mod texture_atlas;
 mod types;
 mod validation;
 
 use std::{borrow::Cow, collections::HashMap, fs, io::Cursor, path::Path};
 
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result};
 use gltf::{binary::Glb, import};
 use image::ImageFormat;
+use nalgebra::Matrix4;
 use serde_json::Value;
 
+use crate::cancellation::CancellationToken;
 use crate::texture::{ResizeInterpolation, resize_texture_to_max};
 
 // Re-export public types for callers of this module.
+pub use error::ConvertError;
+pub use profile::SkeletonProfile;
 pub use types::{
-    AnalysisReport, ConversionReport, ConvertOptions, Severity, TextureInfo, UploadFeeEstimate,
-    ValidationIssue,
+    AnalysisReport, BoneRemapAction, BoneRemapMatcher, BoneRemapRule, ConversionReport,
+    ConversionStage, ConvertOptions, ProgressCallback, Severity, SkinDumpEntry, SkinDumpReport,
+    SkinRepairStats, SkinWeightRebakeStats, TextureInfo, UploadFeeEstimate, ValidationIssue,
 };
 
 // Pull in sub-module helpers used in the orchestration functions below.
+use bvh::{bvh_path_for_output, export_skeleton_to_bvh};
 use diagnostic::{
+    DEFAULT_WORLD_BIND_DISTANCE_WARN_THRESHOLD, analyze_texture_content,
     collect_output_texture_infos, diagnostic_log_path_for_output, parse_glb_json,
     write_conversion_diagnostic_log,
 };
-use geometry::{bake_scale_into_geometry, collect_mesh_statistics, estimate_height_cm};
+use geometry::{
+    bake_scale_into_geometry, collect_mesh_statistics, estimate_height_cm,
+    split_oversized_primitives,
+};
+use gltf_utils::{collect_parent_index_map_from_json, compute_node_world_matrices, node_to_local_matrix};
 use skeleton::{
-    ensure_target_bones_exist_after_rename, normalize_sl_bone_rotations,
-    promote_pelvis_to_scene_root, reconstruct_sl_core_hierarchy, regenerate_inverse_bind_matrices,
-    rename_bones, set_skin_skeleton_root, validate_bone_conversion_preconditions,
+    apply_sl_joint_position_overrides, collect_node_shear_issues, correct_a_pose_to_t_pose,
+    diagnose_bind_pose_drift, ensure_target_bones_exist_after_rename, normalize_sl_bone_rotations,
+    promote_pelvis_to_scene_root, reconstruct_extended_bone_hierarchy,
+    reconstruct_sl_core_hierarchy, regenerate_inverse_bind_matrices, rename_bones,
+    rename_extended_bones, repose_limbs_to_sl_t_pose, retarget_secondary_bone_nodes,
+    retarget_to_tpose, set_skin_skeleton_root, snap_bones_to_canonical_sl_rest_skeleton,
+    synthesize_fitted_mesh_collision_volumes, synthesize_missing_intermediate_bones,
+    validate_bone_conversion_preconditions, validate_sl_rest_skeleton_positions,
+};
+use skinning::{
+    DEFAULT_SKIN_EXPLOSION_TOLERANCE_MULTIPLIER, collect_skin_weight_issues,
+    optimize_skinning_weights_and_joints, rebake_skin_weights_for_sl_compliance,
+    simulate_pose_deformation, strip_redundant_second_influence_set,
 };
-use skinning::{optimize_skinning_weights_and_joints, remap_unmapped_bone_weights};
+use texture_atlas::{TextureAtlasStats, apply_texture_atlas};
 use validation::{
-    collect_mapped_bones, collect_missing_required_bones, collect_node_names,
-    collect_parent_index_map, estimate_texture_fee, extract_author, extract_humanoid_bone_nodes,
-    extract_model_name, remove_unsupported_features, remove_vrm_extensions_and_extras,
-    validate_hierarchy, validate_vroid_model,
+    collect_mapped_bones, collect_mapped_extended_bones, collect_missing_required_bones,
+    collect_node_names, collect_parent_index_map, estimate_texture_fee, extract_author,
+    extract_extended_bone_nodes, extract_humanoid_bone_nodes, extract_model_name,
+    extract_secondary_bone_nodes, extract_vrm_extension_bone_nodes, fee_per_texture,
+    load_bone_map_override, merge_bone_map_override, remove_unsupported_features,
+    remove_vrm_extensions_and_extras,
+    synthesizable_intermediate_bone, validate_bone_left_right_symmetry, validate_hierarchy,
+    validate_vroid_model,
 };
 
 // ─── Public API ───────────────────────────────────────────────────────────────
@@ -115,7 +146,19 @@ pub fn write_final_validation_checklist(
 }
 
 /// Analyze a VRM/GLB file and return validation + diagnostic information.
-pub fn analyze_vrm(input_path: &Path, options: ConvertOptions) -> Result<AnalysisReport> {
+pub fn analyze_vrm(
+    input_path: &Path,
+    options: ConvertOptions,
+    mut progress: Option<&mut ProgressCallback>,
+    cancellation: Option<&CancellationToken>,
+) -> Result<AnalysisReport> {
+    if let Some(progress) = progress.as_deref_mut() {
+        progress(ConversionStage::Validate, 0.0);
+    }
+    if cancellation.is_some_and(CancellationToken::is_cancelled) {
+        return Err(ConvertError::cancelled().into());
+    }
+
     let input_bytes = fs::read(input_path)
         .with_context(|| format!("failed to read input file: {}", input_path.display()))?;
     let input_glb = Glb::from_slice(&input_bytes).context("input VRM is not a GLB container")?;
@@ -135,35 +178,125 @@ pub fn analyze_vrm(input_path: &Path, options: ConvertOptions) -> Result<Analysi
         });
     }
 
-    let humanoid_bone_nodes = extract_humanoid_bone_nodes(&input_json);
-    let node_names = collect_node_names(&document);
-    let parent_index_map = collect_parent_index_map(&document);
-    let missing_required_bones = collect_missing_required_bones(&humanoid_bone_nodes);
+    let skeleton_profile = SkeletonProfile::resolve(options.skeleton_profile_path.as_deref())?;
 
-    for missing in &missing_required_bones {
+    let extension_bone_nodes = extract_vrm_extension_bone_nodes(&input_json);
+    let mut humanoid_bone_nodes = extract_humanoid_bone_nodes(&input_json, &skeleton_profile);
+    let heuristically_matched: Vec<&str> = humanoid_bone_nodes
+        .keys()
+        .filter(|name| !extension_bone_nodes.contains_key(*name))
+        .map(String::as_str)
+        .collect();
+    if !heuristically_matched.is_empty() {
+        let mut sorted = heuristically_matched;
+        sorted.sort_unstable();
         issues.push(ValidationIssue {
-            severity: Severity::Error,
-            code: "MISSING_REQUIRED_BONE".to_string(),
-            message: format!("[ERROR] Required bone '{}' was not found", missing),
+            severity: Severity::Warning,
+            code: "HEURISTIC_BONE_MATCH".to_string(),
+            message: format!(
+                "⚠️ No VRM humanoid extension data for: {}. Matched by node-name heuristics instead — verify the mapping before uploading",
+                sorted.join(", ")
+            ),
         });
     }
+    if let Some(bone_map_override_path) = options.bone_map_override_path.as_deref() {
+        let overrides = load_bone_map_override(Path::new(bone_map_override_path))?;
+        issues.extend(merge_bone_map_override(
+            &input_json,
+            &mut humanoid_bone_nodes,
+            &overrides,
+        ));
+    }
+    let secondary_bone_nodes = if options.retarget_secondary_bones {
+        extract_secondary_bone_nodes(&input_json, &skeleton_profile)
+    } else {
+        HashMap::new()
+    };
+    let extended_bone_nodes = if options.include_extended_bones {
+        extract_extended_bone_nodes(&input_json)
+    } else {
+        HashMap::new()
+    };
+    let node_names = collect_node_names(&document);
+    let parent_index_map = collect_parent_index_map(&document);
+    let missing_required_bones = collect_missing_required_bones(&humanoid_bone_nodes, &skeleton_profile);
+
+    // A missing intermediate bone with both a required ancestor and
+    // descendant still present can be repaired by synthesizing a
+    // pass-through node during conversion (see
+    // `skeleton::synthesize_missing_intermediate_bones`), so it only raises
+    // a warning here instead of blocking conversion outright.
+    let mut unrepairable_missing_bones = Vec::new();
+    for missing in &missing_required_bones {
+        if synthesizable_intermediate_bone(missing, &humanoid_bone_nodes, &skeleton_profile)
+            .is_some()
+        {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "SYNTHESIZED_BONE".to_string(),
+                message: format!(
+                    "⚠️ Required bone '{}' was not found; a pass-through bone will be synthesized for it during conversion",
+                    missing
+                ),
+            });
+        } else {
+            unrepairable_missing_bones.push(missing.clone());
+            issues.push(ValidationIssue {
+                severity: Severity::Error,
+                code: "MISSING_REQUIRED_BONE".to_string(),
+                message: format!("[ERROR] Required bone '{}' was not found", missing),
+            });
+        }
+    }
 
-    issues.extend(validate_hierarchy(&humanoid_bone_nodes, &parent_index_map));
+    issues.extend(validate_hierarchy(
+        &humanoid_bone_nodes,
+        &parent_index_map,
+        &skeleton_profile,
+    ));
+    let source_locals: Vec<Matrix4<f32>> = input_json["nodes"]
+        .as_array()
+        .map(|nodes| nodes.iter().map(node_to_local_matrix).collect())
+        .unwrap_or_default();
+    let source_parent_map = collect_parent_index_map_from_json(&input_json);
+    let source_node_worlds = compute_node_world_matrices(&source_locals, &source_parent_map);
+    issues.extend(validate_bone_left_right_symmetry(
+        &humanoid_bone_nodes,
+        &source_parent_map,
+        &source_node_worlds,
+    ));
     issues.extend(validate_bone_conversion_preconditions(
         &input_json,
         &humanoid_bone_nodes,
     ));
+    issues.extend(collect_skin_weight_issues(
+        &input_json,
+        input_glb.bin.as_deref().unwrap_or(&[]),
+        &humanoid_bone_nodes,
+        &secondary_bone_nodes,
+        &skeleton_profile,
+    ));
 
-    let (total_vertices, total_polygons, mut geometry_issues) = collect_mesh_statistics(&document);
+    let (total_vertices, total_polygons, mut geometry_issues) =
+        collect_mesh_statistics(&document, options.split_oversized_primitives);
     issues.append(&mut geometry_issues);
 
     let texture_infos: Vec<TextureInfo> = images
         .iter()
         .enumerate()
-        .map(|(index, image)| TextureInfo {
-            index,
-            width: image.width,
-            height: image.height,
+        .map(|(index, image)| {
+            let (channel_count, has_alpha_channel, alpha_channel_used, average_color) =
+                analyze_texture_content(image);
+            TextureInfo {
+                index,
+                width: image.width,
+                height: image.height,
+                channel_count,
+                has_alpha_channel,
+                alpha_channel_used,
+                is_opaque: !has_alpha_channel || !alpha_channel_used,
+                average_color,
+            }
         })
         .collect();
 
@@ -208,10 +341,14 @@ pub fn analyze_vrm(input_path: &Path, options: ConvertOptions) -> Result<Analysi
         });
     }
 
-    let fee_estimate = estimate_texture_fee(&texture_infos, options.texture_auto_resize);
+    let fee_estimate = estimate_texture_fee(&texture_infos, options.texture_auto_resize, None);
 
     let estimated_height_cm = estimate_height_cm(&document, &buffers).unwrap_or(170.0);
 
+    if let Some(progress) = progress.as_deref_mut() {
+        progress(ConversionStage::Validate, 1.0);
+    }
+
     Ok(AnalysisReport {
         model_name: extract_model_name(&input_json)
             .unwrap_or_else(|| input_path.to_string_lossy().to_string()),
@@ -222,7 +359,8 @@ pub fn analyze_vrm(input_path: &Path, options: ConvertOptions) -> Result<Analysi
         total_vertices,
         total_polygons,
         mapped_bones: collect_mapped_bones(&humanoid_bone_nodes),
-        missing_required_bones,
+        mapped_extended_bones: collect_mapped_extended_bones(&extended_bone_nodes),
+        missing_required_bones: unrepairable_missing_bones,
         texture_infos,
         fee_estimate,
         issues,
@@ -234,16 +372,45 @@ pub fn convert_vrm_to_gdb(
     input_path: &Path,
     output_path: &Path,
     options: ConvertOptions,
+    mut progress: Option<&mut ProgressCallback>,
+    cancellation: Option<&CancellationToken>,
 ) -> Result<ConversionReport> {
-    let analysis = analyze_vrm(input_path, options)?;
+    let analysis = analyze_vrm(
+        input_path,
+        options.clone(),
+        progress.as_deref_mut(),
+        cancellation,
+    )?;
     let input_json = parse_glb_json(input_path)?;
-    let humanoid_bone_nodes = extract_humanoid_bone_nodes(&input_json);
+    let skeleton_profile = SkeletonProfile::resolve(options.skeleton_profile_path.as_deref())?;
+    let mut humanoid_bone_nodes = extract_humanoid_bone_nodes(&input_json, &skeleton_profile);
+    if let Some(bone_map_override_path) = options.bone_map_override_path.as_deref() {
+        // Warnings from this merge are already in `analysis.issues` from the
+        // equivalent merge inside `analyze_vrm` above; discard this copy
+        // rather than reporting it twice.
+        let overrides = load_bone_map_override(Path::new(bone_map_override_path))?;
+        merge_bone_map_override(&input_json, &mut humanoid_bone_nodes, &overrides);
+    }
 
     if !analysis.missing_required_bones.is_empty() {
-        bail!(
-            "[ERROR] Missing required bones: {}",
-            analysis.missing_required_bones.join(", ")
-        );
+        let issues: Vec<ValidationIssue> = analysis
+            .missing_required_bones
+            .iter()
+            .map(|bone| ValidationIssue {
+                severity: Severity::Error,
+                code: "MISSING_REQUIRED_BONE".to_string(),
+                message: format!("[ERROR] Required bone '{}' was not found", bone),
+            })
+            .collect();
+        return Err(ConvertError::from_issues(
+            "MISSING_REQUIRED_BONES",
+            format!(
+                "[ERROR] Missing required bones: {}",
+                analysis.missing_required_bones.join(", ")
+            ),
+            issues,
+        )
+        .into());
     }
 
     if analysis
@@ -251,33 +418,73 @@ pub fn convert_vrm_to_gdb(
         .iter()
         .any(|issue| issue.severity == Severity::Error)
     {
-        let message = analysis
+        let error_issues: Vec<ValidationIssue> = analysis
             .issues
             .iter()
             .filter(|issue| issue.severity == Severity::Error)
+            .cloned()
+            .collect();
+        let message = error_issues
+            .iter()
             .map(|issue| issue.message.clone())
             .collect::<Vec<String>>()
             .join(" / ");
-        bail!(message);
+        return Err(ConvertError::from_issues("VALIDATION_FAILED", message, error_issues).into());
     }
 
-    let computed_scale_factor = if analysis.estimated_height_cm > 0.0 {
+    let computed_scale_factor = if analysis.estimated_height_cm > 1e-6 {
         (options.target_height_cm / analysis.estimated_height_cm) * options.manual_scale
     } else {
         options.manual_scale
     };
 
-    transform_and_write_glb(
+    let (weight_rebake_stats, rest_skeleton_issues, atlas_stats) = transform_and_write_glb(
         input_path,
         output_path,
         computed_scale_factor,
         &humanoid_bone_nodes,
+        &skeleton_profile,
+        analysis.estimated_height_cm,
+        options.force_tpose,
+        options.repose_limbs_to_t_pose,
+        options.preserve_custom_proportions,
+        options.export_bvh_animation,
+        options.retarget_secondary_bones,
+        options.include_extended_bones,
+        &options.bone_remap_rules,
         options.texture_auto_resize,
         options.texture_resize_method,
+        options.enable_texture_atlas,
+        options.split_oversized_primitives,
+        progress,
+        cancellation,
     )?;
 
     let diagnostic_path = diagnostic_log_path_for_output(output_path);
-    write_conversion_diagnostic_log(output_path, &diagnostic_path, computed_scale_factor)?;
+    let diagnostic_validation_issues = write_conversion_diagnostic_log(
+        output_path,
+        &diagnostic_path,
+        computed_scale_factor,
+        DEFAULT_WORLD_BIND_DISTANCE_WARN_THRESHOLD,
+    )?;
+    if diagnostic_validation_issues
+        .iter()
+        .any(|issue| issue.severity == Severity::Error)
+    {
+        let error_issues: Vec<ValidationIssue> = diagnostic_validation_issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+            .cloned()
+            .collect();
+        let message = error_issues
+            .iter()
+            .map(|issue| issue.message.clone())
+            .collect::<Vec<String>>()
+            .join(" / ");
+        return Err(
+            ConvertError::from_issues("SKIN_VALIDATION_FAILED", message, error_issues).into(),
+        );
+    }
 
     let output_texture_infos = collect_output_texture_infos(output_path)?;
     let output_texture_over_1024_count = output_texture_infos
@@ -292,6 +499,37 @@ pub fn convert_vrm_to_gdb(
         .count();
 
     let mut issues = analysis.issues;
+    let weights_rebaked = weight_rebake_stats.clamped_vertex_count
+        + weight_rebake_stats.renormalized_vertex_count
+        + weight_rebake_stats.remapped_orphan_vertex_count
+        + weight_rebake_stats.dropped_vertex_count;
+    if weights_rebaked > 0 {
+        issues.push(ValidationIssue {
+            severity: Severity::Warning,
+            code: "SKIN_WEIGHTS_REBAKED".to_string(),
+            message: format!(
+                "⚠️ Rebaked skin weights for SL compliance: {} vertex/vertices clamped to 4 influences, {} renormalized, {} re-bound per the active bone remap rules, {} dropped by a Drop rule",
+                weight_rebake_stats.clamped_vertex_count,
+                weight_rebake_stats.renormalized_vertex_count,
+                weight_rebake_stats.remapped_orphan_vertex_count,
+                weight_rebake_stats.dropped_vertex_count
+            ),
+        });
+    }
+    issues.extend(rest_skeleton_issues);
+    issues.extend(diagnostic_validation_issues);
+    if atlas_stats.sheet_count > 0 {
+        issues.push(ValidationIssue {
+            severity: Severity::Info,
+            code: "TEXTURE_ATLAS_PACKED".to_string(),
+            message: format!(
+                "[INFO] Packed {} baseColorTexture(s) into {} atlas sheet(s); {} texture(s) were skipped as ineligible",
+                atlas_stats.atlased_texture_indices.len(),
+                atlas_stats.sheet_count,
+                atlas_stats.skipped_texture_count
+            ),
+        });
+    }
     issues.push(ValidationIssue {
         severity: Severity::Info,
         code: "DIAGNOSTIC_LOG_WRITTEN".to_string(),
@@ -301,6 +539,36 @@ pub fn convert_vrm_to_gdb(
         ),
     });
 
+    // Atlas packing folds several textures into shared sheets whose indices
+    // no longer line up with the input texture list, so that case still
+    // projects the "after" fee from `analysis.texture_infos` (see
+    // `estimate_texture_fee`). Otherwise, bill the textures that were
+    // actually written rather than re-projecting what the resize *should*
+    // have produced, so the reported fee can never drift from reality.
+    let fee_estimate = if atlas_stats.sheet_count > 0 {
+        estimate_texture_fee(
+            &analysis.texture_infos,
+            options.texture_auto_resize,
+            Some(&atlas_stats),
+        )
+    } else {
+        let after = output_texture_infos
+            .iter()
+            .map(|texture| fee_per_texture(texture.width, texture.height))
+            .sum::<u32>();
+        let before = analysis.fee_estimate.before_linden_dollar;
+        let reduction_percent = if before > 0 {
+            ((before.saturating_sub(after)) * 100) / before
+        } else {
+            0
+        };
+        UploadFeeEstimate {
+            before_linden_dollar: before,
+            after_resize_linden_dollar: after,
+            reduction_percent,
+        }
+    };
+
     Ok(ConversionReport {
         model_name: analysis.model_name,
         author: analysis.author,
@@ -312,15 +580,109 @@ pub fn convert_vrm_to_gdb(
         total_vertices: analysis.total_vertices,
         total_polygons: analysis.total_polygons,
         mapped_bones: analysis.mapped_bones,
+        mapped_extended_bones: analysis.mapped_extended_bones,
         texture_count: analysis.texture_infos.len(),
         texture_over_1024_count,
         output_texture_infos,
         output_texture_over_1024_count,
-        fee_estimate: analysis.fee_estimate,
+        fee_estimate,
+        clamped_weight_vertex_count: weight_rebake_stats.clamped_vertex_count,
+        renormalized_weight_vertex_count: weight_rebake_stats.renormalized_vertex_count,
+        atlased_texture_count: atlas_stats.atlased_texture_indices.len(),
+        atlas_sheet_count: atlas_stats.sheet_count,
         issues,
     })
 }
 
+/// Validate a glTF/VRM file's skin data without modifying it or requiring a
+/// successful VRM humanoid mapping (c.f. [`analyze_vrm`], which is
+/// SL-mapping aware). Useful for diagnosing why a mesh deforms badly in any
+/// viewer, independent of whether it will ever be converted for SL.
+pub fn validate_skinning(input_path: &Path) -> Result<Vec<ValidationIssue>> {
+    let bytes = fs::read(input_path)
+        .with_context(|| format!("failed to read input file: {}", input_path.display()))?;
+    let glb = Glb::from_slice(&bytes).context("input is not a GLB container")?;
+    let json: Value =
+        serde_json::from_slice(glb.json.as_ref()).context("failed to parse glTF JSON chunk")?;
+    let bin = glb.bin.as_deref().unwrap_or(&[]);
+
+    Ok(skin_diagnostics::validate_skinning(&json, bin))
+}
+
+/// Fix the per-vertex defects [`validate_skinning`] can find and write the
+/// result to `output_path`, leaving everything else in the file untouched
+/// (an inverse-bind-matrix count mismatch is reported but not repaired).
+pub fn repair_skinning(input_path: &Path, output_path: &Path) -> Result<SkinRepairStats> {
+    let bytes = fs::read(input_path)
+        .with_context(|| format!("failed to read input file: {}", input_path.display()))?;
+    let glb = Glb::from_slice(&bytes).context("input is not a GLB container")?;
+    let json: Value =
+        serde_json::from_slice(glb.json.as_ref()).context("failed to parse glTF JSON chunk")?;
+    let had_bin = glb.bin.is_some();
+    let mut bin = glb.bin.map(|chunk| chunk.into_owned()).unwrap_or_default();
+
+    let stats = skin_diagnostics::repair_skinning(&json, &mut bin);
+
+    let json_bytes = serde_json::to_vec(&json).context("failed to serialize glTF JSON")?;
+    let repaired = Glb {
+        header: glb.header,
+        json: Cow::Owned(json_bytes),
+        bin: if had_bin || !bin.is_empty() {
+            Some(Cow::Owned(bin))
+        } else {
+            None
+        },
+    };
+
+    let mut out = Vec::new();
+    repaired
+        .to_writer(&mut out)
+        .context("failed to write output GLB")?;
+    fs::write(output_path, out)
+        .with_context(|| format!("failed to write output: {}", output_path.display()))?;
+
+    Ok(stats)
+}
+
+/// Dump per-skin joint usage and influence statistics without modifying the
+/// file, for understanding a mesh's skinning before committing to a full
+/// conversion.
+pub fn dump_skinning(input_path: &Path) -> Result<SkinDumpReport> {
+    let bytes = fs::read(input_path)
+        .with_context(|| format!("failed to read input file: {}", input_path.display()))?;
+    let glb = Glb::from_slice(&bytes).context("input is not a GLB container")?;
+    let json: Value =
+        serde_json::from_slice(glb.json.as_ref()).context("failed to parse glTF JSON chunk")?;
+    let bin = glb.bin.as_deref().unwrap_or(&[]);
+
+    Ok(skin_diagnostics::dump_skinning(&json, bin))
+}
+
+/// Auto-detect `input_path`'s humanoid bone mapping and dump it as the same
+/// `{ role: sourceNodeNameOrIndex }` shape [`ConvertOptions::bone_map_override_path`]
+/// expects, so a user can save it, hand-edit entries for a rig with missing
+/// or non-standard VRM humanoid metadata, and feed the result back in as an
+/// override.
+pub fn dump_bone_map(input_path: &Path) -> Result<HashMap<String, String>> {
+    let input_json = parse_glb_json(input_path)?;
+    let skeleton_profile = SkeletonProfile::resolve(None)?;
+    let humanoid_bone_nodes = extract_humanoid_bone_nodes(&input_json, &skeleton_profile);
+    let nodes = input_json.get("nodes").and_then(Value::as_array);
+
+    Ok(humanoid_bone_nodes
+        .into_iter()
+        .map(|(role, node_index)| {
+            let source = nodes
+                .and_then(|nodes| nodes.get(node_index))
+                .and_then(|node| node.get("name"))
+                .and_then(Value::as_str)
+                .map(ToOwned::to_owned)
+                .unwrap_or_else(|| node_index.to_string());
+            (role, source)
+        })
+        .collect())
+}
+
 // ─── Private orchestration ────────────────────────────────────────────────────
 
 fn transform_and_write_glb(
@@ -328,9 +690,22 @@ fn transform_and_write_glb(
     output_path: &Path,
     scale_factor: f32,
     humanoid_bone_nodes: &HashMap<String, usize>,
+    skeleton_profile: &SkeletonProfile,
+    estimated_height_cm: f32,
+    force_tpose: bool,
+    repose_limbs_to_t_pose: bool,
+    preserve_custom_proportions: bool,
+    export_bvh_animation: bool,
+    retarget_secondary_bones: bool,
+    include_extended_bones: bool,
+    bone_remap_rules: &[BoneRemapRule],
     texture_auto_resize: bool,
     texture_resize_method: ResizeInterpolation,
-) -> Result<()> {
+    enable_texture_atlas: bool,
+    split_oversized_primitive_meshes: bool,
+    mut progress: Option<&mut ProgressCallback>,
+    cancellation: Option<&CancellationToken>,
+) -> Result<(SkinWeightRebakeStats, Vec<ValidationIssue>, TextureAtlasStats)> {
     let bytes = fs::read(input_path)
         .with_context(|| format!("failed to read input file: {}", input_path.display()))?;
     let glb = Glb::from_slice(&bytes).context("input VRM is not a GLB container")?;
@@ -340,44 +715,234 @@ fn transform_and_write_glb(
     let had_bin = glb.bin.is_some();
     let mut bin = glb.bin.map(|chunk| chunk.into_owned()).unwrap_or_default();
 
-    rename_bones(&mut json, humanoid_bone_nodes);
-    ensure_target_bones_exist_after_rename(&json, humanoid_bone_nodes)?;
-    reconstruct_sl_core_hierarchy(&mut json, humanoid_bone_nodes);
+    // Split any over-limit primitive before anything below reads the mesh's
+    // attribute/indices accessors, so every later pass (skin rebake, pose
+    // simulation, scale baking, ...) naturally sees the already-split
+    // sub-meshes like any other primitive.
+    let mut primitive_split_issues = if split_oversized_primitive_meshes {
+        split_oversized_primitives(&mut json, &mut bin)
+    } else {
+        Vec::new()
+    };
+
+    let secondary_bone_nodes = if retarget_secondary_bones {
+        extract_secondary_bone_nodes(&json, skeleton_profile)
+    } else {
+        HashMap::new()
+    };
+    let extended_bone_nodes = if include_extended_bones {
+        extract_extended_bone_nodes(&json)
+    } else {
+        HashMap::new()
+    };
+
+    // Repair any required bone missing an intermediate link (e.g. no `spine`
+    // node between `hips` and `chest`) by splicing in a synthesized
+    // pass-through node, before anything below assumes the full required
+    // chain is present. Extends `humanoid_bone_nodes` with the synthesized
+    // bones' source names, same as if VRM had mapped them directly.
+    let mut humanoid_bone_nodes = humanoid_bone_nodes.clone();
+    let synthesized_bone_issues =
+        synthesize_missing_intermediate_bones(&mut json, &mut humanoid_bone_nodes, skeleton_profile);
+
+    // Straighten VRoid's natural A-pose arms into SL's T-pose first, while
+    // bone names/indices still match `humanoid_bone_nodes`, and before any
+    // other step bakes the current (A-pose) arm orientation into place.
+    correct_a_pose_to_t_pose(&mut json, &mut bin, &humanoid_bone_nodes)?;
+    // Straighten each arm's shoulder/upperArm/lowerArm/hand segments onto
+    // SL's horizontal T-pose axis on top of the fixed-bone correction above,
+    // which doesn't cover the shoulder segment. Also gated here rather than
+    // unconditionally, for the same reason as `repose_limbs_to_t_pose`: some
+    // callers want the model's authored pose preserved as-is.
+    let mut tpose_retarget_issues = Vec::new();
+    if force_tpose {
+        tpose_retarget_issues = retarget_to_tpose(&mut json, &mut bin, &humanoid_bone_nodes)?;
+    }
+    rename_bones(&mut json, &humanoid_bone_nodes, skeleton_profile);
+    retarget_secondary_bone_nodes(&mut json, &secondary_bone_nodes, skeleton_profile);
+    rename_extended_bones(&mut json, &extended_bone_nodes);
+    ensure_target_bones_exist_after_rename(&json, &humanoid_bone_nodes, skeleton_profile)?;
+    let mut hierarchy_repair_issues =
+        reconstruct_sl_core_hierarchy(&mut json, &humanoid_bone_nodes);
+    hierarchy_repair_issues.extend(reconstruct_extended_bone_hierarchy(
+        &mut json,
+        &humanoid_bone_nodes,
+        &extended_bone_nodes,
+    ));
+    // Further straighten arms/legs into SL's T-pose rest direction with
+    // analytic two-bone IK (on top of the fixed-rotation correction above),
+    // unless the caller asked to preserve the authored pose.
+    if repose_limbs_to_t_pose {
+        repose_limbs_to_sl_t_pose(&mut json, &mut bin, &humanoid_bone_nodes)?;
+    }
     // Normalize all SL-mapped bone rotations to identity while preserving
     // their world-space positions.  Second Life reads bone bind positions from
     // the inverse-bind-matrix translations and applies its own (identity)
     // orientations in the SL skeleton; any non-identity rotation baked into
     // the node hierarchy will therefore cause incorrect deformation.
-    normalize_sl_bone_rotations(&mut json, humanoid_bone_nodes);
-    // Remap weights from unmapped VRM bones (e.g. upperChest, spring bones)
-    // to their nearest mapped-SL ancestor so that only valid SL bones remain
-    // in the skin joints list after optimization.
-    remap_unmapped_bone_weights(&mut json, &mut bin, humanoid_bone_nodes);
-    optimize_skinning_weights_and_joints(&mut json, &mut bin)?;
+    // Check for shear baked into any SL-mapped bone's local transform (e.g.
+    // from re-parenting under a non-uniformly scaled ancestor above) before
+    // normalization forces scale back to identity and erases the evidence.
+    let shear_issues = collect_node_shear_issues(&json, &humanoid_bone_nodes);
+    let world_matrices_after_normalize =
+        normalize_sl_bone_rotations(&mut json, &humanoid_bone_nodes);
+    // Compare each mapped bone's (now-normalized) world position against
+    // Second Life's canonical Bento rest-skeleton offsets so badly
+    // mis-proportioned rigs surface a warning instead of silently deforming
+    // oddly once uploaded. Skipped when joint-position overrides are being
+    // recorded below: that deviation is then intentional, not a defect.
+    let mut rest_skeleton_issues = if preserve_custom_proportions {
+        Vec::new()
+    } else {
+        validate_sl_rest_skeleton_positions(
+            &world_matrices_after_normalize,
+            &humanoid_bone_nodes,
+            estimated_height_cm,
+        )
+    };
+    rest_skeleton_issues.extend(shear_issues);
+    rest_skeleton_issues.extend(synthesized_bone_issues);
+    rest_skeleton_issues.extend(hierarchy_repair_issues);
+    rest_skeleton_issues.extend(tpose_retarget_issues);
+    rest_skeleton_issues.append(&mut primitive_split_issues);
+    // Rebake skin weights for SL's 4-influence, normalized-weight
+    // requirement: remap weights bound to unmapped VRM bones (e.g.
+    // upperChest, spring bones) per the active bone-remap rule list (default:
+    // collapse onto the nearest mapped-SL ancestor, same as before rules
+    // existed) so that only valid SL bones remain in the skin joints list
+    // after optimization, clamp to the 4 heaviest influences, and
+    // renormalize. Bones retargeted above onto an SL extension joint are
+    // left alone instead.
+    if let Some(progress) = progress.as_deref_mut() {
+        progress(ConversionStage::RemapUnmappedBoneWeights, 0.0);
+    }
+    let compiled_bone_remap_rules = bone_remap::compile_rules(bone_remap_rules)
+        .context("failed to compile bone remap rules")?;
+    let weight_rebake_stats = rebake_skin_weights_for_sl_compliance(
+        &mut json,
+        &mut bin,
+        &humanoid_bone_nodes,
+        &secondary_bone_nodes,
+        skeleton_profile,
+        &compiled_bone_remap_rules,
+        cancellation,
+    )?;
+    // The rebake above already folded any JOINTS_1/WEIGHTS_1 influences into
+    // JOINTS_0/WEIGHTS_0 and zeroed the second set; drop the now-redundant
+    // attributes so the output only ever carries SL's 4-influence set.
+    strip_redundant_second_influence_set(&mut json);
+    if let Some(progress) = progress.as_deref_mut() {
+        progress(ConversionStage::RemapUnmappedBoneWeights, 1.0);
+        progress(ConversionStage::OptimizeSkinningWeightsAndJoints, 0.0);
+    }
+    // The per-skin loop inside `optimize_skinning_weights_and_joints` is a
+    // natural progress checkpoint, so give it a stage-local callback rather
+    // than only reporting this stage as one all-or-nothing step. Scoped to a
+    // block so the reborrow of `progress` ends before the later stages below
+    // need it again.
+    {
+        let mut optimize_stage_progress = progress.as_deref_mut().map(|cb| {
+            move |fraction: f32| cb(ConversionStage::OptimizeSkinningWeightsAndJoints, fraction)
+        });
+        rest_skeleton_issues.extend(optimize_skinning_weights_and_joints(
+            &mut json,
+            &mut bin,
+            optimize_stage_progress
+                .as_mut()
+                .map(|cb| cb as &mut dyn FnMut(f32)),
+            cancellation,
+        )?);
+    }
     // Clean up wrapper nodes above mPelvis.  Keeps the topmost non-SL
     // ancestor as an identity-transform root so that skin.skeleton can
     // reference a node with no positional offset, preventing the SL viewer
     // from injecting an unwanted transform into the skinning pipeline.
-    let identity_root = promote_pelvis_to_scene_root(&mut json, humanoid_bone_nodes);
+    let identity_root =
+        promote_pelvis_to_scene_root(&mut json, &humanoid_bone_nodes, skeleton_profile);
     // Set skin.skeleton to the identity root node (or mPelvis if none
     // existed) so every importer agrees on the skeleton root.
-    set_skin_skeleton_root(&mut json, humanoid_bone_nodes, identity_root);
+    set_skin_skeleton_root(&mut json, &humanoid_bone_nodes, identity_root, skeleton_profile);
+    // Snap each mapped bone's position onto Second Life's canonical Bento
+    // rest skeleton so re-parenting above (and any VRM rig quirks) can't
+    // leave a joint's position diverging from where SL expects it, which
+    // would otherwise throw off attachment points and rigid mesh even
+    // though the hierarchy and rotations are now correct. Skipped entirely
+    // when preserving the avatar's own proportions, since
+    // `apply_sl_joint_position_overrides` below already records those.
+    let snap_blend_factor = if preserve_custom_proportions { 0.0 } else { 1.0 };
+    rest_skeleton_issues.extend(snap_bones_to_canonical_sl_rest_skeleton(
+        &mut json,
+        &humanoid_bone_nodes,
+        estimated_height_cm,
+        snap_blend_factor,
+    ));
     // Bake the scale factor directly into geometry (node translations and mesh
     // vertex POSITION data) instead of setting a non-unit scale on root nodes.
     // This is the most universally compatible approach for SL: no root-scale
     // means no ambiguity about whether the renderer applies it before or after
     // skinning, and IBMs computed below are all in the final scaled space.
     bake_scale_into_geometry(&mut json, &mut bin, scale_factor)?;
-    regenerate_inverse_bind_matrices(&mut json, &mut bin)?;
+    // Report what the rewrite below is about to change before it happens, so
+    // a drifted or degenerate bind pose shows up as a diagnosable issue
+    // rather than a silent matrix overwrite.
+    rest_skeleton_issues.extend(diagnose_bind_pose_drift(&json, &bin));
+    rest_skeleton_issues.extend(regenerate_inverse_bind_matrices(&mut json, &mut bin)?);
+    // Preserve the avatar's authored proportions instead of relying solely on
+    // SL's default rest skeleton: record each mapped bone's final bind
+    // position as a per-joint override on the skin, for viewers that honor
+    // it, rather than letting the mesh deform toward SL's default skeleton.
+    if preserve_custom_proportions {
+        apply_sl_joint_position_overrides(&mut json, &humanoid_bone_nodes, skeleton_profile);
+    }
+    // Synthesize SL's "fitted mesh" collision-volume bones (belly, pecs, love
+    // handles, butt, limb volumes) under their host mBone so garments relying
+    // on them can still deform with avatar shape sliders, even though VRM has
+    // no equivalent bones of its own.
+    rest_skeleton_issues.extend(synthesize_fitted_mesh_collision_volumes(&mut json, &mut bin)?);
+    // Stress-test the finalized skin under a few canned limb/neck poses now
+    // that the inverse bind matrices above are final, to catch a mismatched
+    // bind matrix or stray heavy weight before it reaches SL as an exploding
+    // mesh at the first pose change.
+    rest_skeleton_issues.extend(simulate_pose_deformation(
+        &json,
+        &bin,
+        DEFAULT_SKIN_EXPLOSION_TOLERANCE_MULTIPLIER,
+    ));
+    // Export the finalized SL-named skeleton as BVH before animations and
+    // VRM extensions below are stripped from `json`, so any glTF animation
+    // sampler data is still available to bake into the MOTION block.
+    if export_bvh_animation {
+        export_skeleton_to_bvh(
+            &json,
+            &bin,
+            &bvh_path_for_output(output_path),
+            &humanoid_bone_nodes,
+        )?;
+    }
     remove_vrm_extensions_and_extras(&mut json);
     remove_unsupported_features(&mut json);
 
+    if let Some(progress) = progress.as_deref_mut() {
+        progress(ConversionStage::TextureProcessing, 0.0);
+    }
     apply_texture_resize_to_embedded_images(
         &mut json,
         &mut bin,
         texture_auto_resize,
         texture_resize_method,
     )?;
+    // Atlas packing runs after resizing so it operates on the final
+    // (possibly downscaled) source images, and before serialization so the
+    // new sheets/bufferViews it appends end up in the written GLB.
+    let atlas_stats = if enable_texture_atlas {
+        apply_texture_atlas(&mut json, &mut bin)?
+    } else {
+        TextureAtlasStats::default()
+    };
+    if let Some(progress) = progress.as_deref_mut() {
+        progress(ConversionStage::TextureProcessing, 1.0);
+        progress(ConversionStage::WriteGlb, 0.0);
+    }
 
     let json_bytes =
         serde_json::to_vec(&json).context("failed to serialize transformed glTF JSON")?;
@@ -399,8 +964,11 @@ fn transform_and_write_glb(
 
     fs::write(output_path, out)
         .with_context(|| format!("failed to write output: {}", output_path.display()))?;
+    if let Some(progress) = progress.as_deref_mut() {
+        progress(ConversionStage::WriteGlb, 1.0);
+    }
 
-    Ok(())
+    Ok((weight_rebake_stats, rest_skeleton_issues, atlas_stats))
 }
 
 /// Resize embedded image buffer views when textures exceed 1024x1024.
@@ -528,23 +1096,34 @@ fn apply_texture_resize_to_embedded_images(
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
 
-    use nalgebra::Vector3;
+    use nalgebra::{Matrix4, Translation3, Vector3};
     use serde_json::Value;
 
+    use super::geometry::{MAX_SL_PRIMITIVE_VERTICES, split_oversized_primitives};
     use super::gltf_utils::{
-        collect_parent_index_map_from_json, compute_node_world_matrices, node_to_local_matrix,
+        collect_parent_index_map_from_json, compute_node_world_matrices,
+        compute_node_world_matrices_with_scale_isolation, decompose_trs, local_to_world,
+        node_to_local_matrix, shear_magnitude, world_to_local,
     };
     use super::skeleton::{
-        ensure_target_bones_exist_after_rename, normalize_sl_bone_rotations,
-        promote_pelvis_to_scene_root, reconstruct_sl_core_hierarchy, rename_bones,
-        validate_bone_conversion_preconditions,
+        collect_node_shear_issues, correct_a_pose_to_t_pose, diagnose_bind_pose_drift,
+        ensure_target_bones_exist_after_rename, extract_bone_chain, normalize_sl_bone_rotations,
+        promote_pelvis_to_scene_root, reconstruct_sl_core_hierarchy,
+        regenerate_inverse_bind_matrices, rename_bones, repose_limbs_to_sl_t_pose,
+        retarget_to_tpose, set_skin_skeleton_root, snap_bones_to_canonical_sl_rest_skeleton,
+        validate_bone_conversion_preconditions, validate_sl_rest_skeleton_positions,
+    };
+    use super::profile::SkeletonProfile;
+    use super::skinning::{
+        collect_skin_weight_issues, optimize_skinning_weights_and_joints,
+        rebake_skin_weights_for_sl_compliance, simulate_pose_deformation,
     };
-    use super::skinning::optimize_skinning_weights_and_joints;
     use super::validation::{
-        estimate_texture_fee, extract_humanoid_bone_nodes, projected_texture_size,
-        validate_hierarchy,
+        estimate_texture_fee, extract_humanoid_bone_nodes, heuristic_match_humanoid_bones,
+        load_bone_map_override, merge_bone_map_override, projected_texture_size,
+        validate_bone_left_right_symmetry, validate_hierarchy,
     };
     use super::*;
 
@@ -555,15 +1134,25 @@ mod tests {
                 index: 0,
                 width: 2048,
                 height: 2048,
+                channel_count: 4,
+                has_alpha_channel: true,
+                alpha_channel_used: true,
+                is_opaque: false,
+                average_color: [128, 128, 128],
             },
             TextureInfo {
                 index: 1,
                 width: 1024,
                 height: 1024,
+                channel_count: 3,
+                has_alpha_channel: false,
+                alpha_channel_used: false,
+                is_opaque: true,
+                average_color: [128, 128, 128],
             },
         ];
 
-        let estimate = estimate_texture_fee(&textures, true);
+        let estimate = estimate_texture_fee(&textures, true, None);
         assert!(estimate.before_linden_dollar > estimate.after_resize_linden_dollar);
         assert!(estimate.reduction_percent > 0);
     }
@@ -594,7 +1183,11 @@ mod tests {
         parent_map.insert(1, 0);
         parent_map.insert(2, 0);
 
-        let issues = validate_hierarchy(&humanoid_bone_nodes, &parent_map);
+        let issues = validate_hierarchy(
+            &humanoid_bone_nodes,
+            &parent_map,
+            &SkeletonProfile::default_sl_bento(),
+        );
         assert!(
             issues
                 .iter()
@@ -602,6 +1195,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn given_missing_right_counterpart_when_validating_symmetry_then_asymmetry_is_reported() {
+        let humanoid_bone_nodes = [("leftUpperArm".to_string(), 0usize)]
+            .into_iter()
+            .collect::<HashMap<String, usize>>();
+        let parent_map = HashMap::new();
+        let node_worlds = vec![Matrix4::<f32>::identity()];
+
+        let issues = validate_bone_left_right_symmetry(&humanoid_bone_nodes, &parent_map, &node_worlds);
+
+        assert!(issues.iter().any(|issue| issue.code == "BONE_ASYMMETRY"));
+    }
+
+    #[test]
+    fn given_mirrored_pair_when_validating_symmetry_then_no_issue_is_reported() {
+        let humanoid_bone_nodes = [
+            ("leftUpperArm".to_string(), 0usize),
+            ("rightUpperArm".to_string(), 1usize),
+        ]
+        .into_iter()
+        .collect::<HashMap<String, usize>>();
+        let parent_map = HashMap::new();
+        let node_worlds = vec![
+            Translation3::new(0.2, 1.4, 0.0).to_homogeneous(),
+            Translation3::new(-0.2, 1.4, 0.0).to_homogeneous(),
+        ];
+
+        let issues = validate_bone_left_right_symmetry(&humanoid_bone_nodes, &parent_map, &node_worlds);
+
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn given_offset_pair_when_validating_symmetry_then_position_asymmetry_is_reported() {
+        let humanoid_bone_nodes = [
+            ("leftUpperArm".to_string(), 0usize),
+            ("rightUpperArm".to_string(), 1usize),
+        ]
+        .into_iter()
+        .collect::<HashMap<String, usize>>();
+        let parent_map = HashMap::new();
+        let node_worlds = vec![
+            Translation3::new(0.2, 1.4, 0.0).to_homogeneous(),
+            Translation3::new(-0.2, 1.2, 0.0).to_homogeneous(),
+        ];
+
+        let issues = validate_bone_left_right_symmetry(&humanoid_bone_nodes, &parent_map, &node_worlds);
+
+        assert!(issues.iter().any(|issue| issue.code == "BONE_ASYMMETRY"));
+    }
+
     #[test]
     fn given_vrmc_humanoid_when_extracting_bones_then_required_bones_are_found() {
         let input_json = serde_json::json!({
@@ -618,12 +1262,83 @@ mod tests {
             }
         });
 
-        let mapping = extract_humanoid_bone_nodes(&input_json);
+        let mapping =
+            extract_humanoid_bone_nodes(&input_json, &SkeletonProfile::default_sl_bento());
         assert_eq!(mapping.get("hips"), Some(&1usize));
         assert_eq!(mapping.get("spine"), Some(&2usize));
         assert_eq!(mapping.get("chest"), Some(&3usize));
     }
 
+    #[test]
+    fn given_no_vrm_extension_when_extracting_bones_then_heuristics_fill_the_mapping() {
+        let input_json = serde_json::json!({
+            "nodes": [
+                {"name": "mixamorig:Hips"},
+                {"name": "mixamorig:Spine"},
+                {"name": "mixamorig:LeftArm"},
+                {"name": "mixamorig:RightArm"}
+            ]
+        });
+
+        let profile = SkeletonProfile::default_sl_bento();
+        let mapping = extract_humanoid_bone_nodes(&input_json, &profile);
+        assert_eq!(mapping.get("hips"), Some(&0usize));
+        assert_eq!(mapping.get("spine"), Some(&1usize));
+        assert_eq!(mapping.get("leftUpperArm"), Some(&2usize));
+        assert_eq!(mapping.get("rightUpperArm"), Some(&3usize));
+    }
+
+    #[test]
+    fn given_partial_vrm_extension_when_extracting_bones_then_extension_entries_take_priority() {
+        let input_json = serde_json::json!({
+            "extensions": {
+                "VRMC_vrm": {
+                    "humanoid": {
+                        "humanBones": {
+                            "hips": {"node": 9}
+                        }
+                    }
+                }
+            },
+            "nodes": [
+                {"name": "Hips"},
+                {"name": "Spine"}
+            ]
+        });
+
+        let profile = SkeletonProfile::default_sl_bento();
+        let mapping = extract_humanoid_bone_nodes(&input_json, &profile);
+        // Extension entry wins even though node 0 also matches the "hips" alias.
+        assert_eq!(mapping.get("hips"), Some(&9usize));
+        assert_eq!(mapping.get("spine"), Some(&1usize));
+    }
+
+    #[test]
+    fn given_custom_profile_when_matching_heuristically_then_unrequested_bones_are_skipped() {
+        let input_json = serde_json::json!({
+            "nodes": [
+                {"name": "Hips"},
+                {"name": "Head"}
+            ]
+        });
+
+        let profile = SkeletonProfile {
+            name: "minimal".to_string(),
+            bones: vec![super::profile::BoneMapping {
+                source: "hips".to_string(),
+                target: "mPelvis".to_string(),
+                required: true,
+                reference_local_transform: None,
+            }],
+            hierarchy: vec![],
+            secondary_bones: vec![],
+        };
+
+        let matches = heuristic_match_humanoid_bones(&input_json, &profile);
+        assert_eq!(matches.get("hips"), Some(&0usize));
+        assert!(!matches.contains_key("head"));
+    }
+
     #[test]
     fn given_invalid_humanoid_node_index_when_validating_preconditions_then_error_is_reported() {
         let input_json = serde_json::json!({
@@ -657,7 +1372,11 @@ mod tests {
             .into_iter()
             .collect::<HashMap<String, usize>>();
 
-        let result = ensure_target_bones_exist_after_rename(&input_json, &humanoid_bone_nodes);
+        let result = ensure_target_bones_exist_after_rename(
+            &input_json,
+            &humanoid_bone_nodes,
+            &SkeletonProfile::default_sl_bento(),
+        );
         assert!(result.is_err());
     }
 
@@ -693,6 +1412,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn given_bent_upper_arm_when_correcting_a_pose_then_rotation_is_straightened_and_translation_kept()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "leftUpperArm",
+                    "rotation": [0.0, 0.0, -0.2, 0.98],
+                    "translation": [0.2, 1.4, 0.0],
+                    "scale": [1.0, 1.0, 1.0]
+                }
+            ]
+        });
+
+        let humanoid = HashMap::from([("leftUpperArm".to_string(), 0usize)]);
+        let mut bin: Vec<u8> = Vec::new();
+        let corrected = correct_a_pose_to_t_pose(&mut json, &mut bin, &humanoid)
+            .expect("a-pose correction should succeed");
+        assert!(corrected);
+
+        let rotation = json
+            .pointer("/nodes/0/rotation")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        assert_eq!(
+            rotation,
+            vec![
+                Value::from(0.0),
+                Value::from(0.0),
+                Value::from(0.0),
+                Value::from(1.0)
+            ]
+        );
+
+        let translation = json
+            .pointer("/nodes/0/translation")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        assert_eq!(
+            translation,
+            vec![Value::from(0.2), Value::from(1.4), Value::from(0.0)]
+        );
+    }
+
+    #[test]
+    fn given_no_mapped_arm_bones_when_correcting_a_pose_then_nothing_is_corrected() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                {"name": "hips", "rotation": [0.0, 0.0, 0.0, 1.0]}
+            ]
+        });
+
+        let humanoid = HashMap::from([("hips".to_string(), 0usize)]);
+        let mut bin: Vec<u8> = Vec::new();
+        let corrected = correct_a_pose_to_t_pose(&mut json, &mut bin, &humanoid)
+            .expect("a-pose correction should succeed");
+        assert!(!corrected);
+    }
+
     #[test]
     fn given_spine_with_world_xz_offsets_when_normalizing_then_world_positions_are_preserved() {
         let mut json = serde_json::json!({
@@ -774,7 +1554,502 @@ mod tests {
     }
 
     #[test]
-    fn given_messy_hierarchy_when_reconstructing_then_core_links_follow_sl_shape() {
+    fn given_sheared_basis_when_decomposing_trs_then_shear_is_detected_and_basis_stays_orthonormal()
+     {
+        let mut matrix = Matrix4::<f32>::identity();
+        matrix[(0, 1)] = 0.3;
+
+        let shear = shear_magnitude(&matrix);
+        assert!((shear - 0.3).abs() < 1e-4);
+
+        let (translation, rotation, scale) = decompose_trs(&matrix);
+        assert!(translation.norm() < 1e-6);
+        assert!((scale - Vector3::new(1.0, 1.0, 1.0)).norm() < 1e-4);
+        assert!(rotation.quaternion().w > 0.9999);
+    }
+
+    #[test]
+    fn given_pure_trs_node_when_checking_shear_then_magnitude_is_negligible() {
+        let node = serde_json::json!({
+            "translation": [1.0, 2.0, 3.0],
+            "rotation": [0.0, 0.0, 0.2, 0.98],
+            "scale": [1.0, 2.0, 0.5]
+        });
+
+        let matrix = node_to_local_matrix(&node);
+        assert!(shear_magnitude(&matrix) < 1e-4);
+    }
+
+    #[test]
+    fn given_bone_with_sheared_matrix_when_collecting_shear_issues_then_warning_is_reported() {
+        let json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "leftUpperArm",
+                    "matrix": [
+                        1.0, 0.0, 0.0, 0.0,
+                        0.3, 1.0, 0.0, 0.0,
+                        0.0, 0.0, 1.0, 0.0,
+                        0.0, 0.0, 0.0, 1.0
+                    ]
+                }
+            ]
+        });
+        let humanoid = HashMap::from([("leftUpperArm".to_string(), 0usize)]);
+
+        let issues = collect_node_shear_issues(&json, &humanoid);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "BONE_TRANSFORM_SHEAR_DETECTED");
+        assert_eq!(issues[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn given_bone_with_pure_trs_matrix_when_collecting_shear_issues_then_none_is_reported() {
+        let json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "leftUpperArm",
+                    "translation": [0.1, 0.2, 0.3],
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "scale": [1.0, 1.0, 1.0]
+                }
+            ]
+        });
+        let humanoid = HashMap::from([("leftUpperArm".to_string(), 0usize)]);
+
+        let issues = collect_node_shear_issues(&json, &humanoid);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn given_bent_arm_chain_when_reposing_via_two_bone_ik_then_chain_is_straightened_along_axis() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "leftUpperArm",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "translation": [0.0, 0.0, 0.0],
+                    "children": [1]
+                },
+                {
+                    "name": "leftLowerArm",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "translation": [0.0, 0.5, -0.3],
+                    "children": [2]
+                },
+                {
+                    "name": "leftHand",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "translation": [0.0, 0.5, -0.2]
+                }
+            ]
+        });
+
+        let humanoid = HashMap::from([
+            ("leftUpperArm".to_string(), 0usize),
+            ("leftLowerArm".to_string(), 1usize),
+            ("leftHand".to_string(), 2usize),
+        ]);
+        let mut bin: Vec<u8> = Vec::new();
+
+        let reposed = repose_limbs_to_sl_t_pose(&mut json, &mut bin, &humanoid)
+            .expect("repose should succeed");
+        assert!(reposed);
+
+        let locals: Vec<_> = json["nodes"]
+            .as_array()
+            .expect("nodes should be array")
+            .iter()
+            .map(node_to_local_matrix)
+            .collect();
+        let worlds =
+            compute_node_world_matrices(&locals, &collect_parent_index_map_from_json(&json));
+
+        let l1 = (0.5f32 * 0.5 + 0.3 * 0.3).sqrt();
+        let l2 = (0.5f32 * 0.5 + 0.2 * 0.2).sqrt();
+
+        let mid_world = Vector3::new(worlds[1][(0, 3)], worlds[1][(1, 3)], worlds[1][(2, 3)]);
+        let end_world = Vector3::new(worlds[2][(0, 3)], worlds[2][(1, 3)], worlds[2][(2, 3)]);
+
+        // The target end position for this chain (root + (l1+l2)·(+Y)) lies
+        // at exactly the chain's maximum reach, so the solved chain should
+        // end up perfectly straight along +Y.
+        assert!((mid_world - Vector3::new(0.0, l1, 0.0)).norm() < 1e-3);
+        assert!((end_world - Vector3::new(0.0, l1 + l2, 0.0)).norm() < 1e-3);
+    }
+
+    #[test]
+    fn given_collinear_arm_chain_when_reposing_via_two_bone_ik_then_pole_vector_resolves_bend_plane_without_nan()
+     {
+        // The chain's current direction is already exactly the target axis
+        // (+Y), which makes the old chain-direction-derived bend plane
+        // degenerate (its cross product with the target axis is zero). The
+        // pole vector must resolve the bend plane deterministically here
+        // instead of falling through to the fixed world-axis fallbacks.
+        let mut json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "leftUpperArm",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "translation": [0.0, 0.0, 0.0],
+                    "children": [1]
+                },
+                {
+                    "name": "leftLowerArm",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "translation": [0.0, 0.5, 0.0],
+                    "children": [2]
+                },
+                {
+                    "name": "leftHand",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "translation": [0.0, 0.2, 0.0]
+                }
+            ]
+        });
+
+        let humanoid = HashMap::from([
+            ("leftUpperArm".to_string(), 0usize),
+            ("leftLowerArm".to_string(), 1usize),
+            ("leftHand".to_string(), 2usize),
+        ]);
+        let mut bin: Vec<u8> = Vec::new();
+
+        let reposed = repose_limbs_to_sl_t_pose(&mut json, &mut bin, &humanoid)
+            .expect("repose should succeed");
+        assert!(reposed);
+
+        let locals: Vec<_> = json["nodes"]
+            .as_array()
+            .expect("nodes should be array")
+            .iter()
+            .map(node_to_local_matrix)
+            .collect();
+        let worlds =
+            compute_node_world_matrices(&locals, &collect_parent_index_map_from_json(&json));
+
+        let mid_world = Vector3::new(worlds[1][(0, 3)], worlds[1][(1, 3)], worlds[1][(2, 3)]);
+        let end_world = Vector3::new(worlds[2][(0, 3)], worlds[2][(1, 3)], worlds[2][(2, 3)]);
+
+        assert!(mid_world.iter().all(|value| value.is_finite()));
+        assert!(end_world.iter().all(|value| value.is_finite()));
+        assert!((mid_world - Vector3::new(0.0, 0.5, 0.0)).norm() < 1e-3);
+        assert!((end_world - Vector3::new(0.0, 0.7, 0.0)).norm() < 1e-3);
+    }
+
+    #[test]
+    fn given_incomplete_limb_chain_when_reposing_via_two_bone_ik_then_chain_is_skipped() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "leftUpperArm",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "translation": [0.0, 0.0, 0.0],
+                    "children": [1]
+                },
+                {
+                    "name": "leftLowerArm",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "translation": [0.0, 0.5, -0.3]
+                }
+            ]
+        });
+
+        // "leftHand" is missing from the humanoid map, so this chain has no
+        // end bone and must be left untouched.
+        let humanoid = HashMap::from([
+            ("leftUpperArm".to_string(), 0usize),
+            ("leftLowerArm".to_string(), 1usize),
+        ]);
+        let mut bin: Vec<u8> = Vec::new();
+
+        let reposed = repose_limbs_to_sl_t_pose(&mut json, &mut bin, &humanoid)
+            .expect("repose should succeed");
+        assert!(!reposed);
+
+        let translation = json
+            .pointer("/nodes/1/translation")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        assert_eq!(
+            translation,
+            vec![Value::from(0.0), Value::from(0.5), Value::from(-0.3)]
+        );
+    }
+
+    #[test]
+    fn given_a_posed_upper_arm_when_retargeting_to_tpose_then_segment_is_horizontal_and_issue_reported()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "leftUpperArm",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "translation": [0.0, 0.0, 0.0],
+                    "children": [1]
+                },
+                {
+                    "name": "leftLowerArm",
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    // Authored in a typical A-pose: mostly outward (+Y) with
+                    // some downward (-Z) droop rather than fully horizontal.
+                    "translation": [0.0, 0.9, -0.5]
+                }
+            ]
+        });
+
+        let humanoid = HashMap::from([
+            ("leftUpperArm".to_string(), 0usize),
+            ("leftLowerArm".to_string(), 1usize),
+        ]);
+        let mut bin: Vec<u8> = Vec::new();
+
+        let issues = retarget_to_tpose(&mut json, &mut bin, &humanoid)
+            .expect("retarget should succeed");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "TPOSE_RETARGET_APPLIED");
+        assert_eq!(issues[0].severity, Severity::Info);
+
+        let locals: Vec<_> = json["nodes"]
+            .as_array()
+            .expect("nodes should be array")
+            .iter()
+            .map(node_to_local_matrix)
+            .collect();
+        let worlds =
+            compute_node_world_matrices(&locals, &collect_parent_index_map_from_json(&json));
+
+        let root_world = Vector3::new(worlds[0][(0, 3)], worlds[0][(1, 3)], worlds[0][(2, 3)]);
+        let child_world = Vector3::new(worlds[1][(0, 3)], worlds[1][(1, 3)], worlds[1][(2, 3)]);
+        let direction = (child_world - root_world).normalize();
+
+        // Straightened onto the +Y axis (this segment's SL T-pose target),
+        // so the Z component should have collapsed to ~0.
+        assert!(direction.z.abs() < 1e-3);
+        assert!(direction.y > 0.99);
+    }
+
+    #[test]
+    fn given_no_mapped_arm_bones_when_retargeting_to_tpose_then_nothing_is_corrected() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                {"name": "hips", "rotation": [0.0, 0.0, 0.0, 1.0]}
+            ]
+        });
+
+        let humanoid = HashMap::from([("hips".to_string(), 0usize)]);
+        let mut bin: Vec<u8> = Vec::new();
+
+        let issues = retarget_to_tpose(&mut json, &mut bin, &humanoid)
+            .expect("retarget should succeed");
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn given_override_with_node_name_when_merging_then_mapping_is_updated() {
+        let json = serde_json::json!({
+            "nodes": [
+                {"name": "Bip01 L UpperArm"},
+                {"name": "Bip01 L Forearm"}
+            ]
+        });
+        let mut humanoid = HashMap::from([("leftLowerArm".to_string(), 1usize)]);
+        let overrides = HashMap::from([(
+            "leftUpperArm".to_string(),
+            "Bip01 L UpperArm".to_string(),
+        )]);
+
+        let issues = merge_bone_map_override(&json, &mut humanoid, &overrides);
+
+        assert!(issues.is_empty());
+        assert_eq!(humanoid.get("leftUpperArm"), Some(&0));
+        assert_eq!(humanoid.get("leftLowerArm"), Some(&1));
+    }
+
+    #[test]
+    fn given_override_with_node_index_when_merging_then_existing_mapping_is_overwritten() {
+        let json = serde_json::json!({
+            "nodes": [{"name": "Auto"}, {"name": "Hand-authored"}]
+        });
+        let mut humanoid = HashMap::from([("leftHand".to_string(), 0usize)]);
+        let overrides = HashMap::from([("leftHand".to_string(), "1".to_string())]);
+
+        let issues = merge_bone_map_override(&json, &mut humanoid, &overrides);
+
+        assert!(issues.is_empty());
+        assert_eq!(humanoid.get("leftHand"), Some(&1));
+    }
+
+    #[test]
+    fn given_override_with_unresolvable_source_when_merging_then_warning_is_reported() {
+        let json = serde_json::json!({
+            "nodes": [{"name": "hips"}]
+        });
+        let mut humanoid = HashMap::new();
+        let overrides = HashMap::from([(
+            "leftUpperArm".to_string(),
+            "does-not-exist".to_string(),
+        )]);
+
+        let issues = merge_bone_map_override(&json, &mut humanoid, &overrides);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "BONE_MAP_OVERRIDE_UNRESOLVED");
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert!(humanoid.is_empty());
+    }
+
+    #[test]
+    fn given_override_file_on_disk_when_loading_then_entries_are_parsed() {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "vrm2sl-bone-map-override-test-{}.json",
+            std::process::id()
+        ));
+        fs::write(&path, r#"{"leftUpperArm": "Bip01 L UpperArm"}"#)
+            .expect("failed to write override file");
+
+        let overrides = load_bone_map_override(&path).expect("should load override file");
+
+        let _ = fs::remove_file(&path);
+        assert_eq!(
+            overrides.get("leftUpperArm"),
+            Some(&"Bip01 L UpperArm".to_string())
+        );
+    }
+
+    #[test]
+    fn given_bone_within_tolerance_when_validating_rest_positions_then_no_issue_is_reported() {
+        let json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "mPelvis",
+                    "translation": [0.0, 0.0, 1.00],
+                    "rotation": [0.0, 0.0, 0.0, 1.0]
+                }
+            ]
+        });
+        let humanoid = HashMap::from([("hips".to_string(), 0usize)]);
+
+        let locals: Vec<_> = json["nodes"]
+            .as_array()
+            .expect("nodes should be array")
+            .iter()
+            .map(node_to_local_matrix)
+            .collect();
+        let world_matrices =
+            compute_node_world_matrices(&locals, &collect_parent_index_map_from_json(&json));
+
+        let issues = validate_sl_rest_skeleton_positions(&world_matrices, &humanoid, 200.0);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn given_bone_drifted_beyond_tolerance_when_validating_rest_positions_then_warning_is_reported()
+     {
+        let json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "mPelvis",
+                    "translation": [0.0, 0.0, 1.30],
+                    "rotation": [0.0, 0.0, 0.0, 1.0]
+                }
+            ]
+        });
+        let humanoid = HashMap::from([("hips".to_string(), 0usize)]);
+
+        let locals: Vec<_> = json["nodes"]
+            .as_array()
+            .expect("nodes should be array")
+            .iter()
+            .map(node_to_local_matrix)
+            .collect();
+        let world_matrices =
+            compute_node_world_matrices(&locals, &collect_parent_index_map_from_json(&json));
+
+        let issues = validate_sl_rest_skeleton_positions(&world_matrices, &humanoid, 200.0);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "REST_SKELETON_DEVIATION");
+        assert_eq!(issues[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn given_shorter_avatar_when_validating_rest_positions_then_tolerance_scales_with_height() {
+        let json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "mPelvis",
+                    "translation": [0.0, 0.0, 0.50],
+                    "rotation": [0.0, 0.0, 0.0, 1.0]
+                }
+            ]
+        });
+        let humanoid = HashMap::from([("hips".to_string(), 0usize)]);
+
+        let locals: Vec<_> = json["nodes"]
+            .as_array()
+            .expect("nodes should be array")
+            .iter()
+            .map(node_to_local_matrix)
+            .collect();
+        let world_matrices =
+            compute_node_world_matrices(&locals, &collect_parent_index_map_from_json(&json));
+
+        // Half the reference height (100cm vs 200cm): the canonical mPelvis
+        // position ([0, 0, 1.00] at 200cm) scales down to [0, 0, 0.50], which
+        // exactly matches this half-height rig's pelvis — no issue expected.
+        let issues = validate_sl_rest_skeleton_positions(&world_matrices, &humanoid, 100.0);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn given_drifted_pelvis_when_snapping_to_canonical_rest_then_translation_matches_canonical() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "mPelvis",
+                    "translation": [0.0, 0.0, 1.30],
+                    "rotation": [0.0, 0.0, 0.0, 1.0]
+                }
+            ]
+        });
+        let humanoid = HashMap::from([("hips".to_string(), 0usize)]);
+
+        let issues = snap_bones_to_canonical_sl_rest_skeleton(&mut json, &humanoid, 200.0, 1.0);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "BONES_SNAPPED_TO_CANONICAL_REST");
+        let translation = json["nodes"][0]["translation"]
+            .as_array()
+            .expect("translation should be array");
+        assert!((translation[2].as_f64().unwrap() - 1.00).abs() < 1e-4);
+    }
+
+    #[test]
+    fn given_zero_blend_factor_when_snapping_to_canonical_rest_then_translation_is_unchanged() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                {
+                    "name": "mPelvis",
+                    "translation": [0.0, 0.0, 1.30],
+                    "rotation": [0.0, 0.0, 0.0, 1.0]
+                }
+            ]
+        });
+        let humanoid = HashMap::from([("hips".to_string(), 0usize)]);
+
+        let issues = snap_bones_to_canonical_sl_rest_skeleton(&mut json, &humanoid, 200.0, 0.0);
+
+        assert!(issues.is_empty());
+        assert_eq!(
+            json["nodes"][0]["translation"],
+            serde_json::json!([0.0, 0.0, 1.30])
+        );
+    }
+
+    #[test]
+    fn given_messy_hierarchy_when_reconstructing_then_core_links_follow_sl_shape() {
         let mut json = serde_json::json!({
             "nodes": [
                 {"name":"mPelvis", "children":[2,7]},
@@ -856,7 +2131,7 @@ mod tests {
             ("leftIndexDistal".to_string(), 4usize),
         ]);
 
-        rename_bones(&mut json, &humanoid);
+        rename_bones(&mut json, &humanoid, &SkeletonProfile::default_sl_bento());
 
         assert_eq!(
             json.pointer("/nodes/0/name").and_then(Value::as_str),
@@ -926,18 +2201,81 @@ mod tests {
     }
 
     #[test]
-    fn given_unused_joint_slot_when_optimizing_skinning_then_joints_and_ibm_are_compacted() {
-        let mut json = serde_json::json!({
+    fn given_common_ancestor_when_extracting_bone_chain_then_path_joins_through_it() {
+        let json = serde_json::json!({
             "nodes": [
-                { "mesh": 0, "skin": 0 },
-                { "name": "jointA" },
-                { "name": "jointB" },
-                { "name": "jointUnused" }
-            ],
-            "meshes": [
-                {
-                    "primitives": [
-                        {
+                {"name": "hips", "children": [1, 4]},
+                {"name": "chest", "children": [2]},
+                {"name": "leftUpperArm", "children": [3]},
+                {"name": "leftHand", "children": []},
+                {"name": "leftUpperLeg", "children": []}
+            ]
+        });
+        let parent_map = collect_parent_index_map_from_json(&json);
+
+        let chain = extract_bone_chain(&json, &parent_map, 3, 4).expect("nodes share a root");
+
+        assert_eq!(chain, vec![3, 2, 1, 0, 4]);
+    }
+
+    #[test]
+    fn given_disconnected_nodes_when_extracting_bone_chain_then_none_is_returned() {
+        let json = serde_json::json!({
+            "nodes": [
+                {"name": "hips", "children": [1]},
+                {"name": "chest", "children": []},
+                {"name": "strayJoint", "children": []}
+            ]
+        });
+        let parent_map = collect_parent_index_map_from_json(&json);
+
+        assert!(extract_bone_chain(&json, &parent_map, 1, 2).is_none());
+    }
+
+    #[test]
+    fn given_orphaned_bone_not_reachable_from_hips_when_reconstructing_then_it_is_skipped_with_warning()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                {"name": "mPelvis", "children": [1]},
+                {"name": "mTorso", "children": []},
+                {"name": "strayJoint", "children": []}
+            ],
+            "scenes": [
+                {"nodes": [0, 1, 2]}
+            ]
+        });
+
+        let humanoid = HashMap::from([
+            ("hips".to_string(), 0usize),
+            ("spine".to_string(), 1usize),
+            ("chest".to_string(), 2usize),
+        ]);
+
+        let issues = reconstruct_sl_core_hierarchy(&mut json, &humanoid);
+
+        assert!(issues.iter().any(|issue| issue.code == "ORPHANED_BONE_SKIPPED"));
+        let spine_children = json
+            .pointer("/nodes/1/children")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        assert!(!spine_children.contains(&Value::from(2u64)));
+    }
+
+    #[test]
+    fn given_unused_joint_slot_when_optimizing_skinning_then_joints_and_ibm_are_compacted() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 },
+                { "name": "jointA" },
+                { "name": "jointB" },
+                { "name": "jointUnused" }
+            ],
+            "meshes": [
+                {
+                    "primitives": [
+                        {
                             "attributes": {
                                 "JOINTS_0": 0,
                                 "WEIGHTS_0": 1
@@ -977,7 +2315,7 @@ mod tests {
             bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
         }
 
-        optimize_skinning_weights_and_joints(&mut json, &mut bin)
+        optimize_skinning_weights_and_joints(&mut json, &mut bin, None, None)
             .expect("optimization should succeed");
 
         let joints_len = json
@@ -994,6 +2332,754 @@ mod tests {
         assert_eq!(ibm_count, 2);
     }
 
+    #[test]
+    fn given_more_than_4_influences_when_optimizing_skinning_then_weights_are_clamped_and_renormalized()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 },
+                { "name": "jointA" },
+                { "name": "jointB" },
+                { "name": "jointC" },
+                { "name": "jointD" },
+                { "name": "jointE" }
+            ],
+            "meshes": [
+                {
+                    "primitives": [
+                        {
+                            "attributes": {
+                                "JOINTS_0": 0,
+                                "WEIGHTS_0": 1,
+                                "JOINTS_1": 2,
+                                "WEIGHTS_1": 3
+                            }
+                        }
+                    ]
+                }
+            ],
+            "skins": [
+                {
+                    "joints": [1, 2, 3, 4, 5],
+                    "inverseBindMatrices": 4
+                }
+            ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" },
+                { "bufferView": 2, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 3, "componentType": 5126, "count": 1, "type": "VEC4" },
+                { "bufferView": 4, "componentType": 5126, "count": 5, "type": "MAT4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 },
+                { "buffer": 0, "byteOffset": 20, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 24, "byteLength": 16 },
+                { "buffer": 0, "byteOffset": 40, "byteLength": 320 }
+            ],
+            "buffers": [
+                { "byteLength": 360 }
+            ]
+        });
+
+        let mut bin = vec![0u8; 360];
+        bin[0..4].copy_from_slice(&[0, 1, 2, 3]);
+        for (lane, value) in [0.4f32, 0.3, 0.2, 0.05].iter().enumerate() {
+            let offset = 4 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        bin[20..24].copy_from_slice(&[4, 0, 0, 0]);
+        for (lane, value) in [0.03f32, 0.0, 0.0, 0.0].iter().enumerate() {
+            let offset = 24 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        optimize_skinning_weights_and_joints(&mut json, &mut bin, None, None)
+            .expect("optimization should succeed");
+
+        // jointE (slot 4, the lightest influence) was dropped by the 4-influence
+        // clamp, so it's now unused and compacted out of the joints list.
+        let joints_len = json
+            .pointer("/skins/0/joints")
+            .and_then(Value::as_array)
+            .map(|array| array.len())
+            .unwrap_or(0);
+        assert_eq!(joints_len, 4);
+
+        assert!(
+            json.pointer("/meshes/0/primitives/0/attributes/JOINTS_1")
+                .is_none()
+        );
+        assert!(
+            json.pointer("/meshes/0/primitives/0/attributes/WEIGHTS_1")
+                .is_none()
+        );
+
+        let weight_at = |lane: usize| {
+            let offset = 4 + lane * 4;
+            f32::from_le_bytes(bin[offset..offset + 4].try_into().unwrap())
+        };
+        let renormalized_sum: f32 = (0..4).map(weight_at).sum();
+        assert!((renormalized_sum - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn given_skin_without_inverse_bind_matrices_when_regenerating_then_accessor_is_synthesized() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0, "translation": [0.0, 0.0, 0.0] },
+                { "name": "hips", "translation": [0.0, 1.0, 0.0] },
+                { "name": "spine", "translation": [0.0, 0.5, 0.0] }
+            ],
+            "meshes": [
+                { "primitives": [ { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1 } } ] }
+            ],
+            "skins": [ { "joints": [1, 2] } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 }
+            ],
+            "buffers": [ { "byteLength": 20 } ]
+        });
+        let mut bin = vec![0u8; 20];
+
+        let issues = regenerate_inverse_bind_matrices(&mut json, &mut bin)
+            .expect("regeneration should succeed");
+
+        assert!(
+            issues
+                .iter()
+                .any(|issue| issue.code == "INVERSE_BIND_MATRICES_SYNTHESIZED")
+        );
+
+        let new_accessor_index = json
+            .pointer("/skins/0/inverseBindMatrices")
+            .and_then(Value::as_u64)
+            .expect("inverseBindMatrices should now be set") as usize;
+        assert_eq!(new_accessor_index, 2);
+        assert_eq!(
+            json.pointer("/accessors/2/count").and_then(Value::as_u64),
+            Some(2)
+        );
+        assert_eq!(
+            json.pointer("/accessors/2/type").and_then(Value::as_str),
+            Some("MAT4")
+        );
+
+        let new_buffer_view_index = json
+            .pointer("/accessors/2/bufferView")
+            .and_then(Value::as_u64)
+            .expect("new accessor should reference a buffer view") as usize;
+        let byte_offset = json
+            .pointer(&format!("/bufferViews/{new_buffer_view_index}/byteOffset"))
+            .and_then(Value::as_u64)
+            .expect("new buffer view should have a byte offset") as usize;
+        assert_eq!(bin.len(), byte_offset + 2 * 64);
+
+        // Matrix4::as_slice() is column-major, so the translation column
+        // (index 12..16) starts at float offset 12; its y component (the
+        // inverse of hips's +1.0 translation) is float index 13.
+        let hips_inverse_translation = f32::from_le_bytes(
+            bin[byte_offset + 52..byte_offset + 56]
+                .try_into()
+                .unwrap(),
+        );
+        assert!((hips_inverse_translation - -1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn given_normalized_byte_weights_when_clamping_then_quantized_lanes_sum_to_255() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 },
+                { "name": "jointA" },
+                { "name": "jointB" },
+                { "name": "jointC" }
+            ],
+            "meshes": [
+                { "primitives": [ { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1 } } ] }
+            ],
+            "skins": [ { "joints": [1, 2, 3] } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                {
+                    "bufferView": 1,
+                    "componentType": 5121,
+                    "count": 1,
+                    "type": "VEC4",
+                    "normalized": true
+                }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 4 }
+            ],
+            "buffers": [ { "byteLength": 8 } ]
+        });
+
+        let mut bin = vec![0u8; 8];
+        bin[0..4].copy_from_slice(&[0, 1, 2, 2]);
+        // 0.4 / 0.3 / 0.3 quantize independently to 102 / 77 / 77 (rounding
+        // each half-up), summing to 256 — one over u8::MAX — unless the
+        // remainder is folded back into a lane.
+        let weights = [0.4f32, 0.3, 0.3, 0.0];
+        for (lane, &weight) in weights.iter().enumerate() {
+            bin[4 + lane] = (weight * u8::MAX as f32).round() as u8;
+        }
+
+        optimize_skinning_weights_and_joints(&mut json, &mut bin, None, None)
+            .expect("optimization should succeed");
+
+        let quantized_sum: u32 = bin[4..8].iter().map(|&byte| byte as u32).sum();
+        assert_eq!(quantized_sum, u8::MAX as u32);
+    }
+
+    #[test]
+    fn given_scale_isolated_child_when_computing_world_matrices_then_parent_scale_is_ignored() {
+        let mut parent_map = HashMap::new();
+        parent_map.insert(1, 0);
+
+        let parent_local = Translation3::new(0.0, 0.0, 0.0).to_homogeneous()
+            * Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 4.0, 1.0));
+        let child_local = Translation3::new(0.0, 1.0, 0.0).to_homogeneous();
+        let locals = vec![parent_local, child_local];
+
+        let inheriting = compute_node_world_matrices_with_scale_isolation(
+            &locals,
+            &parent_map,
+            &HashSet::new(),
+        );
+        assert!((inheriting[1][(1, 1)] - 4.0).abs() < 1e-4);
+        assert!((inheriting[1][(1, 3)] - 4.0).abs() < 1e-4);
+
+        let mut isolated_nodes = HashSet::new();
+        isolated_nodes.insert(1);
+        let isolated =
+            compute_node_world_matrices_with_scale_isolation(&locals, &parent_map, &isolated_nodes);
+        assert!((isolated[1][(1, 1)] - 1.0).abs() < 1e-4);
+        assert!((isolated[1][(1, 3)] - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn given_world_matrix_when_round_tripping_a_point_then_it_is_recovered() {
+        let world = Translation3::new(1.0, 2.0, 3.0).to_homogeneous()
+            * Matrix4::new_nonuniform_scaling(&Vector3::new(2.0, 1.0, 0.5));
+
+        let local_point = Vector3::new(4.0, -1.0, 2.0);
+        let world_point = local_to_world(&world, &local_point);
+        let recovered = world_to_local(&world, &world_point).expect("world matrix is invertible");
+
+        assert!((local_point - recovered).norm() < 1e-4);
+        assert!((world_point - Vector3::new(9.0, 1.0, 4.0)).norm() < 1e-4);
+    }
+
+    #[test]
+    fn given_drifted_bind_pose_when_diagnosing_then_drift_issue_is_reported() {
+        let json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0, "translation": [0.0, 0.0, 0.0] },
+                { "name": "hips", "translation": [0.0, 1.0, 0.0] },
+                { "name": "spine", "translation": [0.0, 0.0, 0.0] }
+            ],
+            "meshes": [
+                { "primitives": [ { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1 } } ] }
+            ],
+            "skins": [ { "joints": [1, 2], "inverseBindMatrices": 0 } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 2, "type": "MAT4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 128 }
+            ],
+            "buffers": [ { "byteLength": 128 } ]
+        });
+        // Both stored inverse bind matrices are identity: correct for
+        // "spine" (which sits at the origin, matching its identity world
+        // matrix) but stale for "hips" (translated +1 on Y, so its real
+        // inverse bind matrix should carry a -1 Y translation).
+        let mut bin = vec![0u8; 128];
+        for slot in 0..2 {
+            let offset = slot * 64;
+            for diagonal in 0..4 {
+                let byte_offset = offset + diagonal * 20;
+                bin[byte_offset..byte_offset + 4].copy_from_slice(&1.0f32.to_le_bytes());
+            }
+        }
+
+        let issues = diagnose_bind_pose_drift(&json, &bin);
+
+        assert!(issues.iter().any(|issue| issue.code == "BIND_POSE_DRIFTED"));
+    }
+
+    #[test]
+    fn given_unnormalized_weights_when_rebaking_then_weights_are_renormalized() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 },
+                { "name": "hips" },
+                { "name": "spine" }
+            ],
+            "meshes": [
+                { "primitives": [ { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1 } } ] }
+            ],
+            "skins": [ { "joints": [1, 2] } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 }
+            ],
+            "buffers": [ { "byteLength": 20 } ]
+        });
+
+        let mut bin = vec![0u8; 20];
+        bin[0..4].copy_from_slice(&[0, 1, 0, 0]);
+        for (lane, value) in [0.5f32, 0.3, 0.0, 0.0].iter().enumerate() {
+            let offset = 4 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        let humanoid_bone_nodes =
+            HashMap::from([("hips".to_string(), 1usize), ("spine".to_string(), 2usize)]);
+
+        let stats = rebake_skin_weights_for_sl_compliance(
+            &mut json,
+            &mut bin,
+            &humanoid_bone_nodes,
+            &HashMap::new(),
+            &SkeletonProfile::default_sl_bento(),
+            &[],
+            None,
+        )
+        .expect("not cancelled");
+
+        assert_eq!(stats.renormalized_vertex_count, 1);
+        assert_eq!(stats.clamped_vertex_count, 0);
+        assert_eq!(stats.remapped_orphan_vertex_count, 0);
+
+        let weight_at = |lane: usize| {
+            let offset = 4 + lane * 4;
+            f32::from_le_bytes(bin[offset..offset + 4].try_into().unwrap())
+        };
+        assert!((weight_at(0) - 0.625).abs() < 1e-4);
+        assert!((weight_at(1) - 0.375).abs() < 1e-4);
+    }
+
+    #[test]
+    fn given_weight_bound_to_unmapped_bone_when_rebaking_then_weight_is_remapped_to_mapped_ancestor()
+    {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 },
+                { "name": "hips", "children": [2] },
+                { "name": "J_Sec_Hair_Root" }
+            ],
+            "meshes": [
+                { "primitives": [ { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1 } } ] }
+            ],
+            "skins": [ { "joints": [1, 2] } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 }
+            ],
+            "buffers": [ { "byteLength": 20 } ]
+        });
+
+        let mut bin = vec![0u8; 20];
+        bin[0..4].copy_from_slice(&[1, 0, 0, 0]);
+        for (lane, value) in [1.0f32, 0.0, 0.0, 0.0].iter().enumerate() {
+            let offset = 4 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        let humanoid_bone_nodes = HashMap::from([("hips".to_string(), 1usize)]);
+
+        let stats = rebake_skin_weights_for_sl_compliance(
+            &mut json,
+            &mut bin,
+            &humanoid_bone_nodes,
+            &HashMap::new(),
+            &SkeletonProfile::default_sl_bento(),
+            &[],
+            None,
+        )
+        .expect("not cancelled");
+
+        assert_eq!(stats.remapped_orphan_vertex_count, 1);
+        assert_eq!(stats.clamped_vertex_count, 0);
+
+        let slot_at_lane0 = bin[0];
+        let weight_at_lane0 = f32::from_le_bytes(bin[4..8].try_into().unwrap());
+        assert_eq!(slot_at_lane0, 0);
+        assert!((weight_at_lane0 - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn given_drop_rule_matching_unmapped_bone_when_rebaking_then_weight_is_dropped_and_remaining_renormalized()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 },
+                { "name": "hips" },
+                { "name": "J_Sec_Hair_Root" }
+            ],
+            "meshes": [
+                { "primitives": [ { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1 } } ] }
+            ],
+            "skins": [ { "joints": [1, 2] } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 }
+            ],
+            "buffers": [ { "byteLength": 20 } ]
+        });
+
+        let mut bin = vec![0u8; 20];
+        bin[0..4].copy_from_slice(&[0, 1, 0, 0]);
+        for (lane, value) in [0.7f32, 0.3, 0.0, 0.0].iter().enumerate() {
+            let offset = 4 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        let humanoid_bone_nodes = HashMap::from([("hips".to_string(), 1usize)]);
+        let rules = vec![BoneRemapRule {
+            matcher: BoneRemapMatcher::Prefix("J_Sec_".to_string()),
+            action: BoneRemapAction::Drop,
+        }];
+        let compiled_rules = bone_remap::compile_rules(&rules).expect("compile rules");
+
+        let stats = rebake_skin_weights_for_sl_compliance(
+            &mut json,
+            &mut bin,
+            &humanoid_bone_nodes,
+            &HashMap::new(),
+            &SkeletonProfile::default_sl_bento(),
+            &compiled_rules,
+            None,
+        )
+        .expect("not cancelled");
+
+        assert_eq!(stats.dropped_vertex_count, 1);
+        assert_eq!(stats.remapped_orphan_vertex_count, 0);
+
+        let slot_at_lane0 = bin[0];
+        let weight_at_lane0 = f32::from_le_bytes(bin[4..8].try_into().unwrap());
+        assert_eq!(slot_at_lane0, 0);
+        assert!((weight_at_lane0 - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn given_map_to_rule_matching_unmapped_bone_when_rebaking_then_weight_is_bound_to_named_target()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 },
+                { "name": "hips" },
+                { "name": "chest" },
+                { "name": "J_Sec_Hair_Root" }
+            ],
+            "meshes": [
+                { "primitives": [ { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1 } } ] }
+            ],
+            "skins": [ { "joints": [1, 2, 3] } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 }
+            ],
+            "buffers": [ { "byteLength": 20 } ]
+        });
+
+        let mut bin = vec![0u8; 20];
+        bin[0..4].copy_from_slice(&[2, 0, 0, 0]);
+        for (lane, value) in [1.0f32, 0.0, 0.0, 0.0].iter().enumerate() {
+            let offset = 4 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        let humanoid_bone_nodes = HashMap::from([
+            ("hips".to_string(), 1usize),
+            ("chest".to_string(), 2usize),
+        ]);
+        let rules = vec![BoneRemapRule {
+            matcher: BoneRemapMatcher::Prefix("J_Sec_".to_string()),
+            action: BoneRemapAction::MapTo("mChest".to_string()),
+        }];
+        let compiled_rules = bone_remap::compile_rules(&rules).expect("compile rules");
+
+        let stats = rebake_skin_weights_for_sl_compliance(
+            &mut json,
+            &mut bin,
+            &humanoid_bone_nodes,
+            &HashMap::new(),
+            &SkeletonProfile::default_sl_bento(),
+            &compiled_rules,
+            None,
+        )
+        .expect("not cancelled");
+
+        assert_eq!(stats.remapped_orphan_vertex_count, 1);
+        assert_eq!(stats.dropped_vertex_count, 0);
+
+        // mChest is joints[1] (node 2); the influence should land there.
+        let slot_at_lane0 = bin[0];
+        let weight_at_lane0 = f32::from_le_bytes(bin[4..8].try_into().unwrap());
+        assert_eq!(slot_at_lane0, 1);
+        assert!((weight_at_lane0 - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn given_more_than_4_influences_when_rebaking_then_heaviest_four_are_kept_and_renormalized() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 },
+                { "name": "hips" },
+                { "name": "spine" },
+                { "name": "chest" },
+                { "name": "neck" },
+                { "name": "head" }
+            ],
+            "meshes": [
+                {
+                    "primitives": [
+                        {
+                            "attributes": {
+                                "JOINTS_0": 0,
+                                "WEIGHTS_0": 1,
+                                "JOINTS_1": 2,
+                                "WEIGHTS_1": 3
+                            }
+                        }
+                    ]
+                }
+            ],
+            "skins": [ { "joints": [1, 2, 3, 4, 5] } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" },
+                { "bufferView": 2, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 3, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 },
+                { "buffer": 0, "byteOffset": 20, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 24, "byteLength": 16 }
+            ],
+            "buffers": [ { "byteLength": 40 } ]
+        });
+
+        let mut bin = vec![0u8; 40];
+        bin[0..4].copy_from_slice(&[0, 1, 2, 3]);
+        for (lane, value) in [0.40f32, 0.30, 0.20, 0.06].iter().enumerate() {
+            let offset = 4 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        bin[20..24].copy_from_slice(&[4, 0, 0, 0]);
+        for (lane, value) in [0.04f32, 0.0, 0.0, 0.0].iter().enumerate() {
+            let offset = 24 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        let humanoid_bone_nodes = HashMap::from([
+            ("hips".to_string(), 1usize),
+            ("spine".to_string(), 2usize),
+            ("chest".to_string(), 3usize),
+            ("neck".to_string(), 4usize),
+            ("head".to_string(), 5usize),
+        ]);
+
+        let stats = rebake_skin_weights_for_sl_compliance(
+            &mut json,
+            &mut bin,
+            &humanoid_bone_nodes,
+            &HashMap::new(),
+            &SkeletonProfile::default_sl_bento(),
+            &[],
+            None,
+        )
+        .expect("not cancelled");
+
+        assert_eq!(stats.clamped_vertex_count, 1);
+        assert_eq!(stats.remapped_orphan_vertex_count, 0);
+
+        let weight_at = |lane: usize| {
+            let offset = 4 + lane * 4;
+            f32::from_le_bytes(bin[offset..offset + 4].try_into().unwrap())
+        };
+        assert!((weight_at(0) - 0.40 / 0.96).abs() < 1e-4);
+        assert!((weight_at(1) - 0.30 / 0.96).abs() < 1e-4);
+        assert!((weight_at(2) - 0.20 / 0.96).abs() < 1e-4);
+        assert!((weight_at(3) - 0.06 / 0.96).abs() < 1e-4);
+
+        // The now-redundant JOINTS_1/WEIGHTS_1 set is cleared since only 4
+        // influences are kept in JOINTS_0/WEIGHTS_0.
+        assert_eq!(f32::from_le_bytes(bin[24..28].try_into().unwrap()), 0.0);
+    }
+
+    #[test]
+    fn given_messy_skin_weights_when_collecting_issues_then_all_three_warnings_are_reported() {
+        let json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 },
+                { "name": "hips" },
+                { "name": "spine" },
+                { "name": "J_Sec_Hair_Root" }
+            ],
+            "meshes": [
+                {
+                    "primitives": [
+                        {
+                            "attributes": {
+                                "JOINTS_0": 0,
+                                "WEIGHTS_0": 1,
+                                "JOINTS_1": 2,
+                                "WEIGHTS_1": 3
+                            }
+                        }
+                    ]
+                }
+            ],
+            "skins": [ { "joints": [1, 2, 3] } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" },
+                { "bufferView": 2, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 3, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 },
+                { "buffer": 0, "byteOffset": 20, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 24, "byteLength": 16 }
+            ],
+            "buffers": [ { "byteLength": 40 } ]
+        });
+
+        let mut bin = vec![0u8; 40];
+        // Set 0: hips, spine, spring (unmapped) all carry non-zero weight.
+        bin[0..4].copy_from_slice(&[0, 1, 2, 0]);
+        for (lane, value) in [0.3f32, 0.3, 0.2, 0.0].iter().enumerate() {
+            let offset = 4 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        // Set 1: two more non-zero influences, pushing this vertex past 4
+        // total and its weight sum above 1.0.
+        bin[20..24].copy_from_slice(&[0, 1, 0, 0]);
+        for (lane, value) in [0.3f32, 0.1, 0.0, 0.0].iter().enumerate() {
+            let offset = 24 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        let humanoid_bone_nodes =
+            HashMap::from([("hips".to_string(), 1usize), ("spine".to_string(), 2usize)]);
+
+        let issues = collect_skin_weight_issues(
+            &json,
+            &bin,
+            &humanoid_bone_nodes,
+            &HashMap::new(),
+            &SkeletonProfile::default_sl_bento(),
+        );
+        let codes: Vec<&str> = issues.iter().map(|issue| issue.code.as_str()).collect();
+
+        assert!(codes.contains(&"SKIN_WEIGHTS_OVER_4_INFLUENCES"));
+        assert!(codes.contains(&"SKIN_WEIGHTS_NOT_NORMALIZED"));
+        assert!(codes.contains(&"SKIN_WEIGHTS_UNMAPPED_BONE"));
+    }
+
+    fn deformation_test_fixture(vertex_position: [f32; 3]) -> (Value, Vec<u8>) {
+        let json = serde_json::json!({
+            "nodes": [
+                { "name": "mNeck" },
+                { "mesh": 0, "skin": 0 }
+            ],
+            "meshes": [
+                {
+                    "name": "TestMesh",
+                    "primitives": [
+                        {
+                            "attributes": {
+                                "POSITION": 0,
+                                "JOINTS_0": 1,
+                                "WEIGHTS_0": 2
+                            }
+                        }
+                    ]
+                }
+            ],
+            "skins": [ { "joints": [0], "inverseBindMatrices": 3 } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3" },
+                { "bufferView": 1, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 2, "componentType": 5126, "count": 1, "type": "VEC4" },
+                { "bufferView": 3, "componentType": 5126, "count": 1, "type": "MAT4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 12 },
+                { "buffer": 0, "byteOffset": 12, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 16, "byteLength": 16 },
+                { "buffer": 0, "byteOffset": 32, "byteLength": 64 }
+            ],
+            "buffers": [ { "byteLength": 96 } ]
+        });
+
+        let mut bin = vec![0u8; 96];
+        for (lane, value) in vertex_position.iter().enumerate() {
+            let offset = lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        bin[12..16].copy_from_slice(&[0, 0, 0, 0]);
+        bin[16..20].copy_from_slice(&1.0f32.to_le_bytes());
+        // Identity inverse bind matrix: `mNeck` sits at the world origin with
+        // no parent, so its world matrix is already identity.
+        for column in 0..4 {
+            let offset = 32 + column * 16 + column * 4;
+            bin[offset..offset + 4].copy_from_slice(&1.0f32.to_le_bytes());
+        }
+
+        (json, bin)
+    }
+
+    #[test]
+    fn given_vertex_near_its_joint_when_simulating_pose_deformation_then_no_issue_is_reported() {
+        let (json, bin) = deformation_test_fixture([0.0, 1.0, 0.0]);
+
+        let issues = simulate_pose_deformation(&json, &bin, DEFAULT_SKIN_EXPLOSION_TOLERANCE_MULTIPLIER);
+
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn given_vertex_misweighted_far_from_its_joint_when_simulating_pose_deformation_then_skin_explosion_is_flagged()
+     {
+        let (json, bin) = deformation_test_fixture([0.0, 50.0, 0.0]);
+
+        let issues = simulate_pose_deformation(&json, &bin, DEFAULT_SKIN_EXPLOSION_TOLERANCE_MULTIPLIER);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "SKIN_EXPLOSION_SUSPECTED");
+        assert_eq!(issues[0].severity, Severity::Error);
+    }
+
     #[test]
     fn given_root_wrapper_when_promoting_pelvis_then_identity_root_is_kept() {
         let mut json = serde_json::json!({
@@ -1021,7 +3107,11 @@ mod tests {
         });
 
         let humanoid = HashMap::from([("hips".to_string(), 1usize)]);
-        let identity_root = promote_pelvis_to_scene_root(&mut json, &humanoid);
+        let identity_root = promote_pelvis_to_scene_root(
+            &mut json,
+            &humanoid,
+            &SkeletonProfile::default_sl_bento(),
+        );
 
         // The identity root should be the original Root node (index 0).
         assert_eq!(identity_root, Some(0));
@@ -1084,4 +3174,170 @@ mod tests {
             "mPelvis Y should be ~1.0 (world) but got {y}"
         );
     }
+
+    #[test]
+    fn given_pelvis_in_second_scene_when_promoting_then_first_scene_is_untouched() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "name": "UnrelatedSceneRoot", "children": [] },
+                {
+                    "name": "Root",
+                    "translation": [0.0, 0.0, 0.0],
+                    "children": [2]
+                },
+                {
+                    "name": "mPelvis",
+                    "translation": [0.0, 1.0, 0.0],
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "children": []
+                }
+            ],
+            "scenes": [
+                { "nodes": [0] },
+                { "nodes": [1] }
+            ]
+        });
+
+        let humanoid = HashMap::from([("hips".to_string(), 2usize)]);
+        let identity_root = promote_pelvis_to_scene_root(
+            &mut json,
+            &humanoid,
+            &SkeletonProfile::default_sl_bento(),
+        );
+
+        assert_eq!(identity_root, Some(1));
+
+        let first_scene_nodes = json
+            .pointer("/scenes/0/nodes")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        assert_eq!(
+            first_scene_nodes,
+            vec![Value::from(0u64)],
+            "the unrelated first scene should be untouched: {first_scene_nodes:?}"
+        );
+
+        let second_scene_nodes = json
+            .pointer("/scenes/1/nodes")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        assert!(
+            second_scene_nodes.iter().any(|v| v.as_u64() == Some(1)),
+            "Root (identity) should remain in its own scene: {second_scene_nodes:?}"
+        );
+    }
+
+    #[test]
+    fn given_skin_whose_joints_are_outside_identity_root_when_setting_skeleton_then_it_falls_back_to_its_own_joint()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "name": "Root", "children": [1] },
+                { "name": "mPelvis", "children": [] },
+                { "name": "OtherRoot", "children": [3] },
+                { "name": "otherJoint", "children": [] }
+            ],
+            "skins": [
+                { "joints": [1] },
+                { "joints": [3] }
+            ]
+        });
+
+        let humanoid = HashMap::from([("hips".to_string(), 1usize)]);
+        set_skin_skeleton_root(
+            &mut json,
+            &humanoid,
+            Some(0),
+            &SkeletonProfile::default_sl_bento(),
+        );
+
+        assert_eq!(
+            json.pointer("/skins/0/skeleton").and_then(Value::as_u64),
+            Some(0),
+            "the skin under the identity root should point at it"
+        );
+        assert_eq!(
+            json.pointer("/skins/1/skeleton").and_then(Value::as_u64),
+            Some(3),
+            "the skin outside both the identity root and mPelvis should fall back to its own first joint"
+        );
+    }
+
+    #[test]
+    fn given_primitive_exceeding_vertex_limit_when_splitting_then_sub_meshes_stay_within_limit() {
+        // Every triangle uses 3 brand-new vertices, so the vertex count grows
+        // in lockstep with the index count and the chunk boundary is exactly
+        // where `partition_triangles_into_chunks` flushes at the limit.
+        let vertex_count = MAX_SL_PRIMITIVE_VERTICES + 3;
+        let index_count = vertex_count;
+
+        let mut positions_bin = Vec::with_capacity(vertex_count * 12);
+        for vertex in 0..vertex_count {
+            for component in 0..3 {
+                positions_bin.extend_from_slice(&((vertex * 3 + component) as f32).to_le_bytes());
+            }
+        }
+        let mut indices_bin = Vec::with_capacity(index_count * 4);
+        for index in 0..index_count {
+            indices_bin.extend_from_slice(&(index as u32).to_le_bytes());
+        }
+
+        let positions_byte_length = positions_bin.len();
+        let indices_byte_length = indices_bin.len();
+        let mut bin = positions_bin;
+        let indices_offset = bin.len();
+        bin.extend(indices_bin);
+
+        let mut json = serde_json::json!({
+            "meshes": [
+                {
+                    "name": "oversized_mesh",
+                    "primitives": [
+                        {
+                            "attributes": { "POSITION": 0 },
+                            "indices": 1,
+                            "mode": 4
+                        }
+                    ]
+                }
+            ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": vertex_count, "type": "VEC3" },
+                { "bufferView": 1, "componentType": 5125, "count": index_count, "type": "SCALAR" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": positions_byte_length },
+                { "buffer": 0, "byteOffset": indices_offset, "byteLength": indices_byte_length }
+            ],
+            "buffers": [
+                { "byteLength": bin.len() }
+            ]
+        });
+
+        let issues = split_oversized_primitives(&mut json, &mut bin);
+
+        assert!(issues.iter().any(|issue| issue.code == "PRIMITIVE_SPLIT"));
+
+        let primitives = json
+            .pointer("/meshes/0/primitives")
+            .and_then(Value::as_array)
+            .expect("mesh should still have primitives");
+        assert_eq!(primitives.len(), 2);
+
+        let mut total_vertices = 0usize;
+        for primitive in primitives {
+            let accessor_index = primitive
+                .pointer("/attributes/POSITION")
+                .and_then(Value::as_u64)
+                .expect("split primitive should keep a POSITION attribute") as usize;
+            let count = json["accessors"][accessor_index]["count"]
+                .as_u64()
+                .expect("accessor should have a count") as usize;
+            assert!(count <= MAX_SL_PRIMITIVE_VERTICES);
+            total_vertices += count;
+        }
+        assert_eq!(total_vertices, vertex_count);
+    }
 }
@@ -0,0 +1,344 @@
+use std::{collections::HashSet, fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use super::types::{
+    BENTO_BONE_MAP, BENTO_HIERARCHY_RELATIONS, BONE_MAP, CORE_HIERARCHY_RELATIONS, REQUIRED_BONES,
+};
+
+/// A bone's reference local transform in its default T-pose, expressed the
+/// same way a glTF node's own `translation`/`rotation` are: relative to its
+/// parent rather than the world. Carried by [`BoneMapping`] so a retarget
+/// editor (or a future T-pose correction pass) has something to compare a
+/// source model's actual local transform against, independent of whatever
+/// that particular model's rest pose happens to be.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ReferenceLocalTransform {
+    pub translation: [f32; 3],
+    pub rotation: [f32; 4],
+}
+
+/// A single source (VRM humanoid) → target (SL) bone name mapping entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoneMapping {
+    pub source: String,
+    pub target: String,
+    /// When `true`, a missing `source` bone raises `MISSING_REQUIRED_BONE`
+    /// (or a `SYNTHESIZED_BONE` warning when a pass-through bone can be
+    /// synthesized for it). A missing optional bone raises no issue of its own.
+    #[serde(default)]
+    pub required: bool,
+    /// Known-good local T-pose transform for `source`, if the profile author
+    /// supplied one. `None` for every bone in [`SkeletonProfile::default_sl_bento`],
+    /// since the stock tables don't carry reference poses of their own.
+    #[serde(default)]
+    pub reference_local_transform: Option<ReferenceLocalTransform>,
+}
+
+/// A parent→child hierarchy edge expressed in source (VRM humanoid) bone names.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HierarchyEdge {
+    pub parent: String,
+    pub child: String,
+}
+
+/// A VRM spring/secondary bone node name → SL Bento extension joint name,
+/// for retargeting hair/skirt/tail/wing chains that have no VRM humanoid
+/// bone semantic. Unlike [`BoneMapping`], `source` is matched against a
+/// glTF node's literal `name`, not a humanoid bone name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecondaryBoneMapping {
+    pub source: String,
+    pub target: String,
+}
+
+/// A user-editable skeleton retargeting profile.
+///
+/// Loaded from a `.toml` or `.json` file (see [`SkeletonProfile::load_from_file`])
+/// or supplied in-memory, this replaces the hardcoded `BONE_MAP` /
+/// `BENTO_BONE_MAP` / `REQUIRED_BONES` / `CORE_HIERARCHY_RELATIONS` constants
+/// so the tool can target alternate SL rigs or non-VRoid humanoids without
+/// recompiling. [`SkeletonProfile::default_sl_bento`] mirrors the stock tables
+/// exactly, so existing conversions are unaffected when no profile is supplied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkeletonProfile {
+    pub name: String,
+    pub bones: Vec<BoneMapping>,
+    pub hierarchy: Vec<HierarchyEdge>,
+    /// Spring/secondary bone retargeting table, consulted when
+    /// [`ConvertOptions::retarget_secondary_bones`] is set. Empty by
+    /// default: secondary bones are collapsed into their nearest mapped
+    /// SL ancestor as before.
+    ///
+    /// [`ConvertOptions::retarget_secondary_bones`]: super::types::ConvertOptions::retarget_secondary_bones
+    #[serde(default)]
+    pub secondary_bones: Vec<SecondaryBoneMapping>,
+}
+
+impl SkeletonProfile {
+    /// Load a skeleton profile from a `.toml` or `.json` file.
+    ///
+    /// The format is selected from the file extension; anything other than
+    /// `.toml` is parsed as JSON.
+    pub fn load_from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read skeleton profile: {}", path.display()))?;
+
+        let is_toml = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.eq_ignore_ascii_case("toml"))
+            .unwrap_or(false);
+
+        let profile: SkeletonProfile = if is_toml {
+            toml::from_str(&content).with_context(|| {
+                format!("failed to parse TOML skeleton profile: {}", path.display())
+            })?
+        } else {
+            serde_json::from_str(&content).with_context(|| {
+                format!("failed to parse JSON skeleton profile: {}", path.display())
+            })?
+        };
+
+        if profile.bones.is_empty() {
+            bail!(
+                "[ERROR] Skeleton profile '{}' defines no bones",
+                path.display()
+            );
+        }
+
+        profile.validate_references().with_context(|| {
+            format!("invalid skeleton profile: {}", path.display())
+        })?;
+
+        Ok(profile)
+    }
+
+    /// Check that every `hierarchy` edge's `parent`/`child` refers to a bone
+    /// declared in `bones`, so a profile with a typo'd or stale bone name
+    /// fails fast at load time instead of silently producing an edge that
+    /// `validate_hierarchy`/hierarchy reconstruction can never resolve.
+    fn validate_references(&self) -> Result<()> {
+        let known_sources: HashSet<&str> =
+            self.bones.iter().map(|bone| bone.source.as_str()).collect();
+
+        for edge in &self.hierarchy {
+            for bone_source in [edge.parent.as_str(), edge.child.as_str()] {
+                if !known_sources.contains(bone_source) {
+                    bail!(
+                        "[ERROR] Skeleton profile '{}' hierarchy references unknown bone '{}'",
+                        self.name,
+                        bone_source
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Built-in profile mirroring the stock VRoid→SL Bento bone tables.
+    ///
+    /// Used whenever `ConvertOptions::skeleton_profile_path` is unset, so
+    /// behavior for standard VRoid models is unchanged.
+    pub fn default_sl_bento() -> Self {
+        let required: HashSet<&str> = REQUIRED_BONES.iter().copied().collect();
+
+        let bones = BONE_MAP
+            .iter()
+            .chain(BENTO_BONE_MAP.iter())
+            .map(|(source, target)| BoneMapping {
+                source: source.to_string(),
+                target: target.to_string(),
+                required: required.contains(source),
+                reference_local_transform: None,
+            })
+            .collect();
+
+        let hierarchy = CORE_HIERARCHY_RELATIONS
+            .iter()
+            .chain(BENTO_HIERARCHY_RELATIONS.iter())
+            .map(|(parent, child)| HierarchyEdge {
+                parent: parent.to_string(),
+                child: child.to_string(),
+            })
+            .collect();
+
+        Self {
+            name: "sl-bento-default".to_string(),
+            bones,
+            hierarchy,
+            secondary_bones: Vec::new(),
+        }
+    }
+
+    /// Resolve the active profile for a conversion: the file at
+    /// `profile_path` when set, otherwise [`SkeletonProfile::default_sl_bento`].
+    pub fn resolve(profile_path: Option<&str>) -> Result<Self> {
+        match profile_path {
+            Some(path) => Self::load_from_file(Path::new(path)),
+            None => Ok(Self::default_sl_bento()),
+        }
+    }
+
+    /// Source bone names flagged `required` in this profile.
+    pub(super) fn required_sources(&self) -> Vec<&str> {
+        self.bones
+            .iter()
+            .filter(|bone| bone.required)
+            .map(|bone| bone.source.as_str())
+            .collect()
+    }
+
+    /// Source→target bone name pairs, in declaration order.
+    pub(super) fn bone_pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.bones
+            .iter()
+            .map(|bone| (bone.source.as_str(), bone.target.as_str()))
+    }
+
+    /// Source node name→target SL joint name pairs for spring/secondary
+    /// bone retargeting, in declaration order.
+    pub(super) fn secondary_bone_pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.secondary_bones
+            .iter()
+            .map(|bone| (bone.source.as_str(), bone.target.as_str()))
+    }
+
+    /// The skeleton root's source (VRM humanoid) bone name: the first bone,
+    /// in declaration order, that never appears as a `child` in `hierarchy`
+    /// (so with an empty hierarchy, simply the first bone). Falls back to
+    /// `"hips"` (the stock `sl-bento-default` root) only when every bone
+    /// appears as a child, e.g. a cyclic hierarchy list, or `bones` is empty.
+    pub(super) fn root_source(&self) -> &str {
+        let children: HashSet<&str> = self
+            .hierarchy
+            .iter()
+            .map(|edge| edge.child.as_str())
+            .collect();
+
+        self.bones
+            .iter()
+            .map(|bone| bone.source.as_str())
+            .find(|source| !children.contains(source))
+            .unwrap_or("hips")
+    }
+}
+
+impl Default for SkeletonProfile {
+    fn default() -> Self {
+        Self::default_sl_bento()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_no_profile_path_when_resolving_then_default_sl_bento_is_used() {
+        let profile = SkeletonProfile::resolve(None).expect("default profile should resolve");
+        assert_eq!(profile.name, "sl-bento-default");
+        assert!(profile.bones.iter().any(|bone| bone.source == "hips" && bone.required));
+        assert!(
+            profile
+                .bones
+                .iter()
+                .any(|bone| bone.source == "leftShoulder" && !bone.required)
+        );
+    }
+
+    #[test]
+    fn given_default_profile_when_listing_required_then_matches_required_bones_const() {
+        let profile = SkeletonProfile::default_sl_bento();
+        let required = profile.required_sources();
+        assert_eq!(required.len(), REQUIRED_BONES.len());
+        for bone in REQUIRED_BONES {
+            assert!(required.contains(&bone));
+        }
+    }
+
+    #[test]
+    fn given_bone_mapping_json_when_reference_local_transform_present_then_it_round_trips() {
+        let json = r#"{
+            "source": "hips",
+            "target": "mPelvis",
+            "required": true,
+            "reference_local_transform": {
+                "translation": [0.0, 1.0, 0.0],
+                "rotation": [0.0, 0.0, 0.0, 1.0]
+            }
+        }"#;
+
+        let bone: BoneMapping = serde_json::from_str(json).expect("bone mapping should parse");
+        let reference = bone
+            .reference_local_transform
+            .expect("reference_local_transform should be present");
+        assert_eq!(reference.translation, [0.0, 1.0, 0.0]);
+        assert_eq!(reference.rotation, [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn given_bone_mapping_json_when_reference_local_transform_absent_then_it_defaults_to_none() {
+        let json = r#"{"source": "hips", "target": "mPelvis", "required": true}"#;
+
+        let bone: BoneMapping = serde_json::from_str(json).expect("bone mapping should parse");
+        assert!(bone.reference_local_transform.is_none());
+    }
+
+    #[test]
+    fn given_hierarchy_edge_with_unknown_bone_when_loading_profile_then_load_fails() {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "vrm2sl-skeleton-profile-test-{}.json",
+            std::process::id()
+        ));
+        fs::write(
+            &path,
+            r#"{
+                "name": "broken",
+                "bones": [
+                    {"source": "hips", "target": "mPelvis", "required": true}
+                ],
+                "hierarchy": [
+                    {"parent": "hips", "child": "spine"}
+                ]
+            }"#,
+        )
+        .expect("failed to write profile file");
+
+        let result = SkeletonProfile::load_from_file(&path);
+
+        let _ = fs::remove_file(&path);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn given_consistent_hierarchy_when_loading_profile_then_load_succeeds() {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "vrm2sl-skeleton-profile-test-ok-{}.json",
+            std::process::id()
+        ));
+        fs::write(
+            &path,
+            r#"{
+                "name": "minimal",
+                "bones": [
+                    {"source": "hips", "target": "mPelvis", "required": true},
+                    {"source": "spine", "target": "mTorso", "required": true}
+                ],
+                "hierarchy": [
+                    {"parent": "hips", "child": "spine"}
+                ]
+            }"#,
+        )
+        .expect("failed to write profile file");
+
+        let result = SkeletonProfile::load_from_file(&path);
+
+        let _ = fs::remove_file(&path);
+        assert!(result.is_ok());
+    }
+}
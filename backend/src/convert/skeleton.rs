@@ -1,25 +1,295 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use anyhow::{Result, bail};
-use nalgebra::{Matrix4, Vector3, Vector4};
+use nalgebra::{Matrix4, Translation3, Unit, UnitQuaternion, Vector3, Vector4};
 use serde_json::Value;
 
+use crate::correction::pseudo_inverse_bind_matrix;
+use crate::pipeline::{
+    CorrectionOptions, NodeCorrectionInput, build_default_upper_limb_t_pose_targets,
+    correct_skeleton_to_t_pose, resolve_target_t_pose_rotation,
+};
+
 use super::gltf_utils::{
     accessor_meta, collect_node_name_set_from_json, collect_parent_index_map_from_json,
-    compute_node_world_matrices, node_to_local_matrix, read_joint_slot, read_weight_f32,
-    set_node_local_matrix, write_mat4_f32_le,
+    compose_trs, compute_node_world_matrices, compute_node_world_matrices_with_scale_isolation,
+    decompose_trs, is_weight_component_type, local_to_world, node_index_by_name,
+    node_to_local_matrix, read_joint_slot, read_mat4_from_accessor, read_weight_f32,
+    scene_containing_node, set_node_local_matrix, shear_magnitude, write_joint_slot,
+    write_mat4_f32_le, write_weight_f32,
 };
+use super::profile::SkeletonProfile;
 use super::types::{
-    BENTO_BONE_MAP, BENTO_HIERARCHY_RELATIONS, BONE_MAP, CORE_HIERARCHY_RELATIONS, ValidationIssue,
+    BENTO_BONE_MAP, BENTO_HIERARCHY_RELATIONS, BONE_MAP, CANONICAL_REFERENCE_HEIGHT_CM,
+    CANONICAL_SL_REST_POSITIONS, CORE_HIERARCHY_RELATIONS, EXTENDED_BONE_MAP,
+    EXTENDED_HIERARCHY_RELATIONS, FITTED_MESH_COLLISION_VOLUMES,
+    LIMB_COLLISION_VOLUME_LENGTH_BONES, ValidationIssue,
 };
+use super::validation::synthesizable_intermediate_bone;
+
+// ─── Skeleton pose cache ──────────────────────────────────────────────────────
+
+/// Owns a glTF node hierarchy's local transforms, parent links and
+/// topological (parent-before-child) order, with world (absolute) matrices
+/// computed lazily and cached until a local transform is mutated through
+/// [`SkeletonPose::set_local`].
+///
+/// [`reconstruct_sl_core_hierarchy`], [`normalize_sl_bone_rotations`],
+/// [`regenerate_inverse_bind_matrices`] and [`reposition_limb_chain`] each
+/// used to rebuild the parent map and recompute every node's world matrix
+/// from scratch, sometimes more than once per call as a chain's bones moved
+/// one after another. Routing them through one `SkeletonPose` means the
+/// hierarchy is read once and world matrices are only recomputed when a
+/// local transform actually changed, instead of on every pass.
+pub(super) struct SkeletonPose {
+    locals: Vec<Matrix4<f32>>,
+    parent_map: HashMap<usize, usize>,
+    topo_order: Vec<usize>,
+    worlds: Option<Vec<Matrix4<f32>>>,
+    dirty_nodes: HashSet<usize>,
+    scale_isolated_nodes: HashSet<usize>,
+}
+
+impl SkeletonPose {
+    /// Snapshot the current local transforms, parent links and topological
+    /// (parent-before-child) order from glTF JSON.
+    pub(super) fn from_json(json: &Value) -> Self {
+        let locals: Vec<Matrix4<f32>> = json
+            .get("nodes")
+            .and_then(Value::as_array)
+            .map(|nodes| nodes.iter().map(node_to_local_matrix).collect())
+            .unwrap_or_default();
+        let parent_map = collect_parent_index_map_from_json(json);
+        let topo_order = topological_node_order(json, &parent_map, locals.len());
+
+        Self {
+            locals,
+            parent_map,
+            topo_order,
+            worlds: None,
+            dirty_nodes: HashSet::new(),
+            scale_isolated_nodes: HashSet::new(),
+        }
+    }
+
+    /// Mark `nodes` as declining to inherit their parent's scale when their
+    /// world matrix is next computed (see
+    /// [`compute_node_world_matrices_with_scale_isolation`]) — the node still
+    /// inherits translation and rotation, just not scale. Used for joints
+    /// whose own authored size must stay stable regardless of what an
+    /// ancestor happens to be scaled to, e.g. SL fitted-mesh collision
+    /// volumes. Invalidates cached world matrices like
+    /// [`SkeletonPose::set_local`], since this changes how every descendant's
+    /// world matrix is derived.
+    pub(super) fn set_scale_isolated_nodes(&mut self, nodes: HashSet<usize>) {
+        self.scale_isolated_nodes = nodes;
+        self.worlds = None;
+    }
+
+    pub(super) fn parent_map(&self) -> &HashMap<usize, usize> {
+        &self.parent_map
+    }
+
+    pub(super) fn topo_order(&self) -> &[usize] {
+        &self.topo_order
+    }
+
+    /// A node's current local transform matrix, or `None` if `index` is out
+    /// of range.
+    pub(super) fn local(&self, index: usize) -> Option<Matrix4<f32>> {
+        self.locals.get(index).copied()
+    }
+
+    /// Replace a node's local transform, invalidating the cached world
+    /// matrices so the next [`SkeletonPose::worlds`] call recomputes them.
+    /// A no-op if `index` is out of range.
+    pub(super) fn set_local(&mut self, index: usize, matrix: Matrix4<f32>) {
+        if let Some(slot) = self.locals.get_mut(index) {
+            *slot = matrix;
+            self.worlds = None;
+            self.dirty_nodes.insert(index);
+        }
+    }
+
+    /// World (absolute) matrices for every node, recomputed only if a local
+    /// transform changed since the last call.
+    pub(super) fn worlds(&mut self) -> &[Matrix4<f32>] {
+        let scale_isolated_nodes = &self.scale_isolated_nodes;
+        self.worlds.get_or_insert_with(|| {
+            compute_node_world_matrices_with_scale_isolation(
+                &self.locals,
+                &self.parent_map,
+                scale_isolated_nodes,
+            )
+        })
+    }
+
+    /// A node's current world (absolute) matrix, or `None` if `index` is out
+    /// of range.
+    pub(super) fn world(&mut self, index: usize) -> Option<Matrix4<f32>> {
+        self.worlds().get(index).copied()
+    }
+
+    /// Inverse of a node's current world matrix (identity if singular), or
+    /// `None` if `index` is out of range.
+    pub(super) fn inverse_bind(&mut self, index: usize) -> Option<Matrix4<f32>> {
+        self.world(index)
+            .map(|world| world.try_inverse().unwrap_or_else(Matrix4::identity))
+    }
+
+    /// Overwrite each named bone's local rotation with its entry in
+    /// `target_local_rotations` (translation and scale are preserved), then
+    /// return the per-bone current→target correction quaternion keyed by
+    /// node index — the same correction
+    /// [`correct_mesh_vertices_for_bind_pose_change`] needs to rebake mesh
+    /// vertices into the new rest pose. A bone already at its target rotation
+    /// is left untouched rather than rewritten through a no-op
+    /// decompose/recompose round trip, so unaffected regions of the mesh stay
+    /// bit-stable. Each bone that IS rewritten invalidates the cached world
+    /// transforms as usual, so the next [`SkeletonPose::worlds`] call
+    /// repropagates the whole hierarchy from the new locals.
+    ///
+    /// The actual rotation blending is delegated to
+    /// [`correct_skeleton_to_t_pose`], batching together whichever target
+    /// bones are themselves parent/child (e.g. a shoulder feeding its
+    /// elbow's `parent_index`) so the correction propagates down the chain
+    /// the same way [`SkeletonPose::worlds`] would; a target bone whose real
+    /// parent isn't itself a target is anchored to that parent's current
+    /// (unmodified) world matrix. `weight` is always `1.0` here, matching
+    /// this method's pre-existing full-snap behavior exactly.
+    pub(super) fn bake_rest_pose(
+        &mut self,
+        humanoid_bone_nodes: &HashMap<String, usize>,
+        target_local_rotations: &HashMap<String, UnitQuaternion<f32>>,
+    ) -> Result<HashMap<usize, UnitQuaternion<f32>>> {
+        let target_node_indices: HashMap<usize, UnitQuaternion<f32>> = target_local_rotations
+            .iter()
+            .filter_map(|(bone_name, &rotation)| {
+                humanoid_bone_nodes
+                    .get(bone_name)
+                    .map(|&node_index| (node_index, rotation))
+            })
+            .collect();
+        if target_node_indices.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let worlds = self.worlds().to_vec();
+        let parent_map = self.parent_map().clone();
+
+        let mut current_rotations = HashMap::with_capacity(target_node_indices.len());
+        let mut translations = HashMap::with_capacity(target_node_indices.len());
+        let mut scales = HashMap::with_capacity(target_node_indices.len());
+        let mut inputs = Vec::with_capacity(target_node_indices.len());
+
+        for (&node_index, &target_rotation) in &target_node_indices {
+            let Some(local_matrix) = self.local(node_index) else {
+                continue;
+            };
+            let (translation, current_rotation, scale) = decompose_trs(&local_matrix);
+            current_rotations.insert(node_index, current_rotation);
+            translations.insert(node_index, translation);
+            scales.insert(node_index, scale);
+
+            let real_parent = parent_map.get(&node_index).copied();
+            let parent_index = real_parent.filter(|parent| target_node_indices.contains_key(parent));
+            let parent_world_matrix = real_parent
+                .and_then(|parent| worlds.get(parent).copied())
+                .unwrap_or_else(Matrix4::identity);
+
+            inputs.push(NodeCorrectionInput {
+                node_index,
+                node_name: String::new(),
+                parent_index,
+                current_local_rotation: current_rotation,
+                target_t_pose_rotation: Some(target_rotation),
+                weight: 1.0,
+                max_angle: None,
+                parent_world_matrix,
+                local_transform_matrix: local_matrix,
+                vertices: Vec::new(),
+            });
+        }
+
+        let options = CorrectionOptions {
+            allow_pseudo_inverse: true,
+            ..CorrectionOptions::default()
+        };
+        let results = correct_skeleton_to_t_pose(inputs, &options)
+            .map_err(|err| anyhow::anyhow!("{err}"))?;
+
+        let mut corrections = HashMap::with_capacity(results.len());
+        for result in results {
+            let current_rotation = current_rotations[&result.node_index];
+            let correction = result.corrected_local_rotation * current_rotation.inverse();
+            corrections.insert(result.node_index, correction);
+            if correction.angle().abs() < 1e-6 {
+                continue;
+            }
+            self.set_local(
+                result.node_index,
+                compose_trs(
+                    translations[&result.node_index],
+                    result.corrected_local_rotation,
+                    scales[&result.node_index],
+                ),
+            );
+        }
+        Ok(corrections)
+    }
+
+    /// Write every node whose local transform was changed through
+    /// [`SkeletonPose::set_local`] back into `json` as TRS.
+    pub(super) fn flush(&self, json: &mut Value) {
+        let Some(nodes) = json.get_mut("nodes").and_then(Value::as_array_mut) else {
+            return;
+        };
+        for &index in &self.dirty_nodes {
+            if let Some(node) = nodes.get_mut(index) {
+                set_node_local_matrix(node, &self.locals[index]);
+            }
+        }
+    }
+}
+
+/// BFS topological (parent-before-child) order over `node_count` nodes,
+/// starting from roots (nodes absent from `parent_map`).
+fn topological_node_order(
+    json: &Value,
+    parent_map: &HashMap<usize, usize>,
+    node_count: usize,
+) -> Vec<usize> {
+    let mut order = Vec::with_capacity(node_count);
+    let mut queue: VecDeque<usize> = (0..node_count)
+        .filter(|index| !parent_map.contains_key(index))
+        .collect();
+
+    while let Some(index) = queue.pop_front() {
+        order.push(index);
+        if let Some(children) = json["nodes"][index].get("children").and_then(Value::as_array) {
+            for child in children {
+                if let Some(child_index) = child.as_u64().map(|value| value as usize) {
+                    queue.push_back(child_index);
+                }
+            }
+        }
+    }
+
+    order
+}
 
 // ─── Bone renaming ────────────────────────────────────────────────────────────
 
-/// Rename known bones according to the VRM→SL mapping table.
-pub(super) fn rename_bones(json: &mut Value, humanoid_bone_nodes: &HashMap<String, usize>) {
+/// Rename known bones according to the active skeleton profile's mapping
+/// table (the stock VRM→SL table by default; see [`SkeletonProfile`]).
+pub(super) fn rename_bones(
+    json: &mut Value,
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
+) {
     if let Some(nodes) = json.get_mut("nodes").and_then(Value::as_array_mut) {
-        for (source, target) in BONE_MAP.iter().chain(BENTO_BONE_MAP.iter()) {
-            if let Some(node_index) = humanoid_bone_nodes.get(*source).copied() {
+        for (source, target) in profile.bone_pairs() {
+            if let Some(node_index) = humanoid_bone_nodes.get(source).copied() {
                 if let Some(node) = nodes.get_mut(node_index) {
                     node["name"] = Value::String(target.to_string());
                 }
@@ -28,21 +298,129 @@ pub(super) fn rename_bones(json: &mut Value, humanoid_bone_nodes: &HashMap<Strin
     }
 }
 
+/// Rename VRM spring/secondary bone nodes onto their SL Bento extension
+/// joint, per the active skeleton profile's `secondary_bones` table. Unlike
+/// [`rename_bones`], `secondary_bone_nodes` is keyed by the node's literal
+/// glTF name rather than a VRM humanoid semantic name (spring bones have
+/// neither), so the lookup is a direct index match rather than going
+/// through `humanoid_bone_nodes`.
+pub(super) fn retarget_secondary_bone_nodes(
+    json: &mut Value,
+    secondary_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
+) {
+    if secondary_bone_nodes.is_empty() {
+        return;
+    }
+    let Some(nodes) = json.get_mut("nodes").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for (source, target) in profile.secondary_bone_pairs() {
+        if let Some(node_index) = secondary_bone_nodes.get(source).copied() {
+            if let Some(node) = nodes.get_mut(node_index) {
+                node["name"] = Value::String(target.to_string());
+            }
+        }
+    }
+}
+
+/// Rename extended non-humanoid bone nodes (tail, wings, hind limbs, groin)
+/// onto their SL name, per [`EXTENDED_BONE_MAP`]. Like
+/// [`retarget_secondary_bone_nodes`], `extended_bone_nodes` is keyed by the
+/// node's literal glTF name rather than a VRM humanoid semantic name, since
+/// none of these bones have one.
+pub(super) fn rename_extended_bones(json: &mut Value, extended_bone_nodes: &HashMap<String, usize>) {
+    if extended_bone_nodes.is_empty() {
+        return;
+    }
+    let Some(nodes) = json.get_mut("nodes").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for (source, target) in EXTENDED_BONE_MAP {
+        if let Some(node_index) = extended_bone_nodes.get(source).copied() {
+            if let Some(node) = nodes.get_mut(node_index) {
+                node["name"] = Value::String(target.to_string());
+            }
+        }
+    }
+}
+
 // ─── Hierarchy reconstruction ─────────────────────────────────────────────────
 
-/// Reconstruct core humanoid hierarchy toward SL-compatible parent-child links.
+/// Walk `start`'s parent chain up to the scene root (the first node absent
+/// from `parent_index_map`), returning `[start, parent(start), ..., root]`.
+/// Returns `None` if a node is revisited before reaching a root, which means
+/// the hierarchy has a parent cycle rather than being a tree.
+fn ancestor_chain(parent_index_map: &HashMap<usize, usize>, start: usize) -> Option<Vec<usize>> {
+    let mut chain = vec![start];
+    let mut seen: HashSet<usize> = HashSet::from([start]);
+    let mut current = start;
+    while let Some(&parent) = parent_index_map.get(&current) {
+        if !seen.insert(parent) {
+            return None;
+        }
+        chain.push(parent);
+        current = parent;
+    }
+    Some(chain)
+}
+
+/// Classic tree `getChain` walk: collect the ancestors of `from_node` and of
+/// `to_node` up to the scene root, strip the longest shared suffix (their
+/// lowest common ancestor and everything above it), then splice the two
+/// unique segments back together through that ancestor. Returns the ordered
+/// node path from `from_node` down to `to_node`, or `None` if either index is
+/// out of range, a parent cycle is detected, or the two nodes don't share a
+/// root at all (an orphaned joint).
+pub(super) fn extract_bone_chain(
+    json: &Value,
+    parent_index_map: &HashMap<usize, usize>,
+    from_node: usize,
+    to_node: usize,
+) -> Option<Vec<usize>> {
+    let node_count = json
+        .get("nodes")
+        .and_then(Value::as_array)
+        .map(|nodes| nodes.len())
+        .unwrap_or(0);
+    if from_node >= node_count || to_node >= node_count {
+        return None;
+    }
+
+    let from_ancestors = ancestor_chain(parent_index_map, from_node)?;
+    let to_ancestors = ancestor_chain(parent_index_map, to_node)?;
+
+    let mut shared = 0;
+    while shared < from_ancestors.len()
+        && shared < to_ancestors.len()
+        && from_ancestors[from_ancestors.len() - 1 - shared]
+            == to_ancestors[to_ancestors.len() - 1 - shared]
+    {
+        shared += 1;
+    }
+    if shared == 0 {
+        return None;
+    }
+
+    let mut chain = from_ancestors[..from_ancestors.len() - shared + 1].to_vec();
+    chain.extend(to_ancestors[..to_ancestors.len() - shared].iter().rev());
+    Some(chain)
+}
+
+/// Reconstruct core humanoid hierarchy toward SL-compatible parent-child links,
+/// reporting every bone actually moved to a new parent so callers can surface
+/// what changed instead of silently rewriting the node tree.
 pub(super) fn reconstruct_sl_core_hierarchy(
     json: &mut Value,
     humanoid_bone_nodes: &HashMap<String, usize>,
-) {
-    let original_node_locals: Vec<Matrix4<f32>> = json
-        .get("nodes")
-        .and_then(Value::as_array)
-        .map(|nodes| nodes.iter().map(node_to_local_matrix).collect())
-        .unwrap_or_default();
+) -> Vec<ValidationIssue> {
+    let mut original_pose = SkeletonPose::from_json(json);
+    let original_node_worlds = original_pose.worlds();
     let original_parent_map = collect_parent_index_map_from_json(json);
-    let original_node_worlds =
-        compute_node_world_matrices(&original_node_locals, &original_parent_map);
+    let node_source_name: HashMap<usize, &str> = humanoid_bone_nodes
+        .iter()
+        .map(|(source, &index)| (index, source.as_str()))
+        .collect();
 
     let planned_links: Vec<(usize, usize)> = {
         // Build a child→parent map; later entries (refinement) override
@@ -71,8 +449,139 @@ pub(super) fn reconstruct_sl_core_hierarchy(
             .collect()
     };
 
+    let mut issues = Vec::new();
+
+    // A bone whose source rig doesn't actually reach 'hips' (an orphaned
+    // joint, or a parent cycle in a malformed rig) can't be safely relinked:
+    // its original world transform isn't meaningfully relative to the rest
+    // of the skeleton, so skip it rather than graft it onto the SL chain.
+    let planned_links: Vec<(usize, usize)> =
+        if let Some(&hips_index) = humanoid_bone_nodes.get("hips") {
+            planned_links
+                .into_iter()
+                .filter(|&(_, child_index)| {
+                    if child_index == hips_index
+                        || extract_bone_chain(json, &original_parent_map, hips_index, child_index)
+                            .is_some()
+                    {
+                        return true;
+                    }
+                    let child_name = node_source_name.get(&child_index).copied().unwrap_or("?");
+                    issues.push(ValidationIssue {
+                        severity: super::types::Severity::Warning,
+                        code: "ORPHANED_BONE_SKIPPED".to_string(),
+                        message: format!(
+                            "[WARNING] Skipped reparenting '{}' because it isn't reachable from 'hips' in the source hierarchy (orphaned joint or parent cycle)",
+                            child_name
+                        ),
+                    });
+                    false
+                })
+                .collect()
+        } else {
+            planned_links
+        };
+
     if planned_links.is_empty() {
-        return;
+        return issues;
+    }
+
+    issues.extend(apply_planned_reparent(
+        json,
+        &original_parent_map,
+        original_node_worlds,
+        planned_links,
+        &node_source_name,
+    ));
+    issues
+}
+
+/// Reconstruct extended non-humanoid hierarchy (tail, wings, hind limbs,
+/// groin) toward their SL parent joints, per [`EXTENDED_HIERARCHY_RELATIONS`].
+/// Mirrors [`reconstruct_sl_core_hierarchy`]'s world-position-preserving
+/// reparenting, but its root anchors (`hips`, `spine`) are ordinary mapped
+/// humanoid bones, so both `humanoid_bone_nodes` and `extended_bone_nodes`
+/// are consulted to resolve an edge's endpoints.
+pub(super) fn reconstruct_extended_bone_hierarchy(
+    json: &mut Value,
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    extended_bone_nodes: &HashMap<String, usize>,
+) -> Vec<ValidationIssue> {
+    if extended_bone_nodes.is_empty() {
+        return Vec::new();
+    }
+
+    let mut original_pose = SkeletonPose::from_json(json);
+    let original_node_worlds = original_pose.worlds();
+    let original_parent_map = collect_parent_index_map_from_json(json);
+
+    let combined_bone_nodes: HashMap<&str, usize> = humanoid_bone_nodes
+        .iter()
+        .map(|(name, &index)| (name.as_str(), index))
+        .chain(
+            extended_bone_nodes
+                .iter()
+                .map(|(name, &index)| (name.as_str(), index)),
+        )
+        .collect();
+    let node_source_name: HashMap<usize, &str> = combined_bone_nodes
+        .iter()
+        .map(|(&name, &index)| (index, name))
+        .collect();
+
+    let planned_links: Vec<(usize, usize)> = EXTENDED_HIERARCHY_RELATIONS
+        .iter()
+        .filter_map(|(parent, child)| {
+            let parent_index = combined_bone_nodes.get(parent).copied()?;
+            let child_index = combined_bone_nodes.get(child).copied()?;
+            (parent_index != child_index).then_some((parent_index, child_index))
+        })
+        .collect();
+
+    if planned_links.is_empty() {
+        return Vec::new();
+    }
+
+    apply_planned_reparent(
+        json,
+        &original_parent_map,
+        original_node_worlds,
+        planned_links,
+        &node_source_name,
+    )
+}
+
+/// Apply a set of planned parent→child relinks, preserving each child's
+/// current world transform as its new local transform under its new parent,
+/// and updating `children`/scene-root node lists to match. Shared by
+/// [`reconstruct_sl_core_hierarchy`] and [`reconstruct_extended_bone_hierarchy`].
+fn apply_planned_reparent(
+    json: &mut Value,
+    original_parent_map: &HashMap<usize, usize>,
+    original_node_worlds: &[Matrix4<f32>],
+    planned_links: Vec<(usize, usize)>,
+    node_source_name: &HashMap<usize, &str>,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    for &(planned_parent_index, child_index) in &planned_links {
+        let actual_parent_index = original_parent_map.get(&child_index).copied();
+        if actual_parent_index == Some(planned_parent_index) {
+            continue;
+        }
+        let child_name = node_source_name.get(&child_index).copied().unwrap_or("?");
+        let parent_name = node_source_name
+            .get(&planned_parent_index)
+            .copied()
+            .unwrap_or("?");
+        issues.push(ValidationIssue {
+            severity: super::types::Severity::Info,
+            code: "BONE_HIERARCHY_REPAIRED".to_string(),
+            message: format!(
+                "[INFO] Reparented '{}' under '{}' to match the SL-compatible hierarchy",
+                child_name, parent_name
+            ),
+        });
     }
 
     let controlled_children: HashSet<usize> =
@@ -142,6 +651,8 @@ pub(super) fn reconstruct_sl_core_hierarchy(
                 .unwrap_or(true)
         });
     }
+
+    issues
 }
 
 // ─── Bone precondition validation ─────────────────────────────────────────────
@@ -181,16 +692,120 @@ pub(super) fn validate_bone_conversion_preconditions(
         .collect()
 }
 
+/// Compare each mapped bone's post-normalization world position against
+/// Second Life's canonical Bento rest-skeleton offsets, emitting a `Warning`
+/// for any bone that drifts beyond its tolerance.
+///
+/// `world_matrices` must be the world matrices returned by
+/// [`normalize_sl_bone_rotations`] (taken *before* that function zeroes local
+/// rotations): it deliberately preserves every SL-mapped bone's world-space
+/// translation, so that snapshot already reflects the post-normalization
+/// bind pose without needing to be recomputed here.
+///
+/// [`CANONICAL_SL_REST_POSITIONS`] was authored at
+/// [`CANONICAL_REFERENCE_HEIGHT_CM`]; both the canonical position and its
+/// tolerance are scaled by `estimated_height_cm / CANONICAL_REFERENCE_HEIGHT_CM`
+/// so the comparison stays proportion-relative across avatar heights.
+pub(super) fn validate_sl_rest_skeleton_positions(
+    world_matrices: &[Matrix4<f32>],
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    estimated_height_cm: f32,
+) -> Vec<ValidationIssue> {
+    if estimated_height_cm <= 0.0 {
+        return Vec::new();
+    }
+    let height_ratio = estimated_height_cm / CANONICAL_REFERENCE_HEIGHT_CM;
+
+    BONE_MAP
+        .iter()
+        .filter_map(|(source, target)| {
+            let node_index = humanoid_bone_nodes.get(*source).copied()?;
+            let world = world_matrices.get(node_index)?;
+            let (canonical_position, base_tolerance) = CANONICAL_SL_REST_POSITIONS
+                .iter()
+                .find(|(name, _, _)| name == target)
+                .map(|(_, position, tolerance)| (*position, *tolerance))?;
+
+            let expected = Vector3::new(
+                canonical_position[0],
+                canonical_position[1],
+                canonical_position[2],
+            ) * height_ratio;
+            let actual = Vector3::new(world[(0, 3)], world[(1, 3)], world[(2, 3)]);
+            let deviation = (actual - expected).norm();
+            let tolerance = base_tolerance * height_ratio;
+
+            if deviation > tolerance {
+                Some(ValidationIssue {
+                    severity: super::types::Severity::Warning,
+                    code: "REST_SKELETON_DEVIATION".to_string(),
+                    message: format!(
+                        "⚠️ Bone '{}' is {:.3}m from Second Life's canonical rest position (tolerance {:.3}m) — deformation around this joint may look distorted in SL",
+                        target, deviation, tolerance
+                    ),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Shear magnitude above which a bone's local transform is flagged as
+/// carrying real shear rather than floating-point noise.
+const SHEAR_EPSILON: f32 = 1e-4;
+
+/// Detect non-negligible shear baked into any SL-mapped bone's local
+/// transform matrix, most commonly introduced when
+/// [`reconstruct_sl_core_hierarchy`] re-parents a bone under a
+/// non-uniformly scaled ancestor. [`decompose_trs`] reduces a matrix to
+/// translation/rotation/scale and silently drops any shear in the process,
+/// so without this check a sheared bone would quietly bake an incorrect
+/// rest rotation with no indication anything went wrong.
+///
+/// Must run before [`normalize_sl_bone_rotations`], which forces every
+/// SL-mapped bone's scale back to identity and would otherwise erase the
+/// evidence this check looks for.
+pub(super) fn collect_node_shear_issues(
+    json: &Value,
+    humanoid_bone_nodes: &HashMap<String, usize>,
+) -> Vec<ValidationIssue> {
+    let nodes = json.get("nodes").and_then(Value::as_array);
+
+    BONE_MAP
+        .iter()
+        .chain(BENTO_BONE_MAP.iter())
+        .filter_map(|(source, target)| {
+            let node_index = humanoid_bone_nodes.get(*source).copied()?;
+            let node = nodes.and_then(|nodes| nodes.get(node_index))?;
+            let shear = shear_magnitude(&node_to_local_matrix(node));
+
+            if shear > SHEAR_EPSILON {
+                Some(ValidationIssue {
+                    severity: super::types::Severity::Warning,
+                    code: "BONE_TRANSFORM_SHEAR_DETECTED".to_string(),
+                    message: format!(
+                        "⚠️ Bone '{}' has shear ({:.5}) baked into its local transform from a non-uniformly scaled parent — its rest rotation may be incorrect",
+                        target, shear
+                    ),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 /// Ensure all expected target SL bone names exist after rename.
 pub(super) fn ensure_target_bones_exist_after_rename(
     json: &Value,
     humanoid_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
 ) -> Result<()> {
     let node_name_set = collect_node_name_set_from_json(json);
 
-    let expected_targets: Vec<String> = BONE_MAP
-        .iter()
-        .chain(BENTO_BONE_MAP.iter())
+    let expected_targets: Vec<String> = profile
+        .bone_pairs()
         .filter(|(source, _)| humanoid_bone_nodes.contains_key(*source))
         .map(|(_, target)| target.to_string())
         .collect();
@@ -210,93 +825,538 @@ pub(super) fn ensure_target_bones_exist_after_rename(
     Ok(())
 }
 
-// ─── Rotation normalisation ───────────────────────────────────────────────────
+// ─── A-pose to T-pose correction ──────────────────────────────────────────────
 
-/// Normalize the local rotation of every SL-mapped bone to identity while
-/// preserving the bone's world-space position.
-///
-/// Second Life reads joint bind-positions from the inverse-bind-matrix
-/// (4th column) and then applies its **own** default (identity) orientations
-/// when deforming the mesh. Any non-identity local rotation baked into the
-/// glTF node hierarchy will therefore cause incorrect deformation because the
-/// IBM accounts for the rotation but SL does not re-apply it.
+/// Straighten VRoid's natural A-pose arms into Second Life's expected T-pose
+/// before any other bone transform is baked, rebaking skinned mesh vertices so
+/// the mesh keeps its visual shape.
 ///
-/// Bones are processed in topological (parent-before-child) order so that each
-/// child uses the already-corrected parent world position when computing its
-/// own local translation.
+/// Each upper-limb bone listed in [`build_default_upper_limb_t_pose_targets`]
+/// is corrected independently via [`SkeletonPose::bake_rest_pose`] (a single
+/// local-rotation overwrite, not an IK solve), so children inherit the new
+/// pose through the normal parent-child hierarchy rather than being
+/// corrected individually. This must run before [`normalize_sl_bone_rotations`],
+/// which bakes whatever pose is current into zero-rotation translations.
 ///
-/// Returns the **pre-normalization** node world matrices so that the caller can
-/// correct mesh vertex positions for the bind-pose change.
-pub(super) fn normalize_sl_bone_rotations(
+/// Returns `true` when at least one bone was corrected, so the caller can
+/// report whether the correction had any effect.
+pub(super) fn correct_a_pose_to_t_pose(
     json: &mut Value,
+    bin: &mut [u8],
     humanoid_bone_nodes: &HashMap<String, usize>,
-) -> Vec<Matrix4<f32>> {
-    let sl_node_indices: HashSet<usize> = BONE_MAP
-        .iter()
-        .chain(BENTO_BONE_MAP.iter())
-        .filter_map(|(vrm_name, _)| humanoid_bone_nodes.get(*vrm_name).copied())
-        .collect();
+) -> Result<bool> {
+    let targets = build_default_upper_limb_t_pose_targets();
 
-    let node_locals: Vec<Matrix4<f32>> = json["nodes"]
-        .as_array()
-        .map(|nodes| nodes.iter().map(node_to_local_matrix).collect())
-        .unwrap_or_default();
-    let parent_map = collect_parent_index_map_from_json(json);
-    let node_worlds_snapshot = compute_node_world_matrices(&node_locals, &parent_map);
+    let mut pose = SkeletonPose::from_json(json);
+    let old_worlds = pose.worlds().to_vec();
 
-    let node_count = json["nodes"].as_array().map(|a| a.len()).unwrap_or(0);
+    let target_local_rotations: HashMap<String, UnitQuaternion<f32>> = humanoid_bone_nodes
+        .keys()
+        .filter_map(|bone_name| {
+            resolve_target_t_pose_rotation(bone_name, &targets)
+                .map(|rotation| (bone_name.clone(), rotation))
+        })
+        .collect();
 
-    // BFS topological order (parents before children).
-    let mut topo_order: Vec<usize> = Vec::with_capacity(node_count);
-    {
-        let mut queue: std::collections::VecDeque<usize> = (0..node_count)
-            .filter(|&i| !parent_map.contains_key(&i))
-            .collect();
-        while let Some(idx) = queue.pop_front() {
-            topo_order.push(idx);
-            if let Some(children) = json["nodes"][idx].get("children").and_then(Value::as_array) {
-                for child in children {
-                    if let Some(c) = child.as_u64().map(|v| v as usize) {
-                        queue.push_back(c);
-                    }
-                }
-            }
-        }
-    }
+    let corrections = pose.bake_rest_pose(humanoid_bone_nodes, &target_local_rotations)?;
+    let corrected_any = corrections
+        .values()
+        .any(|correction| correction.angle().abs() >= 1e-6);
 
-    let mut effective_world_t: Vec<Vector3<f32>> = node_worlds_snapshot
-        .iter()
-        .map(|m| Vector3::new(m[(0, 3)], m[(1, 3)], m[(2, 3)]))
-        .collect();
-    while effective_world_t.len() < node_count {
-        effective_world_t.push(Vector3::zeros());
+    if corrected_any {
+        pose.flush(json);
+        correct_mesh_vertices_for_bind_pose_change(json, bin, &old_worlds)?;
     }
 
-    for &node_idx in &topo_order {
-        if !sl_node_indices.contains(&node_idx) {
-            continue;
-        }
+    Ok(corrected_any)
+}
 
-        let snapshot_t = match node_worlds_snapshot.get(node_idx) {
-            Some(m) => Vector3::new(m[(0, 3)], m[(1, 3)], m[(2, 3)]),
-            None => continue,
-        };
+/// One arm/leg two-bone chain to re-pose, keyed by VRM source bone names
+/// (root → mid → end), plus the world-space axis the end bone should be
+/// straightened along for Second Life's T-pose rest and the anatomical pole
+/// vector its mid joint should bend toward.
+struct LimbChain {
+    root: &'static str,
+    mid: &'static str,
+    end: &'static str,
+    axis: Vector3<f32>,
+    pole: Vector3<f32>,
+}
 
-        let parent_effective_t = match parent_map.get(&node_idx) {
-            Some(&parent_idx) => effective_world_t[parent_idx],
-            None => Vector3::zeros(),
-        };
+/// Analytic two-bone IK targets for SL's T-pose rest: arms straighten out
+/// horizontally (+/-Y), legs hang straight down (-Z), matching the axis
+/// convention used by [`super::types::CANONICAL_SL_REST_POSITIONS`]. Elbows
+/// bend forward (+X) and knees bend backward (-X), matching typical human
+/// anatomy, so the bend plane is anatomically correct even when the source
+/// pose's own chain direction is too straight or ambiguous to infer it from.
+fn sl_t_pose_limb_chains() -> [LimbChain; 4] {
+    const ELBOW_POLE: Vector3<f32> = Vector3::new(1.0, 0.0, 0.0);
+    const KNEE_POLE: Vector3<f32> = Vector3::new(-1.0, 0.0, 0.0);
+    [
+        LimbChain {
+            root: "leftUpperArm",
+            mid: "leftLowerArm",
+            end: "leftHand",
+            axis: Vector3::new(0.0, 1.0, 0.0),
+            pole: ELBOW_POLE,
+        },
+        LimbChain {
+            root: "rightUpperArm",
+            mid: "rightLowerArm",
+            end: "rightHand",
+            axis: Vector3::new(0.0, -1.0, 0.0),
+            pole: ELBOW_POLE,
+        },
+        LimbChain {
+            root: "leftUpperLeg",
+            mid: "leftLowerLeg",
+            end: "leftFoot",
+            axis: Vector3::new(0.0, 0.0, -1.0),
+            pole: KNEE_POLE,
+        },
+        LimbChain {
+            root: "rightUpperLeg",
+            mid: "rightLowerLeg",
+            end: "rightFoot",
+            axis: Vector3::new(0.0, 0.0, -1.0),
+            pole: KNEE_POLE,
+        },
+    ]
+}
 
-        let local_t = snapshot_t - parent_effective_t;
-        effective_world_t[node_idx] = parent_effective_t + local_t;
+/// Re-pose each arm/leg chain from its authored pose (typically VRoid's
+/// A-pose) into Second Life's canonical T-pose rest direction using analytic
+/// two-bone IK, then rebakes skinned mesh vertices for the resulting bind
+/// pose change.
+///
+/// This is an alternative to [`correct_a_pose_to_t_pose`]'s fixed per-bone
+/// rotation targets: rather than rotating each upper-limb bone to a single
+/// known target rotation, it solves each root/mid/end chain's two interior
+/// joint angles from its own bone lengths, so it also straightens chains
+/// (e.g. legs) that don't have a single well-known target rotation.
+///
+/// Must run before [`normalize_sl_bone_rotations`], which bakes whatever pose
+/// is current into zero-rotation translations.
+///
+/// Returns `true` when at least one chain was re-posed.
+pub(super) fn repose_limbs_to_sl_t_pose(
+    json: &mut Value,
+    bin: &mut [u8],
+    humanoid_bone_nodes: &HashMap<String, usize>,
+) -> Result<bool> {
+    let mut pose = SkeletonPose::from_json(json);
+    let old_worlds = pose.worlds().to_vec();
 
-        if let Some(obj) = json["nodes"][node_idx].as_object_mut() {
-            obj.remove("matrix");
-            obj.insert(
-                "translation".to_string(),
-                serde_json::json!([local_t.x, local_t.y, local_t.z]),
-            );
-            obj.insert(
+    let mut reposed_any = false;
+
+    for chain in sl_t_pose_limb_chains() {
+        let Some(&root_idx) = humanoid_bone_nodes.get(chain.root) else {
+            continue;
+        };
+        let Some(&mid_idx) = humanoid_bone_nodes.get(chain.mid) else {
+            continue;
+        };
+        let Some(&end_idx) = humanoid_bone_nodes.get(chain.end) else {
+            continue;
+        };
+
+        if reposition_limb_chain(
+            &mut pose, root_idx, mid_idx, end_idx, chain.axis, chain.pole,
+        )? {
+            reposed_any = true;
+        }
+    }
+
+    if reposed_any {
+        pose.flush(json);
+        correct_mesh_vertices_for_bind_pose_change(json, bin, &old_worlds)?;
+    }
+
+    Ok(reposed_any)
+}
+
+/// Solve and apply the two-bone IK correction for a single root/mid/end
+/// chain. See [`repose_limbs_to_sl_t_pose`] for the overall algorithm.
+fn reposition_limb_chain(
+    pose: &mut SkeletonPose,
+    root_idx: usize,
+    mid_idx: usize,
+    end_idx: usize,
+    axis: Vector3<f32>,
+    pole: Vector3<f32>,
+) -> Result<bool> {
+    let worlds_snapshot = pose.worlds().to_vec();
+    let (Some(&root_world), Some(&mid_world), Some(&end_world)) = (
+        worlds_snapshot.get(root_idx),
+        worlds_snapshot.get(mid_idx),
+        worlds_snapshot.get(end_idx),
+    ) else {
+        return Ok(false);
+    };
+
+    let root_pos = Vector3::new(root_world[(0, 3)], root_world[(1, 3)], root_world[(2, 3)]);
+    let mid_pos = Vector3::new(mid_world[(0, 3)], mid_world[(1, 3)], mid_world[(2, 3)]);
+    let end_pos = Vector3::new(end_world[(0, 3)], end_world[(1, 3)], end_world[(2, 3)]);
+
+    let l1 = (mid_pos - root_pos).norm();
+    let l2 = (end_pos - mid_pos).norm();
+    if l1 < 1e-6 || l2 < 1e-6 {
+        return Ok(false);
+    }
+
+    let target_end = root_pos + axis.normalize() * (l1 + l2);
+    let to_target = target_end - root_pos;
+    let to_target_len = to_target.norm();
+    if to_target_len < 1e-6 {
+        return Ok(false);
+    }
+    let d = to_target_len.clamp((l1 - l2).abs(), l1 + l2);
+    let target_dir = to_target / to_target_len;
+
+    let old_root_to_mid_dir = (mid_pos - root_pos).normalize();
+    let old_mid_to_end_dir = (end_pos - mid_pos).normalize();
+
+    // Bend plane normal from the chain's anatomical pole vector (elbows
+    // forward, knees backward), so the bend direction stays correct even
+    // when the source pose's own chain direction is too close to straight
+    // to disambiguate it. Falls back to the original chain direction, then
+    // fixed world axes, only when the pole itself is degenerate against the
+    // target direction.
+    let mut plane_normal = target_dir.cross(&pole);
+    if plane_normal.norm() < 1e-6 {
+        plane_normal = target_dir.cross(&old_root_to_mid_dir);
+    }
+    if plane_normal.norm() < 1e-6 {
+        plane_normal = target_dir.cross(&Vector3::new(0.0, 0.0, 1.0));
+    }
+    if plane_normal.norm() < 1e-6 {
+        plane_normal = target_dir.cross(&Vector3::new(1.0, 0.0, 0.0));
+    }
+    let plane_normal = plane_normal.normalize();
+
+    // Law of cosines: interior angle at the root between the new mid
+    // direction and the root→target axis.
+    let cos_alpha = ((l1 * l1 + d * d - l2 * l2) / (2.0 * l1 * d)).clamp(-1.0, 1.0);
+    let alpha = cos_alpha.acos();
+
+    let new_root_to_mid_dir =
+        UnitQuaternion::from_axis_angle(&Unit::new_normalize(plane_normal), alpha) * target_dir;
+
+    let Some(new_root_local) = local_rotation_for_new_world_direction(
+        pose,
+        root_idx,
+        old_root_to_mid_dir,
+        new_root_to_mid_dir,
+    ) else {
+        return Ok(false);
+    };
+    pose.set_local(root_idx, new_root_local);
+
+    // The root's rotation just changed, so the cached world matrices are
+    // dirty; this next `pose.world` call lazily recomputes them before
+    // solving the mid node's new orientation.
+    let Some(mid_world_after_root) = pose.world(mid_idx) else {
+        return Ok(true);
+    };
+    let mid_pos_after_root = Vector3::new(
+        mid_world_after_root[(0, 3)],
+        mid_world_after_root[(1, 3)],
+        mid_world_after_root[(2, 3)],
+    );
+
+    let new_mid_to_target_dir = (target_end - mid_pos_after_root).normalize();
+
+    if let Some(new_mid_local) = local_rotation_for_new_world_direction(
+        pose,
+        mid_idx,
+        old_mid_to_end_dir,
+        new_mid_to_target_dir,
+    ) {
+        pose.set_local(mid_idx, new_mid_local);
+    }
+
+    Ok(true)
+}
+
+/// Compute the new local transform for `node_idx` that rotates its
+/// world-space bone direction from `old_dir` to `new_dir`, keeping its
+/// translation and scale unchanged.
+fn local_rotation_for_new_world_direction(
+    pose: &mut SkeletonPose,
+    node_idx: usize,
+    old_dir: Vector3<f32>,
+    new_dir: Vector3<f32>,
+) -> Option<Matrix4<f32>> {
+    let local_matrix = pose.local(node_idx)?;
+    let (translation, current_local_rotation, scale) = decompose_trs(&local_matrix);
+
+    let parent_idx = pose.parent_map().get(&node_idx).copied();
+    let parent_world_rotation = match parent_idx {
+        Some(parent_idx) => decompose_trs(&pose.world(parent_idx)?).1,
+        None => UnitQuaternion::identity(),
+    };
+
+    let delta_world =
+        UnitQuaternion::rotation_between(&old_dir, &new_dir).unwrap_or_else(UnitQuaternion::identity);
+    let new_local_rotation =
+        parent_world_rotation.inverse() * delta_world * parent_world_rotation * current_local_rotation;
+
+    Some(compose_trs(translation, new_local_rotation, scale))
+}
+
+// ─── Arm-segment T-pose retargeting ────────────────────────────────────────────
+
+/// One arm chain segment to straighten, keyed by VRM source bone names
+/// (parent -> child), plus the world-space axis that segment should align to
+/// in Second Life's T-pose rest.
+struct ArmSegment {
+    parent: &'static str,
+    child: &'static str,
+    target_axis: Vector3<f32>,
+}
+
+/// Shoulder->upperArm, upperArm->lowerArm and lowerArm->hand segments for
+/// both arms, targeting SL's horizontal rest axis (+/-Y, matching
+/// [`sl_t_pose_limb_chains`]), with a small downward slope on the
+/// lowerArm->hand segment since SL's own T-pose rest lets the hand droop
+/// slightly rather than staying perfectly horizontal.
+fn sl_t_pose_arm_segments() -> [ArmSegment; 6] {
+    const HAND_DROOP: f32 = 0.12;
+    [
+        ArmSegment {
+            parent: "leftShoulder",
+            child: "leftUpperArm",
+            target_axis: Vector3::new(0.0, 1.0, 0.0),
+        },
+        ArmSegment {
+            parent: "leftUpperArm",
+            child: "leftLowerArm",
+            target_axis: Vector3::new(0.0, 1.0, 0.0),
+        },
+        ArmSegment {
+            parent: "leftLowerArm",
+            child: "leftHand",
+            target_axis: Vector3::new(0.0, 1.0, -HAND_DROOP).normalize(),
+        },
+        ArmSegment {
+            parent: "rightShoulder",
+            child: "rightUpperArm",
+            target_axis: Vector3::new(0.0, -1.0, 0.0),
+        },
+        ArmSegment {
+            parent: "rightUpperArm",
+            child: "rightLowerArm",
+            target_axis: Vector3::new(0.0, -1.0, 0.0),
+        },
+        ArmSegment {
+            parent: "rightLowerArm",
+            child: "rightHand",
+            target_axis: Vector3::new(0.0, -1.0, -HAND_DROOP).normalize(),
+        },
+    ]
+}
+
+/// Corrections beyond this angle are reported as a [`ValidationIssue`]
+/// warning instead of an info note, since a rig rarely needs this much
+/// straightening and it usually means a humanoid bone was mis-mapped.
+const LARGE_TPOSE_CORRECTION_DEGREES: f32 = 60.0;
+
+/// Straighten each arm chain segment's world-space direction onto SL's
+/// T-pose rest axis, rotating the segment's parent bone and counter-rotating
+/// its immediate child so only that one joint's bend changes and everything
+/// further down the chain keeps its current orientation.
+///
+/// Complements [`correct_a_pose_to_t_pose`]'s fixed per-bone targets (which
+/// don't cover the shoulder segment) and runs independently of
+/// [`repose_limbs_to_sl_t_pose`]'s two-bone IK. Must run before
+/// [`normalize_sl_bone_rotations`], which bakes whatever pose is current
+/// into zero-rotation translations and would otherwise erase this
+/// correction.
+///
+/// Segments shorter than ~1mm are skipped, since their direction is
+/// meaningless. Returns one info/warning [`ValidationIssue`] per segment
+/// actually corrected, reporting the angle applied.
+pub(super) fn retarget_to_tpose(
+    json: &mut Value,
+    bin: &mut [u8],
+    humanoid_bone_nodes: &HashMap<String, usize>,
+) -> Result<Vec<ValidationIssue>> {
+    let mut pose = SkeletonPose::from_json(json);
+    let old_worlds = pose.worlds().to_vec();
+
+    let mut issues = Vec::new();
+    let mut corrected_any = false;
+
+    for segment in sl_t_pose_arm_segments() {
+        let Some(&parent_idx) = humanoid_bone_nodes.get(segment.parent) else {
+            continue;
+        };
+        let Some(&child_idx) = humanoid_bone_nodes.get(segment.child) else {
+            continue;
+        };
+        let (Some(parent_world_before), Some(child_world_before)) =
+            (pose.world(parent_idx), pose.world(child_idx))
+        else {
+            continue;
+        };
+
+        let parent_pos = Vector3::new(
+            parent_world_before[(0, 3)],
+            parent_world_before[(1, 3)],
+            parent_world_before[(2, 3)],
+        );
+        let child_pos = Vector3::new(
+            child_world_before[(0, 3)],
+            child_world_before[(1, 3)],
+            child_world_before[(2, 3)],
+        );
+        let current_dir = child_pos - parent_pos;
+        let length = current_dir.norm();
+        if length < 1e-3 {
+            continue;
+        }
+        let current_dir = current_dir / length;
+
+        let delta_world = UnitQuaternion::rotation_between(&current_dir, &segment.target_axis)
+            .unwrap_or_else(UnitQuaternion::identity);
+        let angle_deg = delta_world.angle().to_degrees();
+        if angle_deg < 1e-2 {
+            continue;
+        }
+
+        let parent_world_rotation_before = decompose_trs(&parent_world_before).1;
+
+        let Some(new_parent_local) =
+            local_rotation_for_new_world_direction(&mut pose, parent_idx, current_dir, segment.target_axis)
+        else {
+            continue;
+        };
+        pose.set_local(parent_idx, new_parent_local);
+
+        if let Some(new_child_local) =
+            counter_rotate_child_for_parent_delta(&pose, child_idx, parent_world_rotation_before, delta_world)
+        {
+            pose.set_local(child_idx, new_child_local);
+        }
+
+        corrected_any = true;
+        let is_large = angle_deg > LARGE_TPOSE_CORRECTION_DEGREES;
+        issues.push(ValidationIssue {
+            severity: if is_large {
+                super::types::Severity::Warning
+            } else {
+                super::types::Severity::Info
+            },
+            code: if is_large {
+                "TPOSE_RETARGET_LARGE_CORRECTION".to_string()
+            } else {
+                "TPOSE_RETARGET_APPLIED".to_string()
+            },
+            message: format!(
+                "[{}] Straightened '{}' -> '{}' toward T-pose by {:.1} degrees{}",
+                if is_large { "WARNING" } else { "INFO" },
+                segment.parent,
+                segment.child,
+                angle_deg,
+                if is_large {
+                    " (unusually large; check for a mis-mapped bone)"
+                } else {
+                    ""
+                }
+            ),
+        });
+    }
+
+    if corrected_any {
+        pose.flush(json);
+        correct_mesh_vertices_for_bind_pose_change(json, bin, &old_worlds)?;
+    }
+
+    Ok(issues)
+}
+
+/// Compute the local rotation `child_idx` needs so that its world rotation
+/// stays exactly what it was before `delta_world` was applied to its
+/// parent's local rotation, given the parent's world rotation as it was
+/// *before* that change. Used to keep a chain's lower joints visually
+/// unaffected when [`retarget_to_tpose`] straightens the joint above them.
+fn counter_rotate_child_for_parent_delta(
+    pose: &SkeletonPose,
+    child_idx: usize,
+    parent_world_rotation_before: UnitQuaternion<f32>,
+    delta_world: UnitQuaternion<f32>,
+) -> Option<Matrix4<f32>> {
+    let local_matrix = pose.local(child_idx)?;
+    let (translation, current_local_rotation, scale) = decompose_trs(&local_matrix);
+
+    let new_local_rotation = parent_world_rotation_before.inverse()
+        * delta_world.inverse()
+        * parent_world_rotation_before
+        * current_local_rotation;
+
+    Some(compose_trs(translation, new_local_rotation, scale))
+}
+
+// ─── Rotation normalisation ───────────────────────────────────────────────────
+
+/// Normalize the local rotation of every SL-mapped bone to identity while
+/// preserving the bone's world-space position.
+///
+/// Second Life reads joint bind-positions from the inverse-bind-matrix
+/// (4th column) and then applies its **own** default (identity) orientations
+/// when deforming the mesh. Any non-identity local rotation baked into the
+/// glTF node hierarchy will therefore cause incorrect deformation because the
+/// IBM accounts for the rotation but SL does not re-apply it.
+///
+/// Bones are processed in topological (parent-before-child) order so that each
+/// child uses the already-corrected parent world position when computing its
+/// own local translation.
+///
+/// Returns the **pre-normalization** node world matrices so that the caller can
+/// correct mesh vertex positions for the bind-pose change.
+pub(super) fn normalize_sl_bone_rotations(
+    json: &mut Value,
+    humanoid_bone_nodes: &HashMap<String, usize>,
+) -> Vec<Matrix4<f32>> {
+    let sl_node_indices: HashSet<usize> = BONE_MAP
+        .iter()
+        .chain(BENTO_BONE_MAP.iter())
+        .filter_map(|(vrm_name, _)| humanoid_bone_nodes.get(*vrm_name).copied())
+        .collect();
+
+    let mut pose = SkeletonPose::from_json(json);
+    let node_worlds_snapshot = pose.worlds().to_vec();
+
+    let mut effective_world_t: Vec<Vector3<f32>> = node_worlds_snapshot
+        .iter()
+        .map(|m| Vector3::new(m[(0, 3)], m[(1, 3)], m[(2, 3)]))
+        .collect();
+
+    for &node_idx in pose.topo_order() {
+        if !sl_node_indices.contains(&node_idx) {
+            continue;
+        }
+
+        let snapshot_t = match node_worlds_snapshot.get(node_idx) {
+            Some(m) => Vector3::new(m[(0, 3)], m[(1, 3)], m[(2, 3)]),
+            None => continue,
+        };
+
+        let parent_effective_t = match pose.parent_map().get(&node_idx) {
+            Some(&parent_idx) => effective_world_t[parent_idx],
+            None => Vector3::zeros(),
+        };
+
+        let local_t = snapshot_t - parent_effective_t;
+        effective_world_t[node_idx] = parent_effective_t + local_t;
+
+        if let Some(obj) = json["nodes"][node_idx].as_object_mut() {
+            obj.remove("matrix");
+            obj.insert(
+                "translation".to_string(),
+                serde_json::json!([local_t.x, local_t.y, local_t.z]),
+            );
+            obj.insert(
                 "rotation".to_string(),
                 serde_json::json!([0.0, 0.0, 0.0, 1.0]),
             );
@@ -415,7 +1475,7 @@ pub(super) fn correct_mesh_vertices_for_bind_pose_change(
             if !(jnt_meta.component_type == 5121 || jnt_meta.component_type == 5123) {
                 continue;
             }
-            if wgt_meta.component_type != 5126 {
+            if !is_weight_component_type(wgt_meta.component_type) {
                 continue;
             }
 
@@ -536,7 +1596,24 @@ pub(super) fn correct_mesh_vertices_for_bind_pose_change(
     Ok(())
 }
 
-/// Compute the weighted correction matrix for a single vertex.
+/// Compute the weighted correction matrix for a single vertex, blending
+/// every joint influence's `(new_world · old_world⁻¹)` correction by its
+/// skin weight. Applying the returned matrix to the vertex is exactly the
+/// per-influence linear-blend-skinning compensation
+/// `Σ wᵢ · (W′ᵢ · W⁻¹ᵢ) · old_pos` mentioned on
+/// [`correct_mesh_vertices_for_bind_pose_change`] — matrix-vector
+/// multiplication distributes over the weighted sum, so blending the
+/// correction matrices first and applying once is equivalent to
+/// transforming the vertex through each influence separately and blending
+/// the results, just without the redundant per-influence matrix-vector
+/// products.
+///
+/// Weights are renormalized defensively by `total_weight` rather than
+/// assumed to already sum to 1, so a vertex whose weights were left slightly
+/// under- or over-normalized upstream still blends correctly instead of
+/// scaling the vertex toward/away from the origin. A vertex with no
+/// effective weight at all (or a single, zero-correction influence) falls
+/// back to — or lands bit-exactly on — the identity correction.
 fn blend_correction_matrix(
     bin: &[u8],
     jnt_meta: &super::gltf_utils::AccessorMeta,
@@ -563,12 +1640,13 @@ fn blend_correction_matrix(
         total_weight += weight;
     }
 
-    // Fallback to identity if no effective weight.
+    // Fallback to identity if no effective weight; otherwise renormalize so
+    // weights that don't already sum to exactly 1 don't scale the vertex.
     if total_weight < 1e-7 {
         return Matrix4::identity();
     }
 
-    result
+    result / total_weight
 }
 
 /// Primitive attribute info for bind-pose correction.
@@ -681,8 +1759,9 @@ fn collect_primitives_for_skin_with_attributes(
 pub(super) fn promote_pelvis_to_scene_root(
     json: &mut Value,
     humanoid_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
 ) -> Option<usize> {
-    let Some(pelvis_index) = humanoid_bone_nodes.get("hips").copied() else {
+    let Some(pelvis_index) = humanoid_bone_nodes.get(profile.root_source()).copied() else {
         return None;
     };
 
@@ -729,7 +1808,10 @@ pub(super) fn promote_pelvis_to_scene_root(
         pelvis_world[(2, 3)],
     );
 
-    // The topmost ancestor becomes the identity skeleton root.
+    // The topmost ancestor becomes the identity skeleton root. Resolve which
+    // scene currently owns this hierarchy before any of the steps below
+    // sever the parent links a scene-root search would otherwise follow.
+    let owning_scene_index = scene_containing_node(json, pelvis_index);
     let identity_root_index = *ancestors.last().unwrap();
     // All ancestors except the topmost are "intermediates" to collapse.
     let intermediates: HashSet<usize> = ancestors
@@ -816,11 +1898,15 @@ pub(super) fn promote_pelvis_to_scene_root(
 
     // ── 6. Update the scene root list. ───────────────────────────────────
     //    Remove intermediates from the scene; ensure identity root is present;
-    //    promote orphaned mesh nodes.
+    //    promote orphaned mesh nodes. Target whichever scene actually owns
+    //    this hierarchy (resolved above, before mPelvis moved under the
+    //    identity root) rather than unconditionally `scenes[0]`, since a
+    //    multi-scene document's humanoid rig isn't guaranteed to live in the
+    //    first one.
     if let Some(scene) = json
         .get_mut("scenes")
         .and_then(Value::as_array_mut)
-        .and_then(|s| s.first_mut())
+        .and_then(|s| s.get_mut(owning_scene_index))
     {
         if let Some(Value::Array(scene_nodes)) = scene.get_mut("nodes") {
             // Remove intermediates and the pelvis itself from the scene
@@ -869,13 +1955,19 @@ pub(super) fn promote_pelvis_to_scene_root(
 /// the normal parent-chain traversal.
 ///
 /// When no identity root is available (i.e. `promote_pelvis_to_scene_root`
-/// found no wrapper ancestors) we fall back to mPelvis itself.
+/// found no wrapper ancestors), or a skin's joints aren't actually
+/// descendants of it (a VRM re-export can carry multiple skins over
+/// different wrapper roots even though they share one humanoid skeleton),
+/// that skin falls back to mPelvis — and if mPelvis isn't an ancestor of its
+/// joints either, to its own first joint.
 pub(super) fn set_skin_skeleton_root(
     json: &mut Value,
     humanoid_bone_nodes: &HashMap<String, usize>,
     identity_root: Option<usize>,
+    profile: &SkeletonProfile,
 ) {
-    let skeleton_index = identity_root.or_else(|| humanoid_bone_nodes.get("hips").copied());
+    let pelvis_index = humanoid_bone_nodes.get(profile.root_source()).copied();
+    let parent_map = collect_parent_index_map_from_json(json);
 
     let skins = match json["skins"].as_array_mut() {
         Some(s) => s,
@@ -883,12 +1975,6 @@ pub(super) fn set_skin_skeleton_root(
     };
 
     for skin in skins.iter_mut() {
-        if let Some(idx) = skeleton_index {
-            skin["skeleton"] = Value::Number(idx.into());
-            continue;
-        }
-
-        // Fallback (no hips bone found): use the first joint as skeleton root.
         let joints: Vec<usize> = skin["joints"]
             .as_array()
             .map(|arr| {
@@ -897,117 +1983,1333 @@ pub(super) fn set_skin_skeleton_root(
                     .collect()
             })
             .unwrap_or_default();
+        let Some(&first_joint) = joints.first() else {
+            continue;
+        };
+
+        let skeleton_index = [identity_root, pelvis_index]
+            .into_iter()
+            .flatten()
+            .find(|&root| node_is_ancestor_of(&parent_map, root, first_joint));
 
-        if let Some(&first) = joints.first() {
-            skin["skeleton"] = Value::Number(first.into());
+        if let Some(idx) = skeleton_index {
+            skin["skeleton"] = Value::Number(idx.into());
+        } else {
+            // Neither root candidate is an ancestor of this skin's joints
+            // (no hips bone found, or this skin lives under an unrelated
+            // wrapper root): fall back to its own first joint.
+            skin["skeleton"] = Value::Number(first_joint.into());
         }
     }
 }
 
-// ─── Inverse bind matrix regeneration ────────────────────────────────────────
-
-/// Rebuild inverse bind matrices from current node transforms and write them
-/// back to the binary buffer for all skins that have writable MAT4 float accessors.
-pub(super) fn regenerate_inverse_bind_matrices(json: &mut Value, bin: &mut [u8]) -> Result<()> {
-    if bin.is_empty() {
-        return Ok(());
+/// Whether `ancestor_index` is `node_index` itself or one of its ancestors,
+/// walking up `parent_map`.
+fn node_is_ancestor_of(
+    parent_map: &HashMap<usize, usize>,
+    ancestor_index: usize,
+    node_index: usize,
+) -> bool {
+    let mut current = node_index;
+    loop {
+        if current == ancestor_index {
+            return true;
+        }
+        match parent_map.get(&current) {
+            Some(&parent) => current = parent,
+            None => return false,
+        }
     }
+}
 
-    let Some(nodes_json) = json.get("nodes").and_then(Value::as_array) else {
-        return Ok(());
-    };
-
-    let node_locals: Vec<Matrix4<f32>> = nodes_json.iter().map(node_to_local_matrix).collect();
-    let parent_map = collect_parent_index_map_from_json(json);
-    let node_worlds = compute_node_world_matrices(&node_locals, &parent_map);
+// ─── Canonical rest skeleton snapping ─────────────────────────────────────────
 
-    let Some(skins) = json.get("skins").and_then(Value::as_array) else {
-        return Ok(());
-    };
+/// Snap each SL-mapped bone's translation toward [`CANONICAL_SL_REST_POSITIONS`]
+/// (scaled to the avatar's estimated height), correcting joint positions that
+/// [`reconstruct_sl_core_hierarchy`] and [`promote_pelvis_to_scene_root`]
+/// leave untouched after re-parenting. `blend_factor` of `1.0` snaps fully
+/// onto the canonical offset; `0.0` is a no-op, left to the caller for
+/// `ConvertOptions::preserve_custom_proportions`, where
+/// [`apply_sl_joint_position_overrides`] already records the avatar's own
+/// proportions for viewers that honor per-joint overrides.
+///
+/// Must run before [`regenerate_inverse_bind_matrices`]: that call derives
+/// each joint's inverse bind matrix from the *current* node world matrices,
+/// so inverse bind matrices only reflect this snap if it already happened.
+pub(super) fn snap_bones_to_canonical_sl_rest_skeleton(
+    json: &mut Value,
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    estimated_height_cm: f32,
+    blend_factor: f32,
+) -> Vec<ValidationIssue> {
+    if blend_factor <= 0.0 || estimated_height_cm <= 0.0 {
+        return Vec::new();
+    }
+    let height_ratio = estimated_height_cm / CANONICAL_REFERENCE_HEIGHT_CM;
 
-    let accessors = json
-        .get("accessors")
-        .and_then(Value::as_array)
-        .cloned()
-        .unwrap_or_default();
-    let buffer_views = json
-        .get("bufferViews")
-        .and_then(Value::as_array)
-        .cloned()
-        .unwrap_or_default();
+    let mut pose = SkeletonPose::from_json(json);
+    let parent_of = pose.parent_map().clone();
+    let sl_node_indices: HashSet<usize> = BONE_MAP
+        .iter()
+        .filter_map(|(source, _)| humanoid_bone_nodes.get(*source).copied())
+        .collect();
 
-    for skin in skins {
-        let Some(joints) = skin.get("joints").and_then(Value::as_array) else {
+    let mut snapped_count = 0usize;
+    for (source, target) in BONE_MAP.iter() {
+        let Some(node_index) = humanoid_bone_nodes.get(*source).copied() else {
             continue;
         };
-
-        let Some(accessor_index) = skin
-            .get("inverseBindMatrices")
-            .and_then(Value::as_u64)
-            .map(|value| value as usize)
+        let Some((_, canonical_position, _)) = CANONICAL_SL_REST_POSITIONS
+            .iter()
+            .find(|(name, _, _)| name == target)
         else {
             continue;
         };
+        let canonical_world_position = Vector3::new(
+            canonical_position[0],
+            canonical_position[1],
+            canonical_position[2],
+        ) * height_ratio;
+
+        let parent_world_inverse = nearest_mapped_ancestor(node_index, &parent_of, &sl_node_indices)
+            .and_then(|parent_index| pose.world(parent_index))
+            .and_then(|parent_world| parent_world.try_inverse());
+
+        let target_local_translation = match parent_world_inverse {
+            Some(parent_world_inverse) => {
+                let target_world = Translation3::from(canonical_world_position).to_homogeneous();
+                let local = parent_world_inverse * target_world;
+                Vector3::new(local[(0, 3)], local[(1, 3)], local[(2, 3)])
+            }
+            // Root of the mapped skeleton (mPelvis): no SL-named parent to
+            // express a relative offset against, so the canonical world
+            // position doubles as the target local translation.
+            None => canonical_world_position,
+        };
 
-        let Some(accessor) = accessors.get(accessor_index) else {
+        let Some(current_local) = pose.local(node_index) else {
             continue;
         };
+        let (current_translation, rotation, scale) = decompose_trs(&current_local);
+        let blended_translation =
+            current_translation + blend_factor * (target_local_translation - current_translation);
+        pose.set_local(node_index, compose_trs(blended_translation, rotation, scale));
+        snapped_count += 1;
+    }
 
-        let component_type = accessor
-            .get("componentType")
-            .and_then(Value::as_u64)
-            .unwrap_or_default();
-        let accessor_type = accessor
-            .get("type")
+    if snapped_count == 0 {
+        return Vec::new();
+    }
+    pose.flush(json);
+
+    vec![ValidationIssue {
+        severity: super::types::Severity::Info,
+        code: "BONES_SNAPPED_TO_CANONICAL_REST".to_string(),
+        message: format!(
+            "ℹ️ Snapped {} bone(s) toward Second Life's canonical Bento rest skeleton ({:.0}% blend)",
+            snapped_count,
+            blend_factor * 100.0
+        ),
+    }]
+}
+
+// ─── Inverse bind matrix regeneration ────────────────────────────────────────
+
+/// Where in the binary buffer a skin's existing `inverseBindMatrices`
+/// accessor stores each joint's matrix, once it's confirmed to be a
+/// writable MAT4/float accessor with a resolvable buffer view. `None` means
+/// the skin has no usable IBM accessor — either it's absent, or it's the
+/// wrong type/missing its buffer view — and one must be synthesized instead.
+fn writable_ibm_location(
+    skin: &Value,
+    accessors: &[Value],
+    buffer_views: &[Value],
+) -> Option<(usize, usize)> {
+    let accessor_index = skin
+        .get("inverseBindMatrices")
+        .and_then(Value::as_u64)
+        .map(|value| value as usize)?;
+    let accessor = accessors.get(accessor_index)?;
+
+    let component_type = accessor
+        .get("componentType")
+        .and_then(Value::as_u64)
+        .unwrap_or_default();
+    let accessor_type = accessor
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    if component_type != 5126 || accessor_type != "MAT4" {
+        return None;
+    }
+
+    let buffer_view_index = accessor
+        .get("bufferView")
+        .and_then(Value::as_u64)
+        .map(|value| value as usize)?;
+    let buffer_view = buffer_views.get(buffer_view_index)?;
+
+    let view_offset = buffer_view
+        .get("byteOffset")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as usize;
+    let accessor_offset = accessor
+        .get("byteOffset")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as usize;
+    let base_offset = view_offset.saturating_add(accessor_offset);
+    let stride = buffer_view
+        .get("byteStride")
+        .and_then(Value::as_u64)
+        .map(|value| value as usize)
+        .unwrap_or(64);
+
+    Some((base_offset, stride))
+}
+
+/// Allocate a fresh `inverseBindMatrices` accessor for a skin that has none,
+/// packing `matrix_bytes` (already `joint_count * 64` bytes of column-major
+/// float matrices) as a new 4-byte-aligned `bufferView`/`accessor` pair in
+/// buffer 0, and return the new accessor's index.
+fn append_inverse_bind_accessor(json: &mut Value, bin: &mut Vec<u8>, matrix_bytes: Vec<u8>, joint_count: usize) -> usize {
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+    let view_offset = bin.len();
+    let view_length = matrix_bytes.len();
+    bin.extend(matrix_bytes);
+
+    if let Some(buffer) = json["buffers"].get_mut(0) {
+        buffer["byteLength"] = Value::from(bin.len() as u64);
+    }
+
+    let buffer_views = json
+        .get_mut("bufferViews")
+        .and_then(Value::as_array_mut)
+        .expect("glTF document has a bufferViews array");
+    let view_index = buffer_views.len();
+    buffer_views.push(serde_json::json!({
+        "buffer": 0,
+        "byteOffset": view_offset,
+        "byteLength": view_length,
+    }));
+
+    let accessors = json
+        .get_mut("accessors")
+        .and_then(Value::as_array_mut)
+        .expect("glTF document has an accessors array");
+    let accessor_index = accessors.len();
+    accessors.push(serde_json::json!({
+        "bufferView": view_index,
+        "componentType": 5126,
+        "type": "MAT4",
+        "count": joint_count,
+    }));
+
+    accessor_index
+}
+
+/// World-space distance (in scene units) beyond which a joint's freshly
+/// recomputed bind-pose translation is considered to have drifted from its
+/// stored inverse bind matrix.
+const BIND_POSE_DRIFT_TRANSLATION_EPSILON: f32 = 1e-3;
+
+/// Angle (radians) beyond which a joint's freshly recomputed bind-pose
+/// rotation is considered to have drifted from its stored one.
+const BIND_POSE_DRIFT_ROTATION_EPSILON: f32 = 1e-3;
+
+/// Per-axis scale difference beyond which a joint's freshly recomputed
+/// bind-pose scale is considered to have drifted from its stored one.
+const BIND_POSE_DRIFT_SCALE_EPSILON: f32 = 1e-3;
+
+/// World-space distance below which two joints of the same skin are
+/// considered to share an identical bind position — usually a VRM rig
+/// authoring error (e.g. duplicate bones stacked at the same origin) rather
+/// than intentional overlap.
+const COINCIDENT_JOINT_POSITION_EPSILON: f32 = 1e-4;
+
+/// Compare every skin's stored `inverseBindMatrices` against the inverse of
+/// its joints' freshly recomputed world transforms, before
+/// [`regenerate_inverse_bind_matrices`] overwrites them — so a user can see
+/// what that rewrite is about to change instead of it happening silently.
+/// Flags, per joint: bind-pose drift beyond
+/// [`BIND_POSE_DRIFT_TRANSLATION_EPSILON`]/rotation/scale thresholds, a
+/// recomputed world matrix that turns out non-invertible (e.g.
+/// zero-scaled), and joints of the same skin sharing an identical bind
+/// position (a common VRM rig error Second Life can't distinguish).
+///
+/// Skins without a usable stored accessor are skipped — there's nothing to
+/// compare against; [`regenerate_inverse_bind_matrices`] handles synthesizing
+/// one for those.
+pub(super) fn diagnose_bind_pose_drift(json: &Value, bin: &[u8]) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if bin.is_empty() || json.get("nodes").and_then(Value::as_array).is_none() {
+        return issues;
+    }
+
+    let node_name = |index: usize| -> String {
+        json.pointer(&format!("/nodes/{index}/name"))
             .and_then(Value::as_str)
+            .map(ToOwned::to_owned)
+            .unwrap_or_else(|| format!("node {index}"))
+    };
+
+    let mut pose = SkeletonPose::from_json(json);
+    let skins = json.get("skins").and_then(Value::as_array).cloned().unwrap_or_default();
+
+    for (skin_index, skin) in skins.iter().enumerate() {
+        let joint_indices: Vec<usize> = skin
+            .get("joints")
+            .and_then(Value::as_array)
+            .map(|joints| {
+                joints
+                    .iter()
+                    .filter_map(|joint| joint.as_u64().map(|value| value as usize))
+                    .collect()
+            })
             .unwrap_or_default();
-        if component_type != 5126 || accessor_type != "MAT4" {
+        if joint_indices.is_empty() {
             continue;
         }
 
-        let Some(buffer_view_index) = accessor
-            .get("bufferView")
+        let Some(ibm_accessor_index) = skin
+            .get("inverseBindMatrices")
             .and_then(Value::as_u64)
             .map(|value| value as usize)
         else {
             continue;
         };
-
-        let Some(buffer_view) = buffer_views.get(buffer_view_index) else {
+        let Some(ibm_meta) = accessor_meta(json, ibm_accessor_index) else {
             continue;
         };
+        if ibm_meta.accessor_type != "MAT4" || ibm_meta.component_type != 5126 {
+            continue;
+        }
 
-        let view_offset = buffer_view
-            .get("byteOffset")
-            .and_then(Value::as_u64)
-            .unwrap_or(0) as usize;
-        let accessor_offset = accessor
-            .get("byteOffset")
-            .and_then(Value::as_u64)
-            .unwrap_or(0) as usize;
-        let base_offset = view_offset.saturating_add(accessor_offset);
-        let stride = buffer_view
-            .get("byteStride")
-            .and_then(Value::as_u64)
-            .map(|value| value as usize)
-            .unwrap_or(64);
+        let mut world_positions: Vec<(usize, Vector3<f32>)> = Vec::with_capacity(joint_indices.len());
+
+        for (joint_array_index, &joint_index) in joint_indices.iter().enumerate() {
+            let Some(world) = pose.world(joint_index) else {
+                continue;
+            };
+            let world_position = local_to_world(&world, &Vector3::zeros());
+            world_positions.push((joint_index, world_position));
 
-        for (joint_array_index, joint) in joints.iter().enumerate() {
-            let Some(joint_index) = joint.as_u64().map(|value| value as usize) else {
+            let Some(stored_inverse) = read_mat4_from_accessor(bin, &ibm_meta, joint_array_index)
+            else {
                 continue;
             };
-            let Some(world) = node_worlds.get(joint_index) else {
+
+            let Some(recomputed_inverse) = world.try_inverse() else {
+                issues.push(ValidationIssue {
+                    severity: super::types::Severity::Warning,
+                    code: "BIND_POSE_DEGENERATE_WORLD_MATRIX".to_string(),
+                    message: format!(
+                        "⚠️ Joint '{}' (skin {}) has a non-invertible world transform (e.g. zero scale); its regenerated bind matrix will fall back to a pseudo-inverse",
+                        node_name(joint_index), skin_index
+                    ),
+                });
                 continue;
             };
 
-            let inverse = world.try_inverse().unwrap_or_else(Matrix4::<f32>::identity);
-            let write_offset = base_offset.saturating_add(joint_array_index.saturating_mul(stride));
-            if write_offset + 64 > bin.len() {
+            let max_element_delta = stored_inverse
+                .iter()
+                .zip(recomputed_inverse.iter())
+                .fold(0.0f32, |max, (&stored, &recomputed)| {
+                    max.max((stored - recomputed).abs())
+                });
+            if max_element_delta < 1e-5 {
                 continue;
             }
-            write_mat4_f32_le(bin, write_offset, &inverse);
-        }
-    }
 
-    Ok(())
+            let (stored_translation, stored_rotation, stored_scale) = decompose_trs(&stored_inverse);
+            let (recomputed_translation, recomputed_rotation, recomputed_scale) =
+                decompose_trs(&recomputed_inverse);
+
+            let translation_delta = (stored_translation - recomputed_translation).norm();
+            let rotation_delta_radians =
+                (stored_rotation.inverse() * recomputed_rotation).angle();
+            let scale_delta = (stored_scale - recomputed_scale).amax();
+
+            if translation_delta > BIND_POSE_DRIFT_TRANSLATION_EPSILON
+                || rotation_delta_radians > BIND_POSE_DRIFT_ROTATION_EPSILON
+                || scale_delta > BIND_POSE_DRIFT_SCALE_EPSILON
+            {
+                issues.push(ValidationIssue {
+                    severity: super::types::Severity::Info,
+                    code: "BIND_POSE_DRIFTED".to_string(),
+                    message: format!(
+                        "[INFO] Joint '{}' (skin {}) bind pose drifted from its stored inverse bind matrix (max element delta {:.4}; translation {:.4}, rotation {:.4} rad, scale {:.4}); will be regenerated",
+                        node_name(joint_index), skin_index, max_element_delta, translation_delta, rotation_delta_radians, scale_delta
+                    ),
+                });
+            }
+        }
+
+        for i in 0..world_positions.len() {
+            for j in (i + 1)..world_positions.len() {
+                let (joint_a, position_a) = world_positions[i];
+                let (joint_b, position_b) = world_positions[j];
+                if (position_a - position_b).norm() < COINCIDENT_JOINT_POSITION_EPSILON {
+                    issues.push(ValidationIssue {
+                        severity: super::types::Severity::Warning,
+                        code: "BIND_POSE_COINCIDENT_JOINTS".to_string(),
+                        message: format!(
+                            "⚠️ Joints '{}' and '{}' (skin {}) share an identical bind position; Second Life may not be able to distinguish them",
+                            node_name(joint_a), node_name(joint_b), skin_index
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+/// Rebuild inverse bind matrices from current node transforms and write them
+/// back to the binary buffer for every skin. Skins with an existing writable
+/// MAT4 float accessor have their matrices overwritten in place; skins that
+/// legally omit `inverseBindMatrices` (or whose accessor isn't usable) have
+/// one synthesized via [`append_inverse_bind_accessor`], since Second Life
+/// — unlike most glTF importers — doesn't assume identity bind matrices for
+/// an omitted accessor.
+///
+/// A joint whose world matrix turns out non-invertible (e.g. a zero-scaled
+/// ancestor) falls back to an SVD pseudo-inverse via
+/// [`pseudo_inverse_bind_matrix`], which at least preserves skinning along
+/// the directions the collapsed matrix didn't zero out, and is reported as a
+/// [`ValidationIssue`] since that bone's bind pose still can't be fully
+/// trusted.
+pub(super) fn regenerate_inverse_bind_matrices(
+    json: &mut Value,
+    bin: &mut Vec<u8>,
+) -> Result<Vec<ValidationIssue>> {
+    if bin.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    if json.get("nodes").and_then(Value::as_array).is_none() {
+        return Ok(Vec::new());
+    }
+    let mut pose = SkeletonPose::from_json(json);
+
+    let skins = json
+        .get("skins")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    if skins.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let accessors = json
+        .get("accessors")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let buffer_views = json
+        .get("bufferViews")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut issues = Vec::new();
+
+    for (skin_index, skin) in skins.iter().enumerate() {
+        let Some(joints) = skin.get("joints").and_then(Value::as_array) else {
+            continue;
+        };
+        let joint_indices: Vec<usize> = joints
+            .iter()
+            .filter_map(|joint| joint.as_u64().map(|value| value as usize))
+            .collect();
+        if joint_indices.is_empty() {
+            continue;
+        }
+
+        let mut non_invertible_joint_count = 0usize;
+
+        match writable_ibm_location(skin, &accessors, &buffer_views) {
+            Some((base_offset, stride)) => {
+                for (joint_array_index, &joint_index) in joint_indices.iter().enumerate() {
+                    let Some(world) = pose.world(joint_index) else {
+                        continue;
+                    };
+                    let inverse = match world.try_inverse() {
+                        Some(inverse) => inverse,
+                        None => {
+                            non_invertible_joint_count += 1;
+                            pseudo_inverse_bind_matrix(
+                                world,
+                                CorrectionOptions::default().singular_tolerance,
+                            )
+                        }
+                    };
+
+                    let write_offset =
+                        base_offset.saturating_add(joint_array_index.saturating_mul(stride));
+                    if write_offset + 64 > bin.len() {
+                        continue;
+                    }
+                    write_mat4_f32_le(bin, write_offset, &inverse);
+                }
+            }
+            None => {
+                let mut matrix_bytes = Vec::<u8>::with_capacity(joint_indices.len() * 64);
+                for &joint_index in &joint_indices {
+                    let inverse = match pose.world(joint_index) {
+                        Some(world) => match world.try_inverse() {
+                            Some(inverse) => inverse,
+                            None => {
+                                non_invertible_joint_count += 1;
+                                pseudo_inverse_bind_matrix(
+                                    world,
+                                    CorrectionOptions::default().singular_tolerance,
+                                )
+                            }
+                        },
+                        None => Matrix4::identity(),
+                    };
+                    let mut bytes = [0u8; 64];
+                    write_mat4_f32_le(&mut bytes, 0, &inverse);
+                    matrix_bytes.extend_from_slice(&bytes);
+                }
+
+                let new_accessor_index =
+                    append_inverse_bind_accessor(json, bin, matrix_bytes, joint_indices.len());
+                json["skins"][skin_index]["inverseBindMatrices"] =
+                    Value::from(new_accessor_index as u64);
+
+                issues.push(ValidationIssue {
+                    severity: super::types::Severity::Info,
+                    code: "INVERSE_BIND_MATRICES_SYNTHESIZED".to_string(),
+                    message: format!(
+                        "[INFO] Skin {} had no inverse bind matrices; synthesized them from the current bind pose",
+                        skin_index
+                    ),
+                });
+            }
+        }
+
+        if non_invertible_joint_count > 0 {
+            issues.push(ValidationIssue {
+                severity: super::types::Severity::Warning,
+                code: "NON_INVERTIBLE_BIND_MATRIX".to_string(),
+                message: format!(
+                    "⚠️ Skin {} has {} joint(s) whose world matrix could not be inverted; their bind matrices fell back to a pseudo-inverse",
+                    skin_index, non_invertible_joint_count
+                ),
+            });
+        }
+    }
+
+    Ok(issues)
+}
+
+// ─── SL joint-position overrides ──────────────────────────────────────────────
+
+/// glTF skin extension name under which per-joint SL bind-position overrides
+/// are recorded when [`super::types::ConvertOptions::preserve_custom_proportions`]
+/// is enabled.
+const JOINT_POSITION_OVERRIDE_EXTENSION: &str = "SL_joint_position_override";
+
+/// Walk up `parent_of` from `start` until an SL-mapped node is reached,
+/// returning its index. Mirrors the VRM-name fallback/refinement chains in
+/// [`CORE_HIERARCHY_RELATIONS`]/[`BENTO_HIERARCHY_RELATIONS`] without having
+/// to re-derive them: by the time overrides are recorded, the node tree
+/// itself already reflects whatever chain `reconstruct_sl_core_hierarchy`
+/// picked (e.g. skipping an absent `leftShoulder` straight to `chest`).
+pub(super) fn nearest_mapped_ancestor(
+    start: usize,
+    parent_of: &HashMap<usize, usize>,
+    sl_node_indices: &HashSet<usize>,
+) -> Option<usize> {
+    let mut cur = parent_of.get(&start).copied();
+    while let Some(p) = cur {
+        if sl_node_indices.contains(&p) {
+            return Some(p);
+        }
+        cur = parent_of.get(&p).copied();
+    }
+    None
+}
+
+/// Record each SL-mapped bone's bind-pose translation relative to its
+/// SL-named parent as a per-joint position override on every skin — the same
+/// `pos` offset Second Life's own avatar skeleton definition expresses each
+/// joint's rest position in, relative to its parent rather than the world.
+/// This lets the SL viewer deform the mesh toward the avatar's authored
+/// proportions instead of snapping it toward SL's default rest skeleton.
+///
+/// Must run after [`regenerate_inverse_bind_matrices`], once bone world
+/// positions reflect the final (scaled, rotation-normalized) bind pose —
+/// recording overrides any earlier would capture the wrong offsets.
+pub(super) fn apply_sl_joint_position_overrides(
+    json: &mut Value,
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
+) {
+    let sl_node_indices: HashSet<usize> = profile
+        .bone_pairs()
+        .filter_map(|(source, _)| humanoid_bone_nodes.get(source).copied())
+        .collect();
+
+    let mut pose = SkeletonPose::from_json(json);
+    let parent_of = pose.parent_map().clone();
+
+    let mut joint_names = Vec::new();
+    let mut joint_positions = Vec::new();
+    for (source, target) in profile.bone_pairs() {
+        let Some(node_index) = humanoid_bone_nodes.get(source).copied() else {
+            continue;
+        };
+        let Some(world) = pose.world(node_index) else {
+            continue;
+        };
+
+        let local_translation = match nearest_mapped_ancestor(node_index, &parent_of, &sl_node_indices)
+        {
+            Some(parent_index) => match pose
+                .world(parent_index)
+                .and_then(|parent_world| parent_world.try_inverse())
+            {
+                Some(parent_world_inverse) => parent_world_inverse * world,
+                None => world,
+            },
+            // Root of the mapped skeleton (mPelvis): no SL-named parent to
+            // express a relative offset against, so fall back to its world
+            // translation, same as the override for any other unresolvable case.
+            None => world,
+        };
+
+        joint_names.push(target.to_string());
+        joint_positions.push(serde_json::json!([
+            local_translation[(0, 3)],
+            local_translation[(1, 3)],
+            local_translation[(2, 3)]
+        ]));
+    }
+
+    if joint_names.is_empty() {
+        return;
+    }
+
+    let Some(skins) = json.get_mut("skins").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for skin in skins {
+        let Some(skin_object) = skin.as_object_mut() else {
+            continue;
+        };
+        let extensions = skin_object
+            .entry("extensions")
+            .or_insert_with(|| Value::Object(serde_json::Map::new()));
+        if let Some(extensions_object) = extensions.as_object_mut() {
+            extensions_object.insert(
+                JOINT_POSITION_OVERRIDE_EXTENSION.to_string(),
+                serde_json::json!({
+                    "jointNames": joint_names,
+                    "jointPositions": joint_positions,
+                }),
+            );
+        }
+    }
+
+    match json.get_mut("extensionsUsed").and_then(Value::as_array_mut) {
+        Some(extensions_used) => {
+            let extension_value = Value::String(JOINT_POSITION_OVERRIDE_EXTENSION.to_string());
+            if !extensions_used.contains(&extension_value) {
+                extensions_used.push(extension_value);
+            }
+        }
+        None => {
+            if let Some(root) = json.as_object_mut() {
+                root.insert(
+                    "extensionsUsed".to_string(),
+                    serde_json::json!([JOINT_POSITION_OVERRIDE_EXTENSION]),
+                );
+            }
+        }
+    }
+}
+
+// ─── Fitted-mesh collision volumes ────────────────────────────────────────────
+
+/// Synthesize SL "fitted mesh" collision-volume bones (belly, pecs, love
+/// handles, butt, upper/lower limb volumes) as new child nodes of their host
+/// `mBone`, append them to every skin's joint palette with fresh inverse bind
+/// matrices, and blend a fraction of the host bone's vertex weight onto each
+/// co-located volume so fitted-mesh clothing actually deforms with it. Limb
+/// volumes are additionally scaled from their host→far bone length (see
+/// [`LIMB_COLLISION_VOLUME_LENGTH_BONES`]) so volume size tracks avatar
+/// proportions instead of being fixed.
+///
+/// Must run after [`regenerate_inverse_bind_matrices`] and
+/// [`apply_sl_joint_position_overrides`], once the standard SL skeleton is in
+/// its final (scaled, renamed) bind pose — the volumes are positioned from
+/// the host bone's final world transform, and weight-blended vertices read
+/// the host bone's final joint slot.
+pub(super) fn synthesize_fitted_mesh_collision_volumes(
+    json: &mut Value,
+    bin: &mut Vec<u8>,
+) -> Result<Vec<ValidationIssue>> {
+    let mut issues = Vec::new();
+
+    let skin_count = json
+        .get("skins")
+        .and_then(Value::as_array)
+        .map(|skins| skins.len())
+        .unwrap_or(0);
+
+    for skin_index in 0..skin_count {
+        issues.extend(add_collision_volumes_to_skin(json, bin, skin_index)?);
+    }
+
+    Ok(issues)
+}
+
+/// Fraction of a limb's host→far-bone length used as the isotropic `scale`
+/// of its collision volume, roughly matching a capsule body's radius for a
+/// typical limb cross-section.
+const LIMB_COLLISION_VOLUME_SCALE_FACTOR: f32 = 0.5;
+
+fn add_collision_volumes_to_skin(
+    json: &mut Value,
+    bin: &mut Vec<u8>,
+    skin_index: usize,
+) -> Result<Vec<ValidationIssue>> {
+    let mut issues = Vec::new();
+
+    let joints_before: Vec<usize> = json["skins"][skin_index]["joints"]
+        .as_array()
+        .map(|joints| {
+            joints
+                .iter()
+                .filter_map(|value| value.as_u64().map(|v| v as usize))
+                .collect()
+        })
+        .unwrap_or_default();
+    if joints_before.is_empty() {
+        return Ok(issues);
+    }
+
+    // `blend_sources`: for each new volume appended below, the joint-array
+    // index (within this skin, before any volumes were added) of the host
+    // bone whose weight it should partially take over, and the fraction to
+    // take.
+    let mut blend_sources: Vec<(usize, usize, f32)> = Vec::new();
+    // Node indices of the volumes themselves, so their inverse bind matrices
+    // can be computed without inheriting a host ancestor's non-uniform scale
+    // (see `SkeletonPose::set_scale_isolated_nodes`).
+    let mut volume_node_indices: HashSet<usize> = HashSet::new();
+
+    let locals: Vec<Matrix4<f32>> = json["nodes"]
+        .as_array()
+        .map(|nodes| nodes.iter().map(node_to_local_matrix).collect())
+        .unwrap_or_default();
+    let parent_map = collect_parent_index_map_from_json(json);
+    let worlds = compute_node_world_matrices(&locals, &parent_map);
+
+    for &(volume_name, host_bone_name, local_offset, blend_fraction) in
+        FITTED_MESH_COLLISION_VOLUMES.iter()
+    {
+        let Some(host_node_index) = node_index_by_name(json, host_bone_name) else {
+            issues.push(ValidationIssue {
+                severity: super::types::Severity::Warning,
+                code: "FITTED_MESH_HOST_BONE_MISSING".to_string(),
+                message: format!(
+                    "⚠️ Host bone {} for fitted-mesh collision volume {} is absent; volume not generated",
+                    host_bone_name, volume_name
+                ),
+            });
+            continue;
+        };
+        let Some(host_joint_array_index) = joints_before
+            .iter()
+            .position(|&joint_node_index| joint_node_index == host_node_index)
+        else {
+            issues.push(ValidationIssue {
+                severity: super::types::Severity::Warning,
+                code: "FITTED_MESH_HOST_BONE_MISSING".to_string(),
+                message: format!(
+                    "⚠️ Host bone {} for fitted-mesh collision volume {} is not a joint of skin {}; volume not generated",
+                    host_bone_name, volume_name, skin_index
+                ),
+            });
+            continue;
+        };
+
+        // Limb volumes also get a `scale` sized from the actual host→far
+        // bone length, so e.g. a long-armed avatar's upper-arm volume is
+        // bigger than a short-armed one's instead of a fixed size for every
+        // avatar.
+        let limb_scale = LIMB_COLLISION_VOLUME_LENGTH_BONES
+            .iter()
+            .find(|&&(name, _)| name == volume_name)
+            .and_then(|&(_, far_bone_name)| node_index_by_name(json, far_bone_name))
+            .and_then(|far_node_index| {
+                let host_world = worlds.get(host_node_index)?;
+                let far_world = worlds.get(far_node_index)?;
+                let host_pos =
+                    Vector3::new(host_world[(0, 3)], host_world[(1, 3)], host_world[(2, 3)]);
+                let far_pos = Vector3::new(far_world[(0, 3)], far_world[(1, 3)], far_world[(2, 3)]);
+                let bone_length = (far_pos - host_pos).norm();
+                (bone_length > 1e-6).then_some(bone_length * LIMB_COLLISION_VOLUME_SCALE_FACTOR)
+            });
+
+        let new_node_index = json["nodes"].as_array().map(|nodes| nodes.len()).unwrap_or(0);
+        let mut new_node = serde_json::json!({
+            "name": volume_name,
+            "translation": [local_offset[0], local_offset[1], local_offset[2]],
+        });
+        if let Some(scale) = limb_scale {
+            new_node["scale"] = serde_json::json!([scale, scale, scale]);
+        }
+        if let Some(nodes) = json.get_mut("nodes").and_then(Value::as_array_mut) {
+            nodes.push(new_node);
+        } else {
+            continue;
+        }
+
+        if let Some(host_node) = json["nodes"].get_mut(host_node_index).and_then(Value::as_object_mut)
+        {
+            let children = host_node
+                .entry("children")
+                .or_insert_with(|| Value::Array(Vec::new()));
+            if let Some(children) = children.as_array_mut() {
+                children.push(Value::from(new_node_index as u64));
+            }
+        }
+
+        if let Some(joints) = json["skins"][skin_index]["joints"].as_array_mut() {
+            joints.push(Value::from(new_node_index as u64));
+            blend_sources.push((joints.len() - 1, host_joint_array_index, blend_fraction));
+            volume_node_indices.insert(new_node_index);
+        }
+    }
+
+    if blend_sources.is_empty() {
+        return Ok(issues);
+    }
+
+    grow_inverse_bind_matrices(json, bin, skin_index, &volume_node_indices)?;
+    blend_collision_volume_weights(json, bin, skin_index, &blend_sources);
+
+    Ok(issues)
+}
+
+/// Recompute inverse bind matrices for every joint of `skin_index` into a
+/// freshly-appended buffer region, and point the skin's inverse bind matrix
+/// accessor at it. Used after joints were appended to the skin, since the new
+/// total no longer fits the accessor's existing (now too-small) buffer
+/// region.
+///
+/// Joints in `scale_isolated_joints` (e.g. newly added fitted-mesh collision
+/// volumes) have their world matrix computed without inheriting an ancestor's
+/// non-uniform scale, so their bind size stays stable regardless of what the
+/// host bone's parent chain happens to be scaled to.
+fn grow_inverse_bind_matrices(
+    json: &mut Value,
+    bin: &mut Vec<u8>,
+    skin_index: usize,
+    scale_isolated_joints: &HashSet<usize>,
+) -> Result<()> {
+    let Some(accessor_index) = json["skins"][skin_index]
+        .get("inverseBindMatrices")
+        .and_then(Value::as_u64)
+        .map(|value| value as usize)
+    else {
+        return Ok(());
+    };
+    let Some(buffer_index) = json["accessors"][accessor_index]["bufferView"]
+        .as_u64()
+        .and_then(|view_index| json["bufferViews"][view_index as usize]["buffer"].as_u64())
+        .map(|value| value as usize)
+    else {
+        return Ok(());
+    };
+
+    let joints: Vec<usize> = json["skins"][skin_index]["joints"]
+        .as_array()
+        .map(|joints| {
+            joints
+                .iter()
+                .filter_map(|value| value.as_u64().map(|v| v as usize))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut pose = SkeletonPose::from_json(json);
+    if !scale_isolated_joints.is_empty() {
+        pose.set_scale_isolated_nodes(scale_isolated_joints.clone());
+    }
+    let mut matrix_bytes = Vec::<u8>::with_capacity(joints.len() * 64);
+    for &joint_node_index in &joints {
+        let inverse = pose
+            .inverse_bind(joint_node_index)
+            .unwrap_or_else(Matrix4::identity);
+        let mut bytes = [0u8; 64];
+        write_mat4_f32_le(&mut bytes, 0, &inverse);
+        matrix_bytes.extend_from_slice(&bytes);
+    }
+
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+    let new_view_offset = bin.len();
+    bin.extend_from_slice(&matrix_bytes);
+
+    let new_view_index = json
+        .get("bufferViews")
+        .and_then(Value::as_array)
+        .map(|views| views.len())
+        .unwrap_or(0);
+    if let Some(buffer_views) = json.get_mut("bufferViews").and_then(Value::as_array_mut) {
+        buffer_views.push(serde_json::json!({
+            "buffer": buffer_index,
+            "byteOffset": new_view_offset,
+            "byteLength": matrix_bytes.len(),
+        }));
+    }
+
+    if let Some(accessor) = json["accessors"].get_mut(accessor_index) {
+        accessor["bufferView"] = Value::from(new_view_index as u64);
+        accessor["byteOffset"] = Value::from(0u64);
+        accessor["count"] = Value::from(joints.len() as u64);
+    }
+
+    if let Some(buffer) = json["buffers"].get_mut(buffer_index) {
+        buffer["byteLength"] = Value::from(bin.len() as u64);
+    }
+
+    Ok(())
+}
+
+/// Blend a fraction of each host bone's vertex weight onto its co-located
+/// collision volume, so fitted-mesh clothing weighted to the volume follows
+/// the host bone's deformation. For a vertex weighted to a host bone, the
+/// blended amount replaces whichever of its other three influences is
+/// weakest, provided that influence is weaker than the blend amount — an
+/// already-dominant second influence is left alone rather than evicted.
+fn blend_collision_volume_weights(
+    json: &mut Value,
+    bin: &mut [u8],
+    skin_index: usize,
+    blend_sources: &[(usize, usize, f32)],
+) {
+    let bindings = collect_primitives_for_skin_with_attributes(json, skin_index);
+
+    for binding in &bindings {
+        let Some(joints_meta) = accessor_meta(json, binding.joints_accessor) else {
+            continue;
+        };
+        let Some(weights_meta) = accessor_meta(json, binding.weights_accessor) else {
+            continue;
+        };
+        if !is_weight_component_type(weights_meta.component_type) {
+            continue;
+        }
+
+        let count = joints_meta.count.min(weights_meta.count);
+        for vertex_index in 0..count {
+            let mut slots = [0u16; 4];
+            let mut weights = [0.0f32; 4];
+            for lane in 0..4 {
+                slots[lane] = read_joint_slot(bin, &joints_meta, vertex_index, lane).unwrap_or(0);
+                weights[lane] =
+                    read_weight_f32(bin, &weights_meta, vertex_index, lane).unwrap_or(0.0);
+            }
+
+            let mut changed = false;
+            for &(volume_joint_array_index, host_joint_array_index, blend_fraction) in
+                blend_sources
+            {
+                let Some(host_lane) = slots
+                    .iter()
+                    .position(|&slot| slot as usize == host_joint_array_index)
+                else {
+                    continue;
+                };
+
+                let blend_weight = weights[host_lane] * blend_fraction;
+                if blend_weight <= 1e-6 {
+                    continue;
+                }
+
+                let Some(evict_lane) = (0..4)
+                    .filter(|&lane| lane != host_lane)
+                    .min_by(|&a, &b| weights[a].partial_cmp(&weights[b]).unwrap_or(std::cmp::Ordering::Equal))
+                else {
+                    continue;
+                };
+                if weights[evict_lane] >= blend_weight {
+                    continue;
+                }
+
+                slots[evict_lane] = volume_joint_array_index as u16;
+                weights[evict_lane] = blend_weight;
+                weights[host_lane] -= blend_weight;
+                changed = true;
+            }
+
+            if !changed {
+                continue;
+            }
+
+            let sum: f32 = weights.iter().sum();
+            if sum > 1e-8 {
+                for weight in &mut weights {
+                    *weight /= sum;
+                }
+            }
+
+            for lane in 0..4 {
+                write_joint_slot(bin, &joints_meta, vertex_index, lane, slots[lane]);
+                write_weight_f32(bin, &weights_meta, vertex_index, lane, weights[lane]);
+            }
+        }
+    }
+}
+
+// ─── Missing intermediate bone repair ─────────────────────────────────────────
+
+/// Repair a required bone entirely missing from `humanoid_bone_nodes` (e.g. no
+/// `spine` node between `hips` and `chest`) by splicing in a synthesized
+/// zero-rotation pass-through node, so a partially-rigged VRM can still be
+/// converted instead of failing outright.
+///
+/// Only repairs the case [`synthesizable_intermediate_bone`] identifies: the
+/// bone's nearest required ancestor and descendant are both present, *and*
+/// the descendant currently hangs directly off the ancestor (i.e. the bone is
+/// truly absent from the node tree, not just unmapped). The synthesized
+/// node's position is interpolated halfway between the ancestor's and
+/// descendant's current world positions, and it's added to
+/// `humanoid_bone_nodes` under the missing bone's source name so every later
+/// pass (renaming, hierarchy reconstruction, weight rebaking, ...) treats it
+/// like any other mapped bone.
+///
+/// Must run before [`rename_bones`], while VRM humanoid source names are
+/// still how bones are identified.
+pub(super) fn synthesize_missing_intermediate_bones(
+    json: &mut Value,
+    humanoid_bone_nodes: &mut HashMap<String, usize>,
+    profile: &SkeletonProfile,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    for bone_source in profile.required_sources() {
+        if humanoid_bone_nodes.contains_key(bone_source) {
+            continue;
+        }
+        let Some((ancestor_source, descendant_source)) =
+            synthesizable_intermediate_bone(bone_source, humanoid_bone_nodes, profile)
+        else {
+            continue;
+        };
+        let Some(&ancestor_node_index) = humanoid_bone_nodes.get(ancestor_source.as_str()) else {
+            continue;
+        };
+        let Some(&descendant_node_index) = humanoid_bone_nodes.get(descendant_source.as_str())
+        else {
+            continue;
+        };
+
+        let parent_map = collect_parent_index_map_from_json(json);
+        if parent_map.get(&descendant_node_index).copied() != Some(ancestor_node_index) {
+            // Something (even if unmapped) already sits between them, so the
+            // bone isn't simply absent from the node tree — leave it to the
+            // regular hierarchy validation instead of guessing a splice point.
+            continue;
+        }
+
+        let node_locals: Vec<Matrix4<f32>> = json["nodes"]
+            .as_array()
+            .map(|nodes| nodes.iter().map(node_to_local_matrix).collect())
+            .unwrap_or_default();
+        let world_matrices = compute_node_world_matrices(&node_locals, &parent_map);
+        let Some(&ancestor_world) = world_matrices.get(ancestor_node_index) else {
+            continue;
+        };
+        let Some(&descendant_world) = world_matrices.get(descendant_node_index) else {
+            continue;
+        };
+        let Some(ancestor_world_inverse) = ancestor_world.try_inverse() else {
+            continue;
+        };
+
+        let midpoint_world = Vector4::new(
+            (ancestor_world[(0, 3)] + descendant_world[(0, 3)]) * 0.5,
+            (ancestor_world[(1, 3)] + descendant_world[(1, 3)]) * 0.5,
+            (ancestor_world[(2, 3)] + descendant_world[(2, 3)]) * 0.5,
+            1.0,
+        );
+        let local_point = ancestor_world_inverse * midpoint_world;
+
+        let new_node_index = json["nodes"].as_array().map(|nodes| nodes.len()).unwrap_or(0);
+        let Some(nodes) = json.get_mut("nodes").and_then(Value::as_array_mut) else {
+            continue;
+        };
+        nodes.push(serde_json::json!({
+            "name": bone_source,
+            "translation": [local_point.x, local_point.y, local_point.z],
+            "children": [descendant_node_index],
+        }));
+
+        if let Some(ancestor_children) = json["nodes"][ancestor_node_index]
+            .get_mut("children")
+            .and_then(Value::as_array_mut)
+        {
+            ancestor_children.retain(|value| {
+                value.as_u64().map(|index| index as usize) != Some(descendant_node_index)
+            });
+            ancestor_children.push(Value::from(new_node_index as u64));
+        }
+
+        // The descendant's parent changed from the ancestor to the new node;
+        // recompute its local transform so its world position is unchanged.
+        let new_node_world = ancestor_world * node_to_local_matrix(&json["nodes"][new_node_index]);
+        if let Some(new_node_world_inverse) = new_node_world.try_inverse() {
+            let descendant_new_local = new_node_world_inverse * descendant_world;
+            if let Some(descendant_node) = json["nodes"].get_mut(descendant_node_index) {
+                set_node_local_matrix(descendant_node, &descendant_new_local);
+            }
+        }
+
+        humanoid_bone_nodes.insert(bone_source.to_string(), new_node_index);
+
+        issues.push(ValidationIssue {
+            severity: super::types::Severity::Warning,
+            code: "SYNTHESIZED_BONE".to_string(),
+            message: format!(
+                "⚠️ Required bone '{}' was missing; synthesized a pass-through bone between '{}' and '{}'",
+                bone_source, ancestor_source, descendant_source
+            ),
+        });
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_host_bone_weighted_vertex_when_blending_collision_volume_then_weakest_lane_is_replaced()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 }
+            ],
+            "meshes": [
+                { "primitives": [ { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1 } } ] }
+            ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 }
+            ],
+            "buffers": [ { "byteLength": 20 } ]
+        });
+
+        // Joint array index 0 is the host bone; the vertex is weighted fully
+        // to it, with the other three lanes empty.
+        let mut bin = vec![0u8; 20];
+        bin[0..4].copy_from_slice(&[0, 0, 0, 0]);
+        for (lane, value) in [1.0f32, 0.0, 0.0, 0.0].iter().enumerate() {
+            let offset = 4 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        // Host is joint array index 0, the collision volume is joint array
+        // index 1, blending in 35% of the host's weight.
+        let blend_sources = [(1usize, 0usize, 0.35f32)];
+        blend_collision_volume_weights(&mut json, &mut bin, 0, &blend_sources);
+
+        let slot_at = |lane: usize| bin[lane];
+        let weight_at = |lane: usize| {
+            let offset = 4 + lane * 4;
+            f32::from_le_bytes(bin[offset..offset + 4].try_into().unwrap())
+        };
+
+        // The weakest non-host lane (lane 1, tied at 0.0 with lanes 2 and 3,
+        // first wins) is replaced by the volume.
+        assert_eq!(slot_at(1), 1);
+        assert!((weight_at(1) - 0.35).abs() < 1e-5);
+        assert!((weight_at(0) - 0.65).abs() < 1e-5);
+        assert_eq!(slot_at(2), 0);
+        assert_eq!(slot_at(3), 0);
+        assert!((weight_at(2)).abs() < 1e-5);
+        assert!((weight_at(3)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn given_dominant_second_influence_when_blending_collision_volume_then_it_is_left_alone() {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "mesh": 0, "skin": 0 }
+            ],
+            "meshes": [
+                { "primitives": [ { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1 } } ] }
+            ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 4, "byteLength": 16 }
+            ],
+            "buffers": [ { "byteLength": 20 } ]
+        });
+
+        // Lane 0 is the host (weight 0.1); lane 1 already dominates (0.9),
+        // so the small blended amount must not be allowed to evict it.
+        let mut bin = vec![0u8; 20];
+        bin[0..4].copy_from_slice(&[0, 4, 0, 0]);
+        for (lane, value) in [0.1f32, 0.9, 0.0, 0.0].iter().enumerate() {
+            let offset = 4 + lane * 4;
+            bin[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        let blend_sources = [(1usize, 0usize, 0.35f32)];
+        blend_collision_volume_weights(&mut json, &mut bin, 0, &blend_sources);
+
+        let slot_at = |lane: usize| bin[lane];
+        let weight_at = |lane: usize| {
+            let offset = 4 + lane * 4;
+            f32::from_le_bytes(bin[offset..offset + 4].try_into().unwrap())
+        };
+
+        // Weakest non-host lane is lane 2 (0.0, tied with lane 3, first
+        // wins), not the dominant lane 1.
+        assert_eq!(slot_at(2), 1);
+        assert!((weight_at(2) - 0.035).abs() < 1e-5);
+        assert_eq!(slot_at(1), 4);
+        assert!((weight_at(1) - 0.9).abs() < 1e-5);
+    }
+
+    #[test]
+    fn given_missing_inverse_bind_region_when_growing_then_buffer_is_appended_and_accessor_repointed()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "name": "hips", "children": [1] },
+                { "name": "spine", "translation": [0.0, 1.0, 0.0] }
+            ],
+            "skins": [ { "joints": [0, 1], "inverseBindMatrices": 0 } ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 2, "type": "MAT4" }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 128 }
+            ],
+            "buffers": [ { "byteLength": 128 } ]
+        });
+        let mut bin = vec![0u8; 128];
+
+        grow_inverse_bind_matrices(&mut json, &mut bin, 0, &HashSet::new())
+            .expect("growing inverse bind matrices should succeed");
+
+        assert_eq!(bin.len(), 256);
+        assert_eq!(json["accessors"][0]["bufferView"], Value::from(1u64));
+        assert_eq!(json["accessors"][0]["count"], Value::from(2u64));
+        assert_eq!(json["bufferViews"][1]["byteOffset"], Value::from(128u64));
+        assert_eq!(json["buffers"][0]["byteLength"], Value::from(256u64));
+
+        let meta = accessor_meta(&json, 0).expect("accessor should be readable");
+        let hips_inverse =
+            read_mat4_from_accessor(&bin, &meta, 0).expect("hips inverse bind matrix");
+        let spine_inverse =
+            read_mat4_from_accessor(&bin, &meta, 1).expect("spine inverse bind matrix");
+
+        assert!((hips_inverse - Matrix4::identity()).norm() < 1e-5);
+        let expected_spine_inverse = Translation3::new(0.0, -1.0, 0.0).to_homogeneous();
+        assert!((spine_inverse - expected_spine_inverse).norm() < 1e-5);
+    }
+
+    #[test]
+    fn given_present_host_bone_when_synthesizing_collision_volumes_then_new_joint_is_linked_and_unscaled_for_non_limb_volume()
+     {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "name": "mTorso" }
+            ],
+            "skins": [ { "joints": [0] } ]
+        });
+        let mut bin: Vec<u8> = Vec::new();
+
+        let issues = add_collision_volumes_to_skin(&mut json, &mut bin, 0)
+            .expect("adding collision volumes should succeed");
+
+        // mTorso hosts BELLY, LEFT_HANDLE and RIGHT_HANDLE; none of them are
+        // limb volumes, so none should carry a synthesized `scale`.
+        let joints = json["skins"][0]["joints"].as_array().expect("joints array");
+        assert_eq!(joints.len(), 4);
+
+        let nodes = json["nodes"].as_array().expect("nodes array");
+        assert_eq!(nodes.len(), 4);
+        for volume_node in &nodes[1..] {
+            assert!(volume_node.get("scale").is_none());
+        }
+
+        let torso_children = nodes[0]["children"].as_array().expect("torso children");
+        assert_eq!(torso_children.len(), 3);
+
+        assert!(issues.iter().any(|issue| issue.code == "FITTED_MESH_HOST_BONE_MISSING"));
+    }
+
+    /// Builds a minimal skin with `mTorso` (a non-limb host) and a
+    /// `mShoulderLeft`/`mElbowLeft` pair `bone_length` apart (a limb host and
+    /// its far bone), runs [`add_collision_volumes_to_skin`], asserts that the
+    /// non-limb `BELLY` volume carries no `scale`, and returns the synthesized
+    /// `LEFT_UPPER_ARM` volume's `scale` (if any).
+    fn synthesize_and_find_limb_volume_scale(bone_length: f32) -> Option<f32> {
+        let mut json = serde_json::json!({
+            "nodes": [
+                { "name": "mTorso" },
+                { "name": "mShoulderLeft" },
+                { "name": "mElbowLeft", "translation": [bone_length, 0.0, 0.0] }
+            ],
+            "skins": [ { "joints": [0, 1] } ]
+        });
+        let mut bin: Vec<u8> = Vec::new();
+
+        add_collision_volumes_to_skin(&mut json, &mut bin, 0)
+            .expect("adding collision volumes should succeed");
+
+        let nodes = json["nodes"].as_array().expect("nodes array");
+
+        let belly = nodes
+            .iter()
+            .find(|node| node["name"] == "BELLY")
+            .expect("BELLY volume should be synthesized");
+        assert!(belly.get("scale").is_none(), "non-limb volume should be left unscaled");
+
+        nodes
+            .iter()
+            .find(|node| node["name"] == "LEFT_UPPER_ARM")
+            .and_then(|node| node["scale"][0].as_f64())
+            .map(|value| value as f32)
+    }
+
+    #[test]
+    fn given_limb_host_and_far_bone_when_synthesizing_collision_volume_then_scale_tracks_bone_length()
+     {
+        let short_limb_scale =
+            synthesize_and_find_limb_volume_scale(0.4).expect("LEFT_UPPER_ARM should be scaled");
+        let long_limb_scale =
+            synthesize_and_find_limb_volume_scale(1.2).expect("LEFT_UPPER_ARM should be scaled");
+
+        assert!((short_limb_scale - 0.2).abs() < 1e-5);
+        assert!((long_limb_scale - 0.6).abs() < 1e-5);
+        assert!(
+            long_limb_scale > short_limb_scale,
+            "a longer limb should produce a proportionally larger collision volume"
+        );
+    }
 }
@@ -0,0 +1,397 @@
+//! Standalone skin validate/repair/dump diagnostics, independent of Second
+//! Life's bone-mapping requirements (c.f. [`super::skinning`]'s
+//! `collect_skin_weight_issues`/`rebake_skin_weights_for_sl_compliance`,
+//! which check and fix skin weights specifically for the mapped SL
+//! skeleton). These operate on any glTF skin so a user can see why a mesh
+//! deforms incorrectly before committing to a full VRM-to-SL conversion.
+
+use std::cmp::Ordering;
+
+use serde_json::Value;
+
+use super::gltf_utils::{
+    accessor_meta, read_joint_slot, read_weight_f32, write_joint_slot, write_weight_f32,
+};
+use super::skinning::{
+    collect_extended_skin_primitive_bindings, collect_skin_primitive_bindings,
+    valid_joint_weight_meta,
+};
+use super::types::{Severity, SkinDumpEntry, SkinDumpReport, SkinRepairStats, ValidationIssue};
+
+/// Scan every skin for correctable defects without modifying anything:
+/// inverse-bind-matrix count mismatching `joints.len()`, more than 4
+/// non-zero influences per vertex, weights that don't sum to 1.0, `JOINTS_0`
+/// indices outside the skin's `joints` array, and NaN/negative weights.
+pub(super) fn validate_skinning(json: &Value, bin: &[u8]) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let skin_count = json["skins"].as_array().map(|s| s.len()).unwrap_or(0);
+
+    for skin_index in 0..skin_count {
+        let joint_count = json["skins"][skin_index]["joints"]
+            .as_array()
+            .map(|arr| arr.len())
+            .unwrap_or(0);
+        if joint_count == 0 {
+            continue;
+        }
+
+        if let Some(ibm_index) = json["skins"][skin_index]["inverseBindMatrices"]
+            .as_u64()
+            .map(|value| value as usize)
+            && let Some(ibm_meta) = accessor_meta(json, ibm_index)
+            && ibm_meta.count != joint_count
+        {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "SKIN_IBM_COUNT_MISMATCH".to_string(),
+                message: format!(
+                    "⚠️ Skin {} has {} inverse bind matrices but {} joints",
+                    skin_index, ibm_meta.count, joint_count
+                ),
+            });
+        }
+
+        let mut over_4_influences = 0usize;
+        let mut not_normalized = 0usize;
+        let mut out_of_range_joints = 0usize;
+        let mut invalid_weights = 0usize;
+
+        for binding in collect_extended_skin_primitive_bindings(json, skin_index) {
+            let Some(joints_0_meta) = accessor_meta(json, binding.primary.joints_accessor) else {
+                continue;
+            };
+            let Some(weights_0_meta) = accessor_meta(json, binding.primary.weights_accessor)
+            else {
+                continue;
+            };
+            if !valid_joint_weight_meta(&joints_0_meta, &weights_0_meta) {
+                continue;
+            }
+            let extra_meta = binding
+                .joints_1_accessor
+                .zip(binding.weights_1_accessor)
+                .and_then(|(j1, w1)| Some((accessor_meta(json, j1)?, accessor_meta(json, w1)?)))
+                .filter(|(jm, wm)| valid_joint_weight_meta(jm, wm));
+
+            let count = joints_0_meta.count.min(weights_0_meta.count);
+            for vertex_index in 0..count {
+                let mut lanes: Vec<(usize, f32)> = Vec::with_capacity(8);
+                for lane in 0..4 {
+                    let slot =
+                        read_joint_slot(bin, &joints_0_meta, vertex_index, lane).unwrap_or(0);
+                    let weight =
+                        read_weight_f32(bin, &weights_0_meta, vertex_index, lane).unwrap_or(0.0);
+                    lanes.push((slot as usize, weight));
+                }
+                if let Some((joints_1_meta, weights_1_meta)) = &extra_meta {
+                    for lane in 0..4 {
+                        let slot = read_joint_slot(bin, joints_1_meta, vertex_index, lane)
+                            .unwrap_or(0);
+                        let weight = read_weight_f32(bin, weights_1_meta, vertex_index, lane)
+                            .unwrap_or(0.0);
+                        lanes.push((slot as usize, weight));
+                    }
+                }
+
+                let mut non_zero_count = 0usize;
+                let mut sum = 0.0f32;
+                let mut vertex_has_invalid_weight = false;
+                let mut vertex_has_out_of_range = false;
+                for (slot, weight) in lanes {
+                    if !weight.is_finite() || weight < 0.0 {
+                        vertex_has_invalid_weight = true;
+                        continue;
+                    }
+                    if weight <= 1e-6 {
+                        continue;
+                    }
+                    non_zero_count += 1;
+                    sum += weight;
+                    if slot >= joint_count {
+                        vertex_has_out_of_range = true;
+                    }
+                }
+
+                if vertex_has_invalid_weight {
+                    invalid_weights += 1;
+                }
+                if vertex_has_out_of_range {
+                    out_of_range_joints += 1;
+                }
+                if non_zero_count > 4 {
+                    over_4_influences += 1;
+                }
+                if non_zero_count > 0 && (sum - 1.0).abs() > 1e-3 {
+                    not_normalized += 1;
+                }
+            }
+        }
+
+        if out_of_range_joints > 0 {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "SKIN_JOINT_INDEX_OUT_OF_RANGE".to_string(),
+                message: format!(
+                    "⚠️ Skin {} has {} vertex/vertices with a joint index outside its {}-joint skeleton. They will be clamped to the fallback slot on repair",
+                    skin_index, out_of_range_joints, joint_count
+                ),
+            });
+        }
+        if invalid_weights > 0 {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "SKIN_WEIGHT_INVALID".to_string(),
+                message: format!(
+                    "⚠️ Skin {} has {} vertex/vertices with a NaN or negative joint weight. They will be treated as zero weight on repair",
+                    skin_index, invalid_weights
+                ),
+            });
+        }
+        if over_4_influences > 0 {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "SKIN_WEIGHTS_OVER_4_INFLUENCES".to_string(),
+                message: format!(
+                    "⚠️ Skin {} has {} vertex/vertices with more than 4 joint influences. They will be clamped to the 4 heaviest influences on repair",
+                    skin_index, over_4_influences
+                ),
+            });
+        }
+        if not_normalized > 0 {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "SKIN_WEIGHTS_NOT_NORMALIZED".to_string(),
+                message: format!(
+                    "⚠️ Skin {} has {} vertex/vertices whose weights don't sum to 1.0. They will be renormalized on repair",
+                    skin_index, not_normalized
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Fix the per-vertex defects [`validate_skinning`] can find, in place (an
+/// inverse-bind-matrix count mismatch is reported there but not repaired
+/// here): NaN/negative
+/// weights are treated as zero, out-of-range `JOINTS_0`/`JOINTS_1` indices
+/// are clamped to the fallback slot (`0`), influences are merged down to the
+/// 4 heaviest and sorted so the dominant one lands in lane 0, and the
+/// survivors are renormalized so they sum to exactly 1.0 (the dominant lane
+/// absorbs the rounding residue rather than being divided independently). A
+/// vertex left with no surviving influence is bound fully to joint slot `0`
+/// at weight 1.0. Any `JOINTS_1`/`WEIGHTS_1` influences folded into
+/// `JOINTS_0`/`WEIGHTS_0` this way are zeroed out, mirroring
+/// [`super::skinning::rebake_skin_weights_for_sl_compliance`].
+pub(super) fn repair_skinning(json: &Value, bin: &mut [u8]) -> SkinRepairStats {
+    let mut stats = SkinRepairStats {
+        clamped_vertex_count: 0,
+        renormalized_vertex_count: 0,
+        out_of_range_joint_count: 0,
+        invalid_weight_count: 0,
+    };
+
+    let skin_count = json["skins"].as_array().map(|s| s.len()).unwrap_or(0);
+
+    for skin_index in 0..skin_count {
+        let joint_count = json["skins"][skin_index]["joints"]
+            .as_array()
+            .map(|arr| arr.len())
+            .unwrap_or(0);
+        if joint_count == 0 {
+            continue;
+        }
+
+        for binding in collect_extended_skin_primitive_bindings(json, skin_index) {
+            let Some(joints_0_meta) = accessor_meta(json, binding.primary.joints_accessor) else {
+                continue;
+            };
+            let Some(weights_0_meta) = accessor_meta(json, binding.primary.weights_accessor)
+            else {
+                continue;
+            };
+            if !valid_joint_weight_meta(&joints_0_meta, &weights_0_meta) {
+                continue;
+            }
+            let extra_meta = binding
+                .joints_1_accessor
+                .zip(binding.weights_1_accessor)
+                .and_then(|(j1, w1)| Some((accessor_meta(json, j1)?, accessor_meta(json, w1)?)))
+                .filter(|(jm, wm)| valid_joint_weight_meta(jm, wm));
+
+            let count = joints_0_meta.count.min(weights_0_meta.count);
+            for vertex_index in 0..count {
+                let mut raw_lanes: Vec<(usize, f32)> = Vec::with_capacity(8);
+                for lane in 0..4 {
+                    let slot =
+                        read_joint_slot(bin, &joints_0_meta, vertex_index, lane).unwrap_or(0);
+                    let weight =
+                        read_weight_f32(bin, &weights_0_meta, vertex_index, lane).unwrap_or(0.0);
+                    raw_lanes.push((slot as usize, weight));
+                }
+                if let Some((joints_1_meta, weights_1_meta)) = &extra_meta {
+                    for lane in 0..4 {
+                        let slot = read_joint_slot(bin, joints_1_meta, vertex_index, lane)
+                            .unwrap_or(0);
+                        let weight = read_weight_f32(bin, weights_1_meta, vertex_index, lane)
+                            .unwrap_or(0.0);
+                        raw_lanes.push((slot as usize, weight));
+                    }
+                }
+
+                let mut had_invalid_weight = false;
+                let mut had_out_of_range = false;
+                let mut merged: Vec<(usize, f32)> = Vec::with_capacity(8);
+                for (slot, weight) in raw_lanes {
+                    if !weight.is_finite() || weight < 0.0 {
+                        had_invalid_weight = true;
+                        continue;
+                    }
+                    if weight <= 1e-6 {
+                        continue;
+                    }
+                    let clamped_slot = if slot >= joint_count {
+                        had_out_of_range = true;
+                        0
+                    } else {
+                        slot
+                    };
+                    if let Some(existing) = merged.iter_mut().find(|(s, _)| *s == clamped_slot) {
+                        existing.1 += weight;
+                    } else {
+                        merged.push((clamped_slot, weight));
+                    }
+                }
+
+                if had_invalid_weight {
+                    stats.invalid_weight_count += 1;
+                }
+                if had_out_of_range {
+                    stats.out_of_range_joint_count += 1;
+                }
+
+                let original_sum: f32 = merged.iter().map(|&(_, w)| w).sum();
+                let clamped = merged.len() > 4;
+                merged.sort_by(|a, b| {
+                    b.1.partial_cmp(&a.1)
+                        .unwrap_or(Ordering::Equal)
+                        .then_with(|| a.0.cmp(&b.0))
+                });
+                merged.truncate(4);
+                let new_sum: f32 = merged.iter().map(|&(_, w)| w).sum();
+
+                let mut new_slots = [0u16; 4];
+                let mut new_weights = [0.0f32; 4];
+                if new_sum > 1e-7 {
+                    for lane in 0..4 {
+                        if let Some(&(slot, _)) = merged.get(lane) {
+                            new_slots[lane] = slot as u16;
+                        }
+                    }
+                    // `merged` is sorted by descending weight, so lane 0 holds
+                    // the dominant influence: give it the leftover residue
+                    // rather than its own independently-rounded share, so the
+                    // stored weights sum to exactly 1.0 instead of drifting
+                    // from accumulated per-lane division error.
+                    let mut residue = 1.0f32;
+                    for lane in 1..4 {
+                        if let Some(&(_, weight)) = merged.get(lane) {
+                            new_weights[lane] = weight / new_sum;
+                            residue -= new_weights[lane];
+                        }
+                    }
+                    if !merged.is_empty() {
+                        new_weights[0] = residue;
+                    }
+                } else {
+                    new_weights[0] = 1.0;
+                }
+
+                for lane in 0..4 {
+                    write_joint_slot(bin, &joints_0_meta, vertex_index, lane, new_slots[lane]);
+                    write_weight_f32(bin, &weights_0_meta, vertex_index, lane, new_weights[lane]);
+                }
+                if let Some((joints_1_meta, weights_1_meta)) = &extra_meta {
+                    for lane in 0..4 {
+                        write_joint_slot(bin, joints_1_meta, vertex_index, lane, 0);
+                        write_weight_f32(bin, weights_1_meta, vertex_index, lane, 0.0);
+                    }
+                }
+
+                if clamped {
+                    stats.clamped_vertex_count += 1;
+                }
+                if (original_sum - 1.0).abs() > 1e-3 {
+                    stats.renormalized_vertex_count += 1;
+                }
+            }
+        }
+    }
+
+    stats
+}
+
+/// Emit per-skin joint usage and influence statistics without modifying
+/// anything: joint count, used vs unused slots, and a histogram of non-zero
+/// influence counts per vertex.
+pub(super) fn dump_skinning(json: &Value, bin: &[u8]) -> SkinDumpReport {
+    let mut skins = Vec::new();
+    let skin_count = json["skins"].as_array().map(|s| s.len()).unwrap_or(0);
+
+    for skin_index in 0..skin_count {
+        let joint_count = json["skins"][skin_index]["joints"]
+            .as_array()
+            .map(|arr| arr.len())
+            .unwrap_or(0);
+        if joint_count == 0 {
+            continue;
+        }
+
+        let mut used_slots = vec![false; joint_count];
+        let mut influence_histogram = [0usize; 5];
+
+        for binding in collect_skin_primitive_bindings(json, skin_index) {
+            let Some(joints_meta) = accessor_meta(json, binding.joints_accessor) else {
+                continue;
+            };
+            let Some(weights_meta) = accessor_meta(json, binding.weights_accessor) else {
+                continue;
+            };
+            if !valid_joint_weight_meta(&joints_meta, &weights_meta) {
+                continue;
+            }
+
+            let count = joints_meta.count.min(weights_meta.count);
+            for vertex_index in 0..count {
+                let mut non_zero_count = 0usize;
+                for lane in 0..4 {
+                    let weight =
+                        read_weight_f32(bin, &weights_meta, vertex_index, lane).unwrap_or(0.0);
+                    if !weight.is_finite() || weight <= 1e-6 {
+                        continue;
+                    }
+                    non_zero_count += 1;
+                    let slot =
+                        read_joint_slot(bin, &joints_meta, vertex_index, lane).unwrap_or(0)
+                            as usize;
+                    if slot < used_slots.len() {
+                        used_slots[slot] = true;
+                    }
+                }
+                influence_histogram[non_zero_count.min(4)] += 1;
+            }
+        }
+
+        let used_joint_slot_count = used_slots.iter().filter(|&&used| used).count();
+        skins.push(SkinDumpEntry {
+            skin_index,
+            joint_count,
+            used_joint_slot_count,
+            unused_joint_slot_count: joint_count - used_joint_slot_count,
+            influence_histogram,
+        });
+    }
+
+    SkinDumpReport { skins }
+}
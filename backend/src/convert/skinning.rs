@@ -1,34 +1,49 @@
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
+use nalgebra::{Matrix4, Point3, UnitQuaternion, Vector3};
 use serde_json::Value;
 
+use crate::cancellation::CancellationToken;
+
+use super::bone_remap::{self, CompiledRule, RemapTarget};
+use super::error::ConvertError;
 use super::gltf_utils::{
-    PrimitiveSkinBinding, accessor_meta, read_joint_slot, read_weight_f32, write_joint_slot,
-    write_weight_f32,
+    AccessorMeta, PrimitiveSkinBinding, accessor_meta, compose_trs, decompose_trs,
+    is_weight_component_type, node_index_by_name, read_f32_element, read_joint_slot,
+    read_mat4_from_accessor, read_sparse_index, read_weight_f32, write_joint_slot,
+    write_weight_lanes_f32,
 };
-use super::types::{BENTO_BONE_MAP, BONE_MAP};
+use super::profile::SkeletonProfile;
+use super::skeleton::SkeletonPose;
+use super::types::{Severity, SkinWeightRebakeStats, ValidationIssue};
 
-// ─── Unmapped-bone weight remapping ──────────────────────────────────────────
+// ─── Unmapped-bone weight remapping / SL compliance rebake ───────────────────
 
-/// Remap vertex weights from non-SL (unmapped) VRM bones to their nearest
-/// SL-mapped ancestor in the skeleton hierarchy.
-///
-/// Unmapped bones include:
-/// - `upperChest` (not in BONE_MAP; SL uses only chest/spine/neck chain)
-/// - Spring/secondary bones (`J_Sec_*`) used for clothing/hair physics in VRM
-///
-/// For each skin, joint-slot indices that refer to unmapped nodes have their
-/// accumulated weight transferred to the nearest ancestor that IS mapped.
-pub(super) fn remap_unmapped_bone_weights(
-    json: &mut Value,
-    bin: &mut [u8],
+/// Extended primitive skin binding: the mandatory `JOINTS_0`/`WEIGHTS_0` pair
+/// plus an optional `JOINTS_1`/`WEIGHTS_1` pair used by source rigs exported
+/// with more than 4 influences per vertex.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ExtendedSkinBinding {
+    pub(super) primary: PrimitiveSkinBinding,
+    pub(super) joints_1_accessor: Option<usize>,
+    pub(super) weights_1_accessor: Option<usize>,
+}
+
+/// Build the set of SL-mapped node indices and a nearest-SL-ancestor lookup
+/// for a humanoid bone mapping, shared by the issue-collection and rebake
+/// passes below.
+fn build_sl_ancestor_lookup(
+    json: &Value,
     humanoid_bone_nodes: &HashMap<String, usize>,
-) {
-    let sl_node_indices: HashSet<usize> = BONE_MAP
-        .iter()
-        .chain(BENTO_BONE_MAP.iter())
-        .filter_map(|(vrm_name, _)| humanoid_bone_nodes.get(*vrm_name).copied())
+    secondary_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
+) -> (HashSet<usize>, Vec<Option<usize>>) {
+    let sl_node_indices: HashSet<usize> = profile
+        .bone_pairs()
+        .filter_map(|(vrm_name, _)| humanoid_bone_nodes.get(vrm_name).copied())
+        .chain(secondary_bone_nodes.values().copied())
         .collect();
 
     let node_count = json["nodes"].as_array().map(|a| a.len()).unwrap_or(0);
@@ -47,17 +62,171 @@ pub(super) fn remap_unmapped_bone_weights(
         }
     }
 
-    let find_sl_ancestor = |start: usize| -> Option<usize> {
-        let mut cur = parent_of[start];
-        while let Some(p) = cur {
-            if sl_node_indices.contains(&p) {
-                return Some(p);
+    (sl_node_indices, parent_of)
+}
+
+fn find_sl_ancestor(
+    start: usize,
+    parent_of: &[Option<usize>],
+    sl_node_indices: &HashSet<usize>,
+) -> Option<usize> {
+    let mut cur = parent_of.get(start).copied().flatten();
+    while let Some(p) = cur {
+        if sl_node_indices.contains(&p) {
+            return Some(p);
+        }
+        cur = parent_of.get(p).and_then(|v| *v);
+    }
+    None
+}
+
+pub(super) fn valid_joint_weight_meta(
+    joints_meta: &AccessorMeta,
+    weights_meta: &AccessorMeta,
+) -> bool {
+    joints_meta.accessor_type == "VEC4"
+        && weights_meta.accessor_type == "VEC4"
+        && (joints_meta.component_type == 5121 || joints_meta.component_type == 5123)
+        && is_weight_component_type(weights_meta.component_type)
+}
+
+pub(super) fn collect_extended_skin_primitive_bindings(
+    json: &Value,
+    skin_index: usize,
+) -> Vec<ExtendedSkinBinding> {
+    let nodes = json
+        .get("nodes")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let meshes = json
+        .get("meshes")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut seen = HashSet::<(usize, usize)>::new();
+    let mut bindings = Vec::<ExtendedSkinBinding>::new();
+
+    for node in nodes {
+        let Some(node_skin_index) = node
+            .get("skin")
+            .and_then(Value::as_u64)
+            .map(|value| value as usize)
+        else {
+            continue;
+        };
+        if node_skin_index != skin_index {
+            continue;
+        }
+
+        let Some(mesh_index) = node
+            .get("mesh")
+            .and_then(Value::as_u64)
+            .map(|value| value as usize)
+        else {
+            continue;
+        };
+        let Some(mesh) = meshes.get(mesh_index) else {
+            continue;
+        };
+        let Some(primitives) = mesh.get("primitives").and_then(Value::as_array) else {
+            continue;
+        };
+
+        for primitive in primitives {
+            let Some(attributes) = primitive.get("attributes").and_then(Value::as_object) else {
+                continue;
+            };
+            let Some(joints_accessor) = attributes
+                .get("JOINTS_0")
+                .and_then(Value::as_u64)
+                .map(|value| value as usize)
+            else {
+                continue;
+            };
+            let Some(weights_accessor) = attributes
+                .get("WEIGHTS_0")
+                .and_then(Value::as_u64)
+                .map(|value| value as usize)
+            else {
+                continue;
+            };
+
+            if !seen.insert((joints_accessor, weights_accessor)) {
+                continue;
             }
-            cur = parent_of.get(p).and_then(|v| *v);
+
+            let joints_1_accessor = attributes
+                .get("JOINTS_1")
+                .and_then(Value::as_u64)
+                .map(|value| value as usize);
+            let weights_1_accessor = attributes
+                .get("WEIGHTS_1")
+                .and_then(Value::as_u64)
+                .map(|value| value as usize);
+
+            bindings.push(ExtendedSkinBinding {
+                primary: PrimitiveSkinBinding {
+                    joints_accessor,
+                    weights_accessor,
+                },
+                joints_1_accessor,
+                weights_1_accessor,
+            });
         }
-        None
-    };
+    }
+
+    bindings
+}
+
+/// Read every non-zero `(joint_slot, weight)` influence for a vertex from the
+/// primary `JOINTS_0`/`WEIGHTS_0` accessors and, when present, the extra
+/// `JOINTS_1`/`WEIGHTS_1` accessors.
+fn read_vertex_influences(
+    bin: &[u8],
+    joints_0_meta: &AccessorMeta,
+    weights_0_meta: &AccessorMeta,
+    extra_meta: Option<(&AccessorMeta, &AccessorMeta)>,
+    vertex_index: usize,
+) -> Vec<(usize, f32)> {
+    let mut influences = Vec::with_capacity(8);
+    for lane in 0..4 {
+        let slot = read_joint_slot(bin, joints_0_meta, vertex_index, lane).unwrap_or(0) as usize;
+        let weight = read_weight_f32(bin, weights_0_meta, vertex_index, lane).unwrap_or(0.0);
+        if weight > 1e-7 {
+            influences.push((slot, weight));
+        }
+    }
+    if let Some((joints_1_meta, weights_1_meta)) = extra_meta {
+        for lane in 0..4 {
+            let slot = read_joint_slot(bin, joints_1_meta, vertex_index, lane).unwrap_or(0) as usize;
+            let weight = read_weight_f32(bin, weights_1_meta, vertex_index, lane).unwrap_or(0.0);
+            if weight > 1e-7 {
+                influences.push((slot, weight));
+            }
+        }
+    }
+    influences
+}
 
+/// Flag skin-weight problems for [`super::AnalysisReport`] without mutating
+/// anything: more than 4 non-zero influences per vertex (only possible when a
+/// source rig carries the optional `JOINTS_1`/`WEIGHTS_1` set), influence
+/// weights that don't sum to ~1.0, and influences bound to bones outside the
+/// mapped SL skeleton. Counts are aggregated per skin rather than reported
+/// per vertex to keep the issue list readable.
+pub(super) fn collect_skin_weight_issues(
+    json: &Value,
+    bin: &[u8],
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    secondary_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
+) -> Vec<ValidationIssue> {
+    let (sl_node_indices, _) =
+        build_sl_ancestor_lookup(json, humanoid_bone_nodes, secondary_bone_nodes, profile);
+
+    let mut issues = Vec::new();
     let skin_count = json["skins"].as_array().map(|s| s.len()).unwrap_or(0);
 
     for skin_index in 0..skin_count {
@@ -69,99 +238,330 @@ pub(super) fn remap_unmapped_bone_weights(
                     .collect()
             })
             .unwrap_or_default();
-
         if joints.is_empty() {
             continue;
         }
 
-        let mut slot_remap: Vec<usize> = (0..joints.len()).collect();
-        let mut any_remap = false;
-        for (slot, &node_idx) in joints.iter().enumerate() {
-            if sl_node_indices.contains(&node_idx) {
+        let mut over_4_influences = 0usize;
+        let mut not_normalized = 0usize;
+        let mut orphan_bound = 0usize;
+
+        for binding in collect_extended_skin_primitive_bindings(json, skin_index) {
+            let Some(joints_0_meta) = accessor_meta(json, binding.primary.joints_accessor) else {
+                continue;
+            };
+            let Some(weights_0_meta) = accessor_meta(json, binding.primary.weights_accessor)
+            else {
+                continue;
+            };
+            if !valid_joint_weight_meta(&joints_0_meta, &weights_0_meta) {
                 continue;
             }
-            if let Some(ancestor_node_idx) = find_sl_ancestor(node_idx) {
-                if let Some(ancestor_slot) = joints.iter().position(|&j| j == ancestor_node_idx) {
-                    slot_remap[slot] = ancestor_slot;
-                    any_remap = true;
+
+            let extra_meta = binding
+                .joints_1_accessor
+                .zip(binding.weights_1_accessor)
+                .and_then(|(j1, w1)| Some((accessor_meta(json, j1)?, accessor_meta(json, w1)?)))
+                .filter(|(jm, wm)| valid_joint_weight_meta(jm, wm));
+
+            let count = joints_0_meta.count.min(weights_0_meta.count);
+            for vertex_index in 0..count {
+                let influences = read_vertex_influences(
+                    bin,
+                    &joints_0_meta,
+                    &weights_0_meta,
+                    extra_meta.as_ref().map(|(jm, wm)| (jm, wm)),
+                    vertex_index,
+                );
+                if influences.is_empty() {
+                    continue;
+                }
+
+                if influences.len() > 4 {
+                    over_4_influences += 1;
+                }
+
+                let sum: f32 = influences.iter().map(|&(_, w)| w).sum();
+                if (sum - 1.0).abs() > 1e-3 {
+                    not_normalized += 1;
+                }
+
+                let has_orphan = influences.iter().any(|&(slot, _)| {
+                    joints
+                        .get(slot)
+                        .is_none_or(|node_idx| !sl_node_indices.contains(node_idx))
+                });
+                if has_orphan {
+                    orphan_bound += 1;
                 }
             }
         }
 
-        if !any_remap {
+        if over_4_influences > 0 {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "SKIN_WEIGHTS_OVER_4_INFLUENCES".to_string(),
+                message: format!(
+                    "⚠️ Skin {} has {} vertex/vertices with more than 4 joint influences. They will be clamped to the 4 heaviest influences on conversion",
+                    skin_index, over_4_influences
+                ),
+            });
+        }
+        if not_normalized > 0 {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "SKIN_WEIGHTS_NOT_NORMALIZED".to_string(),
+                message: format!(
+                    "⚠️ Skin {} has {} vertex/vertices whose weights don't sum to 1.0. They will be renormalized on conversion",
+                    skin_index, not_normalized
+                ),
+            });
+        }
+        if orphan_bound > 0 {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "SKIN_WEIGHTS_UNMAPPED_BONE".to_string(),
+                message: format!(
+                    "⚠️ Skin {} has {} vertex/vertices bound to bones outside the mapped SL skeleton. They will be re-bound to the nearest mapped ancestor on conversion",
+                    skin_index, orphan_bound
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Rebake every skin's vertex weights for Second Life's requirements: at most
+/// 4 influences per vertex (dropped weight redistributed among the rest),
+/// weights renormalized to sum to 1.0, and any influence bound to a bone
+/// outside the mapped SL skeleton re-bound per `bone_remap_rules` (default,
+/// with an empty list: collapsed onto its nearest mapped ancestor).
+///
+/// `secondary_bone_nodes` (non-empty only when
+/// [`ConvertOptions::retarget_secondary_bones`] is set and the caller already
+/// renamed matching nodes via [`super::skeleton::retarget_secondary_bone_nodes`])
+/// are treated as already SL-mapped here, so their influences are left in
+/// place instead of being collapsed into an ancestor.
+///
+/// Runs unconditionally (not gated on whether any influence needs remapping)
+/// so that models whose weights are merely un-normalized are still fixed.
+///
+/// [`ConvertOptions::retarget_secondary_bones`]: super::types::ConvertOptions::retarget_secondary_bones
+pub(super) fn rebake_skin_weights_for_sl_compliance(
+    json: &mut Value,
+    bin: &mut [u8],
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    secondary_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
+    bone_remap_rules: &[CompiledRule],
+    cancellation: Option<&CancellationToken>,
+) -> Result<SkinWeightRebakeStats> {
+    let (sl_node_indices, parent_of) =
+        build_sl_ancestor_lookup(json, humanoid_bone_nodes, secondary_bone_nodes, profile);
+    let remap_targets = bone_remap::build_remap_target_table(
+        json,
+        bone_remap_rules,
+        humanoid_bone_nodes,
+        secondary_bone_nodes,
+        profile,
+    );
+
+    let mut stats = SkinWeightRebakeStats {
+        clamped_vertex_count: 0,
+        renormalized_vertex_count: 0,
+        remapped_orphan_vertex_count: 0,
+        dropped_vertex_count: 0,
+    };
+
+    let skin_count = json["skins"].as_array().map(|s| s.len()).unwrap_or(0);
+
+    for skin_index in 0..skin_count {
+        if cancellation.is_some_and(CancellationToken::is_cancelled) {
+            return Err(ConvertError::cancelled().into());
+        }
+        let joints: Vec<usize> = json["skins"][skin_index]["joints"]
+            .as_array()
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_u64().map(|n| n as usize))
+                    .collect()
+            })
+            .unwrap_or_default();
+        if joints.is_empty() {
             continue;
         }
 
-        let bindings = collect_skin_primitive_bindings(json, skin_index);
-        for binding in bindings {
-            let Some(joints_meta) = accessor_meta(json, binding.joints_accessor) else {
+        for binding in collect_extended_skin_primitive_bindings(json, skin_index) {
+            let Some(joints_0_meta) = accessor_meta(json, binding.primary.joints_accessor) else {
                 continue;
             };
-            let Some(weights_meta) = accessor_meta(json, binding.weights_accessor) else {
+            let Some(weights_0_meta) = accessor_meta(json, binding.primary.weights_accessor)
+            else {
                 continue;
             };
-            if joints_meta.accessor_type != "VEC4" || weights_meta.accessor_type != "VEC4" {
-                continue;
-            }
-            if !(joints_meta.component_type == 5121 || joints_meta.component_type == 5123) {
-                continue;
-            }
-            if weights_meta.component_type != 5126 {
+            if !valid_joint_weight_meta(&joints_0_meta, &weights_0_meta) {
                 continue;
             }
 
-            let count = joints_meta.count.min(weights_meta.count);
+            let extra_meta = binding
+                .joints_1_accessor
+                .zip(binding.weights_1_accessor)
+                .and_then(|(j1, w1)| Some((accessor_meta(json, j1)?, accessor_meta(json, w1)?)))
+                .filter(|(jm, wm)| valid_joint_weight_meta(jm, wm));
+
+            let count = joints_0_meta.count.min(weights_0_meta.count);
             for vertex_index in 0..count {
-                let mut slots = [0u16; 4];
-                let mut weights = [0.0f32; 4];
-                for lane in 0..4 {
-                    slots[lane] =
-                        read_joint_slot(bin, &joints_meta, vertex_index, lane).unwrap_or(0);
-                    weights[lane] =
-                        read_weight_f32(bin, &weights_meta, vertex_index, lane).unwrap_or(0.0);
+                let influences = read_vertex_influences(
+                    bin,
+                    &joints_0_meta,
+                    &weights_0_meta,
+                    extra_meta.as_ref().map(|(jm, wm)| (jm, wm)),
+                    vertex_index,
+                );
+                if influences.is_empty() {
+                    continue;
                 }
 
-                let mut acc = vec![0.0f32; joints.len()];
-                for lane in 0..4 {
-                    let old_slot = slots[lane] as usize;
-                    if old_slot >= slot_remap.len() {
+                let original_sum: f32 = influences.iter().map(|&(_, w)| w).sum();
+
+                let mut remapped_any_orphan = false;
+                let mut dropped_any = false;
+                let mut merged: Vec<(usize, f32)> = Vec::with_capacity(influences.len());
+                for (slot, weight) in influences {
+                    let Some(node_idx) = joints.get(slot).copied() else {
+                        merge_weight(&mut merged, slot, weight);
+                        continue;
+                    };
+                    if sl_node_indices.contains(&node_idx) {
+                        merge_weight(&mut merged, slot, weight);
                         continue;
                     }
-                    let target_slot = slot_remap[old_slot];
-                    if target_slot < acc.len() {
-                        acc[target_slot] += weights[lane];
+
+                    let collapse_to_ancestor = || {
+                        find_sl_ancestor(node_idx, &parent_of, &sl_node_indices)
+                            .and_then(|ancestor| joints.iter().position(|&j| j == ancestor))
+                    };
+
+                    match remap_targets.get(&node_idx) {
+                        Some(RemapTarget::Drop) => dropped_any = true,
+                        Some(RemapTarget::MapToNode(target_node)) => {
+                            match joints
+                                .iter()
+                                .position(|&j| j == *target_node)
+                                .or_else(collapse_to_ancestor)
+                            {
+                                Some(target_slot) => {
+                                    remapped_any_orphan = true;
+                                    merge_weight(&mut merged, target_slot, weight);
+                                }
+                                None => merge_weight(&mut merged, slot, weight),
+                            }
+                        }
+                        None | Some(RemapTarget::CollapseToAncestor) => {
+                            match collapse_to_ancestor() {
+                                Some(ancestor_slot) => {
+                                    remapped_any_orphan = true;
+                                    merge_weight(&mut merged, ancestor_slot, weight);
+                                }
+                                None => merge_weight(&mut merged, slot, weight),
+                            }
+                        }
                     }
                 }
 
-                let mut top4: Vec<(usize, f32)> = acc
-                    .iter()
-                    .enumerate()
-                    .filter(|&(_, &w)| w > 1e-7)
-                    .map(|(s, &w)| (s, w))
-                    .collect();
-                top4.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-                top4.truncate(4);
+                let clamped = merged.len() > 4;
+                merged.sort_by(|a, b| {
+                    b.1.partial_cmp(&a.1)
+                        .unwrap_or(Ordering::Equal)
+                        .then_with(|| a.0.cmp(&b.0))
+                });
+                merged.truncate(4);
+                let new_sum: f32 = merged.iter().map(|&(_, w)| w).sum();
 
-                let weight_sum: f32 = top4.iter().map(|&(_, w)| w).sum();
                 let mut new_slots = [0u16; 4];
                 let mut new_weights = [0.0f32; 4];
-                for lane in 0..4 {
-                    if let Some(&(slot, w)) = top4.get(lane) {
-                        new_slots[lane] = slot as u16;
-                        new_weights[lane] = if weight_sum > 1e-7 {
-                            w / weight_sum
-                        } else {
-                            0.0
-                        };
+                if new_sum > 1e-7 {
+                    for lane in 0..4 {
+                        if let Some(&(slot, weight)) = merged.get(lane) {
+                            new_slots[lane] = slot as u16;
+                            new_weights[lane] = weight / new_sum;
+                        }
                     }
+                } else {
+                    // Degenerate case (every kept influence's weight collapsed
+                    // to ~0, e.g. all-zero WEIGHTS_0 data): bind fully to the
+                    // highest-index surviving influence instead of leaving the
+                    // vertex unweighted, falling back to joint slot 0 if
+                    // nothing survived the merge at all.
+                    new_slots[0] = merged
+                        .iter()
+                        .map(|&(slot, _)| slot as u16)
+                        .max()
+                        .unwrap_or(0);
+                    new_weights[0] = 1.0;
                 }
 
-                for lane in 0..4 {
-                    let _ =
-                        write_joint_slot(bin, &joints_meta, vertex_index, lane, new_slots[lane]);
-                    let _ =
-                        write_weight_f32(bin, &weights_meta, vertex_index, lane, new_weights[lane]);
+                for (lane, &slot) in new_slots.iter().enumerate() {
+                    write_joint_slot(bin, &joints_0_meta, vertex_index, lane, slot);
+                }
+                write_weight_lanes_f32(bin, &weights_0_meta, vertex_index, new_weights);
+                if let Some((joints_1_meta, weights_1_meta)) = &extra_meta {
+                    for lane in 0..4 {
+                        write_joint_slot(bin, joints_1_meta, vertex_index, lane, 0);
+                    }
+                    write_weight_lanes_f32(bin, weights_1_meta, vertex_index, [0.0; 4]);
+                }
+
+                if clamped {
+                    stats.clamped_vertex_count += 1;
+                }
+                if remapped_any_orphan {
+                    stats.remapped_orphan_vertex_count += 1;
                 }
+                if dropped_any {
+                    stats.dropped_vertex_count += 1;
+                }
+                if (original_sum - 1.0).abs() > 1e-3 {
+                    stats.renormalized_vertex_count += 1;
+                }
+            }
+        }
+    }
+
+    Ok(stats)
+}
+
+/// Accumulate `weight` into `merged`'s entry for `slot`, combining influences
+/// that resolved to the same target slot instead of carrying duplicates
+/// through to the 4-influence clamp below.
+fn merge_weight(merged: &mut Vec<(usize, f32)>, slot: usize, weight: f32) {
+    if let Some(existing) = merged.iter_mut().find(|(s, _)| *s == slot) {
+        existing.1 += weight;
+    } else {
+        merged.push((slot, weight));
+    }
+}
+
+/// Drop the `JOINTS_1`/`WEIGHTS_1` attributes left over from an 8-influence
+/// source rig once [`rebake_skin_weights_for_sl_compliance`] has folded their
+/// contribution into `JOINTS_0`/`WEIGHTS_0` and zeroed them out. The
+/// accessors themselves are left in place unreferenced (this codebase never
+/// compacts the `accessors`/`bufferViews` arrays, e.g. dropped `animations`
+/// and morph `targets` accessors in [`super::validation::remove_unsupported_features`]
+/// are abandoned the same way) so no other accessor index needs remapping.
+pub(super) fn strip_redundant_second_influence_set(json: &mut Value) {
+    let Some(meshes) = json.get_mut("meshes").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for mesh in meshes {
+        let Some(primitives) = mesh.get_mut("primitives").and_then(Value::as_array_mut) else {
+            continue;
+        };
+        for primitive in primitives {
+            if let Some(attributes) = primitive.get_mut("attributes").and_then(Value::as_object_mut) {
+                attributes.remove("JOINTS_1");
+                attributes.remove("WEIGHTS_1");
             }
         }
     }
@@ -169,9 +569,104 @@ pub(super) fn remap_unmapped_bone_weights(
 
 // ─── Skinning weight optimization ────────────────────────────────────────────
 
+/// Clamp every vertex of `skin_index` to at most 4 joint influences (the
+/// heaviest survive) and renormalize the survivors to sum to 1.0. Leaves the
+/// now-redundant `JOINTS_1`/`WEIGHTS_1` attributes zeroed but in place; the
+/// caller drops them once via [`strip_redundant_second_influence_set`] after
+/// all skins are clamped.
+///
+/// This duplicates part of what [`rebake_skin_weights_for_sl_compliance`]
+/// already does when it runs earlier in the pipeline, but
+/// [`optimize_skinning_weights_and_joints`] doesn't have that function's
+/// humanoid/profile/remap-rule inputs and shouldn't depend on call order to
+/// produce a compliant skin — an over-4-influence or unnormalized vertex here
+/// would otherwise make the upcoming joint-compaction step keep joint slots
+/// that should have been prunable.
+fn clamp_and_renormalize_skin_weights(json: &mut Value, bin: &mut [u8], skin_index: usize) {
+    for binding in collect_extended_skin_primitive_bindings(json, skin_index) {
+        let Some(joints_0_meta) = accessor_meta(json, binding.primary.joints_accessor) else {
+            continue;
+        };
+        let Some(weights_0_meta) = accessor_meta(json, binding.primary.weights_accessor) else {
+            continue;
+        };
+        if !valid_joint_weight_meta(&joints_0_meta, &weights_0_meta) {
+            continue;
+        }
+
+        let extra_meta = binding
+            .joints_1_accessor
+            .zip(binding.weights_1_accessor)
+            .and_then(|(j1, w1)| Some((accessor_meta(json, j1)?, accessor_meta(json, w1)?)))
+            .filter(|(jm, wm)| valid_joint_weight_meta(jm, wm));
+
+        let count = joints_0_meta.count.min(weights_0_meta.count);
+        for vertex_index in 0..count {
+            let mut influences = read_vertex_influences(
+                bin,
+                &joints_0_meta,
+                &weights_0_meta,
+                extra_meta.as_ref().map(|(jm, wm)| (jm, wm)),
+                vertex_index,
+            );
+            if influences.len() <= 4 {
+                let sum: f32 = influences.iter().map(|&(_, w)| w).sum();
+                if (sum - 1.0).abs() <= 1e-3 {
+                    continue;
+                }
+            }
+
+            influences.sort_by(|a, b| {
+                b.1.partial_cmp(&a.1)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| a.0.cmp(&b.0))
+            });
+            influences.truncate(4);
+            let sum: f32 = influences.iter().map(|&(_, w)| w).sum();
+
+            let mut slots = [0u16; 4];
+            let mut weights = [0.0f32; 4];
+            if sum > 1e-7 {
+                for (lane, &(slot, weight)) in influences.iter().enumerate() {
+                    slots[lane] = slot as u16;
+                    weights[lane] = weight / sum;
+                }
+            } else {
+                // Degenerate case (every kept influence's weight collapsed to
+                // ~0): bind fully to the highest-index surviving influence
+                // instead of leaving the vertex unweighted.
+                slots[0] = influences.iter().map(|&(slot, _)| slot as u16).max().unwrap_or(0);
+                weights[0] = 1.0;
+            }
+
+            for (lane, &slot) in slots.iter().enumerate() {
+                write_joint_slot(bin, &joints_0_meta, vertex_index, lane, slot);
+            }
+            write_weight_lanes_f32(bin, &weights_0_meta, vertex_index, weights);
+            if let Some((joints_1_meta, weights_1_meta)) = &extra_meta {
+                for lane in 0..4 {
+                    write_joint_slot(bin, joints_1_meta, vertex_index, lane, 0);
+                }
+                write_weight_lanes_f32(bin, weights_1_meta, vertex_index, [0.0; 4]);
+            }
+        }
+    }
+}
+
 /// Remove unused joint slots from all skins, compacting the joints list and
-/// inverse bind matrix accessor in-place.
-pub(super) fn optimize_skinning_weights_and_joints(json: &mut Value, bin: &mut [u8]) -> Result<()> {
+/// inverse bind matrix accessor in-place. Primitives bound to an
+/// unprocessable `JOINTS_0`/`WEIGHTS_0` pair (wrong accessor type, an
+/// unsupported component type, ...) are left as-is rather than optimized,
+/// with a coded, located warning returned for each instead of failing
+/// silently.
+pub(super) fn optimize_skinning_weights_and_joints(
+    json: &mut Value,
+    bin: &mut [u8],
+    mut progress: Option<&mut dyn FnMut(f32)>,
+    cancellation: Option<&CancellationToken>,
+) -> Result<Vec<ValidationIssue>> {
+    let mut issues = Vec::new();
+
     let skin_count = json
         .get("skins")
         .and_then(Value::as_array)
@@ -179,6 +674,15 @@ pub(super) fn optimize_skinning_weights_and_joints(json: &mut Value, bin: &mut [
         .unwrap_or(0);
 
     for skin_index in 0..skin_count {
+        if cancellation.is_some_and(CancellationToken::is_cancelled) {
+            return Err(ConvertError::cancelled().into());
+        }
+        if let Some(progress) = progress.as_deref_mut() {
+            progress(skin_index as f32 / skin_count as f32);
+        }
+
+        clamp_and_renormalize_skin_weights(json, bin, skin_index);
+
         let bindings = collect_skin_primitive_bindings(json, skin_index);
         if bindings.is_empty() {
             continue;
@@ -199,7 +703,7 @@ pub(super) fn optimize_skinning_weights_and_joints(json: &mut Value, bin: &mut [
 
         let mut used_slots = vec![false; joints_len];
         for binding in &bindings {
-            scan_used_joint_slots(json, bin, *binding, &mut used_slots);
+            scan_used_joint_slots(json, bin, skin_index, *binding, &mut used_slots, &mut issues);
         }
 
         let mut keep_slots: Vec<usize> = used_slots
@@ -217,16 +721,25 @@ pub(super) fn optimize_skinning_weights_and_joints(json: &mut Value, bin: &mut [
         }
 
         for binding in &bindings {
-            remap_primitive_joints_and_weights(json, bin, *binding, &old_to_new);
+            remap_primitive_joints_and_weights(json, bin, skin_index, *binding, &old_to_new);
         }
 
         compact_skin_joints_and_inverse_bind_matrices(json, bin, skin_index, &keep_slots)?;
     }
 
-    Ok(())
+    strip_redundant_second_influence_set(json);
+
+    if let Some(progress) = progress.as_deref_mut() {
+        progress(1.0);
+    }
+
+    Ok(issues)
 }
 
-fn collect_skin_primitive_bindings(json: &Value, skin_index: usize) -> Vec<PrimitiveSkinBinding> {
+pub(super) fn collect_skin_primitive_bindings(
+    json: &Value,
+    skin_index: usize,
+) -> Vec<PrimitiveSkinBinding> {
     let nodes = json
         .get("nodes")
         .and_then(Value::as_array)
@@ -298,28 +811,83 @@ fn collect_skin_primitive_bindings(json: &Value, skin_index: usize) -> Vec<Primi
     bindings
 }
 
-fn scan_used_joint_slots(
+/// Validates a `JOINTS_0`/`WEIGHTS_0` accessor pair against the shapes
+/// [`scan_used_joint_slots`] and [`remap_primitive_joints_and_weights`]
+/// require, returning the resolved metadata on success or a coded, located
+/// warning describing the defect instead of leaving the caller to guess why
+/// its vertices were silently skipped.
+pub(super) fn validate_skin_accessor_pair(
     json: &Value,
-    bin: &[u8],
+    skin_index: usize,
     binding: PrimitiveSkinBinding,
-    used_slots: &mut [bool],
-) {
-    let Some(joints_meta) = accessor_meta(json, binding.joints_accessor) else {
-        return;
-    };
-    let Some(weights_meta) = accessor_meta(json, binding.weights_accessor) else {
-        return;
+) -> Result<(AccessorMeta, AccessorMeta), ValidationIssue> {
+    let location = format!(
+        "skin[{}].accessors[{}, {}]",
+        skin_index, binding.joints_accessor, binding.weights_accessor
+    );
+    let missing_accessor_issue = |which: &str, accessor_index: usize| ValidationIssue {
+        severity: Severity::Warning,
+        code: "SKIN_ACCESSOR_MISSING".to_string(),
+        message: format!(
+            "⚠️ {} accessor {} referenced by {} does not exist; its vertices were left unoptimized",
+            which, accessor_index, location
+        ),
     };
+
+    let joints_meta = accessor_meta(json, binding.joints_accessor)
+        .ok_or_else(|| missing_accessor_issue("JOINTS_0", binding.joints_accessor))?;
+    let weights_meta = accessor_meta(json, binding.weights_accessor)
+        .ok_or_else(|| missing_accessor_issue("WEIGHTS_0", binding.weights_accessor))?;
     if joints_meta.accessor_type != "VEC4" || weights_meta.accessor_type != "VEC4" {
-        return;
+        return Err(ValidationIssue {
+            severity: Severity::Warning,
+            code: "SKIN_UNSUPPORTED_ACCESSOR_TYPE".to_string(),
+            message: format!(
+                "⚠️ {} has a JOINTS_0/WEIGHTS_0 accessor that isn't VEC4; its vertices were left unoptimized",
+                location
+            ),
+        });
     }
     if !(joints_meta.component_type == 5121 || joints_meta.component_type == 5123) {
-        return;
+        return Err(ValidationIssue {
+            severity: Severity::Warning,
+            code: "SKIN_UNSUPPORTED_JOINT_COMPONENT_TYPE".to_string(),
+            message: format!(
+                "⚠️ {} has a JOINTS_0 component type other than unsigned byte/short; its vertices were left unoptimized",
+                location
+            ),
+        });
     }
-    if weights_meta.component_type != 5126 {
-        return;
+    if !is_weight_component_type(weights_meta.component_type) {
+        return Err(ValidationIssue {
+            severity: Severity::Warning,
+            code: "SKIN_UNSUPPORTED_WEIGHT_COMPONENT_TYPE".to_string(),
+            message: format!(
+                "⚠️ {} has a WEIGHTS_0 component type this converter can't read; its vertices were left unoptimized",
+                location
+            ),
+        });
     }
 
+    Ok((joints_meta, weights_meta))
+}
+
+fn scan_used_joint_slots(
+    json: &Value,
+    bin: &[u8],
+    skin_index: usize,
+    binding: PrimitiveSkinBinding,
+    used_slots: &mut [bool],
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let (joints_meta, weights_meta) = match validate_skin_accessor_pair(json, skin_index, binding) {
+        Ok(metas) => metas,
+        Err(issue) => {
+            issues.push(issue);
+            return;
+        }
+    };
+
     let count = joints_meta.count.min(weights_meta.count);
     for vertex_index in 0..count {
         for lane in 0..4 {
@@ -335,27 +903,21 @@ fn scan_used_joint_slots(
     }
 }
 
+/// Mirrors [`scan_used_joint_slots`]'s accessor-pair validation but doesn't
+/// re-report it: any binding that fails here was already warned about during
+/// the scan pass over the same bindings, moments earlier in
+/// [`optimize_skinning_weights_and_joints`].
 fn remap_primitive_joints_and_weights(
     json: &Value,
     bin: &mut [u8],
+    skin_index: usize,
     binding: PrimitiveSkinBinding,
     old_to_new: &[Option<u16>],
 ) {
-    let Some(joints_meta) = accessor_meta(json, binding.joints_accessor) else {
-        return;
-    };
-    let Some(weights_meta) = accessor_meta(json, binding.weights_accessor) else {
+    let Ok((joints_meta, weights_meta)) = validate_skin_accessor_pair(json, skin_index, binding)
+    else {
         return;
     };
-    if joints_meta.accessor_type != "VEC4" || weights_meta.accessor_type != "VEC4" {
-        return;
-    }
-    if !(joints_meta.component_type == 5121 || joints_meta.component_type == 5123) {
-        return;
-    }
-    if weights_meta.component_type != 5126 {
-        return;
-    }
 
     let fallback = old_to_new.iter().flatten().copied().next().unwrap_or(0u16);
 
@@ -389,10 +951,10 @@ fn remap_primitive_joints_and_weights(
             weights = [1.0, 0.0, 0.0, 0.0];
         }
 
-        for lane in 0..4 {
-            write_joint_slot(bin, &joints_meta, vertex_index, lane, slots[lane]);
-            write_weight_f32(bin, &weights_meta, vertex_index, lane, weights[lane]);
+        for (lane, &slot) in slots.iter().enumerate() {
+            write_joint_slot(bin, &joints_meta, vertex_index, lane, slot);
         }
+        write_weight_lanes_f32(bin, &weights_meta, vertex_index, weights);
     }
 }
 
@@ -456,6 +1018,33 @@ fn compact_inverse_bind_accessor(
 
     let old_count = meta.count;
     let stride = meta.stride.max(64);
+
+    // A sparse IBM accessor patches specific matrices out-of-band; materialize
+    // those overrides into the dense buffer first so the reorder below always
+    // reads fully-resolved matrices, not whatever placeholder sits in the
+    // dense base at a slot the sparse block was meant to replace.
+    if let Some(sparse) = &meta.sparse {
+        for entry in 0..sparse.count {
+            let Some(slot) = read_sparse_index(bin, sparse, entry) else {
+                continue;
+            };
+            if slot >= old_count {
+                continue;
+            }
+            let value_offset = sparse.values_base_offset + entry * sparse.values_stride;
+            if value_offset + 64 > bin.len() {
+                continue;
+            }
+            let mut bytes = [0u8; 64];
+            bytes.copy_from_slice(&bin[value_offset..value_offset + 64]);
+            let dest_offset = meta.base_offset + slot * stride;
+            if dest_offset + 64 > bin.len() {
+                continue;
+            }
+            bin[dest_offset..dest_offset + 64].copy_from_slice(&bytes);
+        }
+    }
+
     let mut matrices = Vec::<[u8; 64]>::new();
 
     for slot in keep_slots.iter().copied() {
@@ -486,6 +1075,424 @@ fn compact_inverse_bind_accessor(
         return Ok(());
     };
     accessor["count"] = Value::from(matrices.len() as u64);
+    // The dense buffer above is now fully resolved; drop the sparse override
+    // block so the shrunk accessor doesn't reference stale indices/values.
+    if let Some(obj) = accessor.as_object_mut() {
+        obj.remove("sparse");
+    }
 
     Ok(())
 }
+
+// ─── Pre-upload skin-deformation self-test ───────────────────────────────────
+
+/// Default multiple of a vertex's dominant bone's length its displacement
+/// may exceed the weighted-rigid expectation by before
+/// [`simulate_pose_deformation`] flags it as a suspected skin explosion.
+pub(super) const DEFAULT_SKIN_EXPLOSION_TOLERANCE_MULTIPLIER: f32 = 3.0;
+
+/// A test angle well beyond any real animation pose, chosen to make a
+/// mismatched inverse bind matrix or stray heavy weight obvious without
+/// being so extreme that ordinary gimbal-adjacent rounding error trips it.
+const DEFORMATION_TEST_ANGLE_DEGREES: f32 = 45.0;
+
+/// A vertex's displacement is considered an outright `Error` rather than a
+/// `Warning` once it clears this multiple of the configured tolerance.
+const DEFORMATION_ERROR_RATIO: f32 = 2.0;
+
+/// A bone too short to meaningfully scale the displacement tolerance by
+/// (e.g. a collision-volume bone coincident with its parent) falls back to
+/// this length instead of letting a near-zero denominator flag everything.
+const DEFORMATION_FALLBACK_BONE_LENGTH: f32 = 5.0;
+
+/// One canned stress pose for [`simulate_pose_deformation`]: rotate
+/// `sl_joint_name` by a fixed angle around the X axis of its parent's space
+/// (the delta is applied on top of the joint's local rotation). The axis is
+/// arbitrary — the goal is to stress every vertex's skin weights and inverse
+/// bind matrix under *some* non-trivial pose, not to reproduce a realistic
+/// animation.
+struct DeformationTestPose {
+    sl_joint_name: &'static str,
+}
+
+/// SL joints exercised by [`simulate_pose_deformation`]: the limb/neck
+/// joints most likely to expose a mismatched inverse bind matrix or a stray
+/// heavy weight left on the wrong bone after conversion.
+fn skin_explosion_test_poses() -> [DeformationTestPose; 5] {
+    [
+        DeformationTestPose { sl_joint_name: "mElbowLeft" },
+        DeformationTestPose { sl_joint_name: "mElbowRight" },
+        DeformationTestPose { sl_joint_name: "mKneeLeft" },
+        DeformationTestPose { sl_joint_name: "mKneeRight" },
+        DeformationTestPose { sl_joint_name: "mNeck" },
+    ]
+}
+
+/// One mesh primitive's attributes needed to linear-blend-skin its vertices
+/// for [`simulate_pose_deformation`], gathered per skin the same way
+/// [`collect_skin_primitive_bindings`] does but also keeping `POSITION` and
+/// the owning mesh's name for reporting.
+struct DeformationTestPrimitive {
+    mesh_name: String,
+    position_accessor: usize,
+    primary: PrimitiveSkinBinding,
+    joints_1_accessor: Option<usize>,
+    weights_1_accessor: Option<usize>,
+}
+
+fn collect_deformation_test_primitives(
+    json: &Value,
+    skin_index: usize,
+) -> Vec<DeformationTestPrimitive> {
+    let nodes = json
+        .get("nodes")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let meshes = json
+        .get("meshes")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut primitives_out = Vec::new();
+    for node in nodes {
+        let Some(node_skin_index) = node.get("skin").and_then(Value::as_u64).map(|v| v as usize)
+        else {
+            continue;
+        };
+        if node_skin_index != skin_index {
+            continue;
+        }
+
+        let Some(mesh_index) = node.get("mesh").and_then(Value::as_u64).map(|v| v as usize) else {
+            continue;
+        };
+        let Some(mesh) = meshes.get(mesh_index) else {
+            continue;
+        };
+        let mesh_name = mesh
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or("unnamed_mesh")
+            .to_string();
+        let Some(primitives) = mesh.get("primitives").and_then(Value::as_array) else {
+            continue;
+        };
+
+        for primitive in primitives {
+            let Some(attributes) = primitive.get("attributes").and_then(Value::as_object) else {
+                continue;
+            };
+            let (Some(position_accessor), Some(joints_accessor), Some(weights_accessor)) = (
+                attributes
+                    .get("POSITION")
+                    .and_then(Value::as_u64)
+                    .map(|v| v as usize),
+                attributes
+                    .get("JOINTS_0")
+                    .and_then(Value::as_u64)
+                    .map(|v| v as usize),
+                attributes
+                    .get("WEIGHTS_0")
+                    .and_then(Value::as_u64)
+                    .map(|v| v as usize),
+            ) else {
+                continue;
+            };
+
+            primitives_out.push(DeformationTestPrimitive {
+                mesh_name: mesh_name.clone(),
+                position_accessor,
+                primary: PrimitiveSkinBinding {
+                    joints_accessor,
+                    weights_accessor,
+                },
+                joints_1_accessor: attributes
+                    .get("JOINTS_1")
+                    .and_then(Value::as_u64)
+                    .map(|v| v as usize),
+                weights_1_accessor: attributes
+                    .get("WEIGHTS_1")
+                    .and_then(Value::as_u64)
+                    .map(|v| v as usize),
+            });
+        }
+    }
+    primitives_out
+}
+
+/// Distance from `joints[slot]`'s world-space origin to its nearest ancestor
+/// that's also a joint of this skin, both read from `old_worlds` (indexed
+/// the same way as `joints`). Falls back to
+/// [`DEFORMATION_FALLBACK_BONE_LENGTH`] for a joint with no in-skin ancestor
+/// (e.g. the skeleton root) or one coincident with it.
+fn bone_length_for_slot(
+    slot: usize,
+    joints: &[usize],
+    node_to_slot: &HashMap<usize, usize>,
+    parent_of: &HashMap<usize, usize>,
+    old_worlds: &[Matrix4<f32>],
+) -> f32 {
+    let world = old_worlds[slot];
+    let origin = Vector3::new(world[(0, 3)], world[(1, 3)], world[(2, 3)]);
+    let mut cur = parent_of.get(&joints[slot]).copied();
+    while let Some(parent_node) = cur {
+        if let Some(&parent_slot) = node_to_slot.get(&parent_node) {
+            let parent_world = old_worlds[parent_slot];
+            let parent_origin = Vector3::new(
+                parent_world[(0, 3)],
+                parent_world[(1, 3)],
+                parent_world[(2, 3)],
+            );
+            let length = (origin - parent_origin).norm();
+            return if length > 1e-4 {
+                length
+            } else {
+                DEFORMATION_FALLBACK_BONE_LENGTH
+            };
+        }
+        cur = parent_of.get(&parent_node).copied();
+    }
+    DEFORMATION_FALLBACK_BONE_LENGTH
+}
+
+/// Linear-blend-skin `rest_position` under `worlds` (indexed the same way as
+/// `joints`/`inverse_binds`) with `influences`' per-slot weights.
+fn skin_vertex(
+    rest_position: Vector3<f32>,
+    influences: &[(usize, f32)],
+    worlds: &[Matrix4<f32>],
+    inverse_binds: &[Matrix4<f32>],
+) -> Vector3<f32> {
+    let mut accumulated = Vector3::zeros();
+    for &(slot, weight) in influences {
+        let (Some(&world), Some(&inverse_bind)) = (worlds.get(slot), inverse_binds.get(slot))
+        else {
+            continue;
+        };
+        let skinned = (world * inverse_bind).transform_point(&Point3::from(rest_position));
+        accumulated += weight * skinned.coords;
+    }
+    accumulated
+}
+
+/// Pre-upload self-test catching skin-deformation "explosions": a mismatched
+/// inverse bind matrix, a stray heavy weight left on the wrong joint, or
+/// un-normalized weights all look fine at the bind pose but throw a vertex
+/// far from where it belongs the moment the avatar actually moves.
+///
+/// For each [`skin_explosion_test_poses`] canned pose, rotates the named SL
+/// joint by [`DEFORMATION_TEST_ANGLE_DEGREES`] and linear-blend-skins every
+/// vertex it influences, once at the original bind pose and once under the
+/// rotated pose. A vertex is flagged when its actual displacement diverges
+/// from the weighted sum of its influencing bones' own world-space
+/// translation — what rigid, correctly-weighted skinning would produce — by
+/// more than `displacement_tolerance_multiplier` times its dominant bone's
+/// length.
+///
+/// Must run after [`super::skeleton::regenerate_inverse_bind_matrices`],
+/// since a stale inverse bind matrix would otherwise make every pose look
+/// explosive.
+pub(super) fn simulate_pose_deformation(
+    json: &Value,
+    bin: &[u8],
+    displacement_tolerance_multiplier: f32,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let skin_count = json["skins"].as_array().map(|s| s.len()).unwrap_or(0);
+    if skin_count == 0 {
+        return issues;
+    }
+
+    let rotation_delta = UnitQuaternion::from_axis_angle(
+        &Vector3::x_axis(),
+        DEFORMATION_TEST_ANGLE_DEGREES.to_radians(),
+    );
+
+    for pose in skin_explosion_test_poses() {
+        let Some(joint_index) = node_index_by_name(json, pose.sl_joint_name) else {
+            continue;
+        };
+
+        for skin_index in 0..skin_count {
+            let joints: Vec<usize> = json["skins"][skin_index]["joints"]
+                .as_array()
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_u64().map(|n| n as usize))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let Some(rotated_slot) = joints.iter().position(|&node| node == joint_index) else {
+                continue;
+            };
+
+            let Some(ibm_accessor_index) = json["skins"][skin_index]["inverseBindMatrices"]
+                .as_u64()
+                .map(|v| v as usize)
+            else {
+                continue;
+            };
+            let Some(ibm_meta) = accessor_meta(json, ibm_accessor_index) else {
+                continue;
+            };
+            let inverse_binds: Vec<Matrix4<f32>> = (0..joints.len())
+                .map(|slot| read_mat4_from_accessor(bin, &ibm_meta, slot).unwrap_or_else(Matrix4::identity))
+                .collect();
+
+            let mut original_pose = SkeletonPose::from_json(json);
+            let old_worlds: Vec<Matrix4<f32>> = joints
+                .iter()
+                .map(|&node_idx| original_pose.world(node_idx).unwrap_or_else(Matrix4::identity))
+                .collect();
+            let node_to_slot: HashMap<usize, usize> = joints
+                .iter()
+                .enumerate()
+                .map(|(slot, &node)| (node, slot))
+                .collect();
+            let parent_of = original_pose.parent_map().clone();
+
+            let mut rotated_pose = SkeletonPose::from_json(json);
+            let Some(local) = rotated_pose.local(joint_index) else {
+                continue;
+            };
+            let (translation, rotation, scale) = decompose_trs(&local);
+            rotated_pose.set_local(
+                joint_index,
+                compose_trs(translation, rotation_delta * rotation, scale),
+            );
+            let new_worlds: Vec<Matrix4<f32>> = joints
+                .iter()
+                .map(|&node_idx| rotated_pose.world(node_idx).unwrap_or_else(Matrix4::identity))
+                .collect();
+
+            let mut flagged_vertex_count = 0usize;
+            let mut worst_ratio = 0.0f32;
+            let mut worst_excess = 0.0f32;
+            let mut worst_mesh = String::new();
+
+            for primitive in collect_deformation_test_primitives(json, skin_index) {
+                let Some(position_meta) = accessor_meta(json, primitive.position_accessor) else {
+                    continue;
+                };
+                let Some(joints_0_meta) = accessor_meta(json, primitive.primary.joints_accessor)
+                else {
+                    continue;
+                };
+                let Some(weights_0_meta) = accessor_meta(json, primitive.primary.weights_accessor)
+                else {
+                    continue;
+                };
+                if !valid_joint_weight_meta(&joints_0_meta, &weights_0_meta) {
+                    continue;
+                }
+                let extra_meta = primitive
+                    .joints_1_accessor
+                    .zip(primitive.weights_1_accessor)
+                    .and_then(|(j1, w1)| Some((accessor_meta(json, j1)?, accessor_meta(json, w1)?)))
+                    .filter(|(jm, wm)| valid_joint_weight_meta(jm, wm));
+
+                let count = position_meta
+                    .count
+                    .min(joints_0_meta.count)
+                    .min(weights_0_meta.count);
+                for vertex_index in 0..count {
+                    let influences = read_vertex_influences(
+                        bin,
+                        &joints_0_meta,
+                        &weights_0_meta,
+                        extra_meta.as_ref().map(|(jm, wm)| (jm, wm)),
+                        vertex_index,
+                    );
+                    let weight_on_rotated_joint = influences
+                        .iter()
+                        .find(|&&(slot, _)| slot == rotated_slot)
+                        .map_or(0.0, |&(_, weight)| weight);
+                    if weight_on_rotated_joint <= 1e-6 {
+                        continue;
+                    }
+                    let Some(rest_position) = read_f32_element(bin, &position_meta, vertex_index)
+                    else {
+                        continue;
+                    };
+                    if rest_position.len() != 3 {
+                        continue;
+                    }
+                    let rest_position =
+                        Vector3::new(rest_position[0], rest_position[1], rest_position[2]);
+
+                    let Some(&(dominant_slot, _)) = influences
+                        .iter()
+                        .max_by(|a, b| a.1.total_cmp(&b.1))
+                    else {
+                        continue;
+                    };
+
+                    let mut expected_translation = Vector3::zeros();
+                    for &(slot, weight) in &influences {
+                        let (Some(&old_world), Some(&new_world)) =
+                            (old_worlds.get(slot), new_worlds.get(slot))
+                        else {
+                            continue;
+                        };
+                        let old_translation =
+                            Vector3::new(old_world[(0, 3)], old_world[(1, 3)], old_world[(2, 3)]);
+                        let new_translation =
+                            Vector3::new(new_world[(0, 3)], new_world[(1, 3)], new_world[(2, 3)]);
+                        expected_translation += weight * (new_translation - old_translation);
+                    }
+
+                    let deformed_old = skin_vertex(rest_position, &influences, &old_worlds, &inverse_binds);
+                    let deformed_new = skin_vertex(rest_position, &influences, &new_worlds, &inverse_binds);
+                    let actual_displacement = deformed_new - deformed_old;
+                    let excess = (actual_displacement - expected_translation).norm();
+
+                    let bone_length = bone_length_for_slot(
+                        dominant_slot,
+                        &joints,
+                        &node_to_slot,
+                        &parent_of,
+                        &old_worlds,
+                    );
+                    let threshold = displacement_tolerance_multiplier * bone_length;
+                    if excess <= threshold {
+                        continue;
+                    }
+
+                    flagged_vertex_count += 1;
+                    let ratio = excess / threshold;
+                    if ratio > worst_ratio {
+                        worst_ratio = ratio;
+                        worst_excess = excess;
+                        worst_mesh = primitive.mesh_name.clone();
+                    }
+                }
+            }
+
+            if flagged_vertex_count > 0 {
+                let severity = if worst_ratio > DEFORMATION_ERROR_RATIO {
+                    Severity::Error
+                } else {
+                    Severity::Warning
+                };
+                issues.push(ValidationIssue {
+                    severity,
+                    code: "SKIN_EXPLOSION_SUSPECTED".to_string(),
+                    message: format!(
+                        "⚠️ Rotating '{}' by {:.0}° displaced {} vertex/vertices in skin {} beyond {:.1}x their dominant bone's length — worst offender in mesh '{}' ({:.3} units over tolerance). Check for a mismatched inverse bind matrix or a stray heavy weight before uploading",
+                        pose.sl_joint_name,
+                        DEFORMATION_TEST_ANGLE_DEGREES,
+                        flagged_vertex_count,
+                        skin_index,
+                        displacement_tolerance_multiplier,
+                        worst_mesh,
+                        worst_excess
+                    ),
+                });
+            }
+        }
+    }
+
+    issues
+}
@@ -0,0 +1,554 @@
+use std::collections::{HashMap, HashSet};
+use std::io::Cursor;
+
+use anyhow::{Context, Result};
+use image::{DynamicImage, ImageFormat, RgbaImage, imageops};
+use serde_json::Value;
+
+use super::gltf_utils::{accessor_meta, read_f32_element, write_f32_element};
+
+/// Side length (in pixels) of every atlas sheet this pass produces.
+pub(super) const SHEET_SIZE: u32 = 1024;
+
+/// glTF sampler `wrapS`/`wrapT` constant for `CLAMP_TO_EDGE`. The glTF
+/// default, `REPEAT` (`10497`), would bleed a texture's edge pixels into its
+/// neighbor's region once packed, so only `CLAMP_TO_EDGE` textures qualify.
+const CLAMP_TO_EDGE: u64 = 33071;
+
+/// Outcome of one [`apply_texture_atlas`] pass, fed back into
+/// [`super::validation::estimate_texture_fee`] so the reported upload fee
+/// reflects the real post-atlas texture count rather than the pre-atlas
+/// per-texture projection.
+#[derive(Debug, Clone, Default)]
+pub(super) struct TextureAtlasStats {
+    /// Atlas sheets written (each a `SHEET_SIZE`x`SHEET_SIZE` PNG).
+    pub(super) sheet_count: usize,
+    /// Original `baseColorTexture` indices folded into a sheet.
+    pub(super) atlased_texture_indices: HashSet<usize>,
+    /// `baseColorTexture` users that were left alone because they failed an
+    /// eligibility check (tiling sampler, out-of-range UVs, shared
+    /// `TEXCOORD_0` accessor, or simply too large to fit a sheet).
+    pub(super) skipped_texture_count: usize,
+}
+
+struct EligibleTexture {
+    texture_index: usize,
+    image: DynamicImage,
+}
+
+struct Shelf {
+    y: u32,
+    height: u32,
+    x_cursor: u32,
+}
+
+struct SheetLayout {
+    shelves: Vec<Shelf>,
+    y_cursor: u32,
+}
+
+struct Placement {
+    texture_index: usize,
+    sheet_index: usize,
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+/// Bin-pack every eligible `baseColorTexture` source image into one or more
+/// [`SHEET_SIZE`]x[`SHEET_SIZE`] atlas sheets, rewriting the owning
+/// materials' `baseColorTexture.index` and remapping the affected
+/// primitives' `TEXCOORD_0` UVs to the atlas coordinate space.
+///
+/// Only `baseColorTexture` is atlased (the dominant upload-fee driver and
+/// the only slot VRM's typical unlit/MToon materials populate); normal/
+/// metallic-roughness/emissive/occlusion textures are never themselves
+/// atlased. But `remap_texcoord_accessor` rewrites a shared `TEXCOORD_0`
+/// accessor's UVs in place, so a material that reuses its base color UV set
+/// for one of those other channels would have that channel's sampling
+/// corrupted against its own, still un-atlased, image — a texture is skipped
+/// (not atlased, not mutated) rather than risking that whenever:
+/// - its sampler isn't `CLAMP_TO_EDGE` on both axes (`REPEAT`/`MIRRORED`
+///   tiling would sample across a neighbor's packed region),
+/// - any of its UVs fall outside `[0, 1]`,
+/// - its `TEXCOORD_0` accessor is also used by a primitive with a
+///   *different* base color texture, or by the *same* material's
+///   normal/metallic-roughness/emissive/occlusion channel, or
+/// - it doesn't fit within one sheet on its own.
+pub(super) fn apply_texture_atlas(json: &mut Value, bin: &mut Vec<u8>) -> Result<TextureAtlasStats> {
+    let texture_accessors = collect_base_color_texture_users(json);
+    if texture_accessors.is_empty() {
+        return Ok(TextureAtlasStats::default());
+    }
+
+    let accessor_texture_counts = accessor_texture_counts(&texture_accessors);
+    let other_channel_accessors = collect_other_channel_accessors(json);
+
+    let mut eligible = Vec::<EligibleTexture>::new();
+    let mut skipped_texture_count = 0usize;
+
+    'textures: for (&texture_index, accessors) in &texture_accessors {
+        let shares_accessor_with_another_texture = accessors.iter().any(|accessor_index| {
+            accessor_texture_counts
+                .get(accessor_index)
+                .is_some_and(|textures| textures.len() > 1)
+                || other_channel_accessors.contains(accessor_index)
+        });
+        if shares_accessor_with_another_texture || !texture_is_clamp_to_edge(json, texture_index) {
+            skipped_texture_count += 1;
+            continue;
+        }
+        for &accessor_index in accessors {
+            if !accessor_uvs_within_unit_square(json, bin, accessor_index) {
+                skipped_texture_count += 1;
+                continue 'textures;
+            }
+        }
+
+        match decode_texture_image(json, bin, texture_index) {
+            Some(image) if image.width() <= SHEET_SIZE && image.height() <= SHEET_SIZE => {
+                eligible.push(EligibleTexture { texture_index, image });
+            }
+            _ => skipped_texture_count += 1,
+        }
+    }
+
+    if eligible.is_empty() {
+        return Ok(TextureAtlasStats {
+            sheet_count: 0,
+            atlased_texture_indices: HashSet::new(),
+            skipped_texture_count,
+        });
+    }
+
+    // Pack largest-first: a shelf packer's waste comes almost entirely from
+    // small items squeezed in after a shelf's height is already fixed by a
+    // big one, so placing the big ones first minimizes it.
+    eligible.sort_by_key(|texture| std::cmp::Reverse(texture.image.width().max(texture.image.height())));
+
+    let placements = pack_shelves(&eligible);
+    let sheet_count = placements
+        .iter()
+        .map(|placement| placement.sheet_index)
+        .max()
+        .map_or(0, |max_index| max_index + 1);
+
+    let mut sheets = vec![RgbaImage::new(SHEET_SIZE, SHEET_SIZE); sheet_count];
+    let images_by_index: HashMap<usize, &DynamicImage> = eligible
+        .iter()
+        .map(|texture| (texture.texture_index, &texture.image))
+        .collect();
+    for placement in &placements {
+        let source = images_by_index[&placement.texture_index].to_rgba8();
+        imageops::replace(
+            &mut sheets[placement.sheet_index],
+            &source,
+            placement.x as i64,
+            placement.y as i64,
+        );
+    }
+
+    let sampler_index = append_clamp_to_edge_sampler(json);
+    let mut atlas_texture_indices = Vec::with_capacity(sheet_count);
+    for sheet in &sheets {
+        let mut encoded = Vec::<u8>::new();
+        DynamicImage::ImageRgba8(sheet.clone())
+            .write_to(&mut Cursor::new(&mut encoded), ImageFormat::Png)
+            .context("failed to encode atlas sheet as PNG")?;
+        let view_index = append_buffer_view(bin, json, encoded);
+        let image_index = append_image(json, view_index);
+        atlas_texture_indices.push(append_texture(json, image_index, sampler_index));
+    }
+
+    let mut atlased_texture_indices = HashSet::with_capacity(placements.len());
+    for placement in &placements {
+        atlased_texture_indices.insert(placement.texture_index);
+        remap_base_color_texture(json, placement.texture_index, atlas_texture_indices[placement.sheet_index]);
+        for &accessor_index in &texture_accessors[&placement.texture_index] {
+            remap_texcoord_accessor(json, bin, accessor_index, placement);
+        }
+    }
+
+    Ok(TextureAtlasStats {
+        sheet_count,
+        atlased_texture_indices,
+        skipped_texture_count,
+    })
+}
+
+/// Map every `baseColorTexture` index to the `TEXCOORD_0` accessors used to
+/// sample it, across every primitive whose material references it.
+fn collect_base_color_texture_users(json: &Value) -> HashMap<usize, Vec<usize>> {
+    let mut material_texture = HashMap::<usize, usize>::new();
+    if let Some(materials) = json.get("materials").and_then(Value::as_array) {
+        for (material_index, material) in materials.iter().enumerate() {
+            if let Some(texture_index) = material
+                .pointer("/pbrMetallicRoughness/baseColorTexture/index")
+                .and_then(Value::as_u64)
+            {
+                material_texture.insert(material_index, texture_index as usize);
+            }
+        }
+    }
+
+    let mut texture_accessors = HashMap::<usize, Vec<usize>>::new();
+    let Some(meshes) = json.get("meshes").and_then(Value::as_array) else {
+        return texture_accessors;
+    };
+    for mesh in meshes {
+        let Some(primitives) = mesh.get("primitives").and_then(Value::as_array) else {
+            continue;
+        };
+        for primitive in primitives {
+            let Some(material_index) = primitive.get("material").and_then(Value::as_u64) else {
+                continue;
+            };
+            let Some(&texture_index) = material_texture.get(&(material_index as usize)) else {
+                continue;
+            };
+            let Some(accessor_index) = primitive
+                .pointer("/attributes/TEXCOORD_0")
+                .and_then(Value::as_u64)
+            else {
+                continue;
+            };
+            texture_accessors
+                .entry(texture_index)
+                .or_default()
+                .push(accessor_index as usize);
+        }
+    }
+    texture_accessors
+}
+
+/// Reverse `texture_accessors`: which base color textures (if more than one)
+/// share a given `TEXCOORD_0` accessor.
+fn accessor_texture_counts(texture_accessors: &HashMap<usize, Vec<usize>>) -> HashMap<usize, HashSet<usize>> {
+    let mut counts = HashMap::<usize, HashSet<usize>>::new();
+    for (&texture_index, accessors) in texture_accessors {
+        for &accessor_index in accessors {
+            counts.entry(accessor_index).or_default().insert(texture_index);
+        }
+    }
+    counts
+}
+
+/// `TEXCOORD_0` accessors touched by any material's `normalTexture`,
+/// `metallicRoughnessTexture`, `emissiveTexture`, or `occlusionTexture`. A
+/// base color texture sharing one of these accessors must be treated as
+/// ineligible even when no *other* base color texture uses it, since
+/// [`remap_texcoord_accessor`] mutates the accessor in place and these other
+/// channels are never re-pointed at an atlas sheet.
+fn collect_other_channel_accessors(json: &Value) -> HashSet<usize> {
+    const OTHER_CHANNEL_POINTERS: [&str; 4] = [
+        "/normalTexture/index",
+        "/pbrMetallicRoughness/metallicRoughnessTexture/index",
+        "/emissiveTexture/index",
+        "/occlusionTexture/index",
+    ];
+
+    let mut other_channel_materials = HashSet::<usize>::new();
+    if let Some(materials) = json.get("materials").and_then(Value::as_array) {
+        for (material_index, material) in materials.iter().enumerate() {
+            let has_other_channel = OTHER_CHANNEL_POINTERS
+                .iter()
+                .any(|pointer| material.pointer(pointer).is_some());
+            if has_other_channel {
+                other_channel_materials.insert(material_index);
+            }
+        }
+    }
+
+    let mut accessors = HashSet::<usize>::new();
+    let Some(meshes) = json.get("meshes").and_then(Value::as_array) else {
+        return accessors;
+    };
+    for mesh in meshes {
+        let Some(primitives) = mesh.get("primitives").and_then(Value::as_array) else {
+            continue;
+        };
+        for primitive in primitives {
+            let Some(material_index) = primitive.get("material").and_then(Value::as_u64) else {
+                continue;
+            };
+            if !other_channel_materials.contains(&(material_index as usize)) {
+                continue;
+            }
+            if let Some(accessor_index) = primitive
+                .pointer("/attributes/TEXCOORD_0")
+                .and_then(Value::as_u64)
+            {
+                accessors.insert(accessor_index as usize);
+            }
+        }
+    }
+    accessors
+}
+
+fn texture_is_clamp_to_edge(json: &Value, texture_index: usize) -> bool {
+    let Some(sampler_index) = json
+        .get("textures")
+        .and_then(Value::as_array)
+        .and_then(|textures| textures.get(texture_index))
+        .and_then(|texture| texture.get("sampler"))
+        .and_then(Value::as_u64)
+    else {
+        // No sampler means glTF's default wrap mode, REPEAT.
+        return false;
+    };
+    let Some(sampler) = json
+        .get("samplers")
+        .and_then(Value::as_array)
+        .and_then(|samplers| samplers.get(sampler_index as usize))
+    else {
+        return false;
+    };
+    let wrap_s = sampler.get("wrapS").and_then(Value::as_u64).unwrap_or(10497);
+    let wrap_t = sampler.get("wrapT").and_then(Value::as_u64).unwrap_or(10497);
+    wrap_s == CLAMP_TO_EDGE && wrap_t == CLAMP_TO_EDGE
+}
+
+fn accessor_uvs_within_unit_square(json: &Value, bin: &[u8], accessor_index: usize) -> bool {
+    const EPS: f32 = 1e-4;
+    let Some(meta) = accessor_meta(json, accessor_index) else {
+        return false;
+    };
+    if meta.accessor_type != "VEC2" {
+        return false;
+    }
+    (0..meta.count).all(|index| match read_f32_element(bin, &meta, index) {
+        Some(uv) => uv[0] >= -EPS && uv[0] <= 1.0 + EPS && uv[1] >= -EPS && uv[1] <= 1.0 + EPS,
+        None => false,
+    })
+}
+
+fn decode_texture_image(json: &Value, bin: &[u8], texture_index: usize) -> Option<DynamicImage> {
+    let image_index = json
+        .get("textures")?
+        .as_array()?
+        .get(texture_index)?
+        .get("source")?
+        .as_u64()? as usize;
+    let image = json.get("images")?.as_array()?.get(image_index)?;
+    let view_index = image.get("bufferView")?.as_u64()? as usize;
+    let view = json.get("bufferViews")?.as_array()?.get(view_index)?;
+    let offset = view.get("byteOffset").and_then(Value::as_u64).unwrap_or(0) as usize;
+    let length = view.get("byteLength").and_then(Value::as_u64)? as usize;
+    let bytes = bin.get(offset..offset.saturating_add(length))?;
+    image::load_from_memory(bytes).ok()
+}
+
+/// Shelf-pack `textures` (already sorted largest-first) into
+/// [`SHEET_SIZE`]x[`SHEET_SIZE`] sheets, opening a new sheet whenever none of
+/// the existing ones have room.
+fn pack_shelves(textures: &[EligibleTexture]) -> Vec<Placement> {
+    let mut sheets = Vec::<SheetLayout>::new();
+    let mut placements = Vec::with_capacity(textures.len());
+
+    for texture in textures {
+        let (w, h) = (texture.image.width(), texture.image.height());
+        let mut placed = false;
+
+        'sheets: for (sheet_index, sheet) in sheets.iter_mut().enumerate() {
+            for shelf in &mut sheet.shelves {
+                if shelf.height >= h && SHEET_SIZE - shelf.x_cursor >= w {
+                    placements.push(Placement {
+                        texture_index: texture.texture_index,
+                        sheet_index,
+                        x: shelf.x_cursor,
+                        y: shelf.y,
+                        w,
+                        h,
+                    });
+                    shelf.x_cursor += w;
+                    placed = true;
+                    break 'sheets;
+                }
+            }
+            if SHEET_SIZE - sheet.y_cursor >= h {
+                let shelf_y = sheet.y_cursor;
+                sheet.shelves.push(Shelf { y: shelf_y, height: h, x_cursor: w });
+                sheet.y_cursor += h;
+                placements.push(Placement {
+                    texture_index: texture.texture_index,
+                    sheet_index,
+                    x: 0,
+                    y: shelf_y,
+                    w,
+                    h,
+                });
+                placed = true;
+                break;
+            }
+        }
+
+        if !placed {
+            let sheet_index = sheets.len();
+            sheets.push(SheetLayout {
+                shelves: vec![Shelf { y: 0, height: h, x_cursor: w }],
+                y_cursor: h,
+            });
+            placements.push(Placement { texture_index: texture.texture_index, sheet_index, x: 0, y: 0, w, h });
+        }
+    }
+
+    placements
+}
+
+fn append_buffer_view(bin: &mut Vec<u8>, json: &mut Value, bytes: Vec<u8>) -> usize {
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+    let offset = bin.len();
+    let length = bytes.len();
+    bin.extend(bytes);
+
+    if let Some(buffers) = json.get_mut("buffers").and_then(Value::as_array_mut) {
+        if let Some(first_buffer) = buffers.first_mut() {
+            first_buffer["byteLength"] = Value::from(bin.len() as u64);
+        }
+    }
+
+    let buffer_views = json
+        .get_mut("bufferViews")
+        .and_then(Value::as_array_mut)
+        .expect("atlased glTF has a bufferViews array");
+    let view_index = buffer_views.len();
+    buffer_views.push(serde_json::json!({
+        "buffer": 0,
+        "byteOffset": offset,
+        "byteLength": length,
+    }));
+    view_index
+}
+
+fn append_image(json: &mut Value, buffer_view_index: usize) -> usize {
+    let images = json
+        .get_mut("images")
+        .and_then(Value::as_array_mut)
+        .expect("atlased glTF has an images array");
+    let index = images.len();
+    images.push(serde_json::json!({
+        "mimeType": "image/png",
+        "bufferView": buffer_view_index,
+    }));
+    index
+}
+
+fn append_clamp_to_edge_sampler(json: &mut Value) -> usize {
+    let samplers = json
+        .as_object_mut()
+        .expect("glTF root is a JSON object")
+        .entry("samplers")
+        .or_insert_with(|| Value::Array(Vec::new()))
+        .as_array_mut()
+        .expect("samplers is an array");
+    let index = samplers.len();
+    samplers.push(serde_json::json!({
+        "wrapS": CLAMP_TO_EDGE,
+        "wrapT": CLAMP_TO_EDGE,
+    }));
+    index
+}
+
+fn append_texture(json: &mut Value, image_index: usize, sampler_index: usize) -> usize {
+    let textures = json
+        .get_mut("textures")
+        .and_then(Value::as_array_mut)
+        .expect("atlased glTF has a textures array");
+    let index = textures.len();
+    textures.push(serde_json::json!({
+        "source": image_index,
+        "sampler": sampler_index,
+    }));
+    index
+}
+
+fn remap_base_color_texture(json: &mut Value, old_texture_index: usize, new_texture_index: usize) {
+    let Some(materials) = json.get_mut("materials").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for material in materials {
+        let current = material
+            .pointer("/pbrMetallicRoughness/baseColorTexture/index")
+            .and_then(Value::as_u64);
+        if current == Some(old_texture_index as u64) {
+            material["pbrMetallicRoughness"]["baseColorTexture"]["index"] =
+                Value::from(new_texture_index as u64);
+        }
+    }
+}
+
+fn remap_texcoord_accessor(json: &Value, bin: &mut [u8], accessor_index: usize, placement: &Placement) {
+    let Some(meta) = accessor_meta(json, accessor_index) else {
+        return;
+    };
+    for vertex_index in 0..meta.count {
+        let Some(uv) = read_f32_element(bin, &meta, vertex_index) else {
+            continue;
+        };
+        let new_u = (uv[0] * placement.w as f32 + placement.x as f32) / SHEET_SIZE as f32;
+        let new_v = (uv[1] * placement.h as f32 + placement.y as f32) / SHEET_SIZE as f32;
+        write_f32_element(bin, &meta, vertex_index, &[new_u, new_v]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_material_reusing_texcoord_for_normal_texture_when_atlasing_then_base_color_texture_is_skipped()
+     {
+        let mut json = serde_json::json!({
+            "materials": [
+                {
+                    "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } },
+                    "normalTexture": { "index": 1 }
+                }
+            ],
+            "meshes": [
+                { "primitives": [ { "material": 0, "attributes": { "TEXCOORD_0": 0 } } ] }
+            ]
+        });
+        let mut bin: Vec<u8> = Vec::new();
+
+        let stats = apply_texture_atlas(&mut json, &mut bin).expect("atlas pass should succeed");
+
+        assert!(stats.atlased_texture_indices.is_empty());
+        assert_eq!(stats.sheet_count, 0);
+        assert_eq!(stats.skipped_texture_count, 1);
+    }
+
+    #[test]
+    fn given_material_without_other_channels_when_collecting_other_channel_accessors_then_set_is_empty()
+     {
+        let json = serde_json::json!({
+            "materials": [
+                { "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } } }
+            ],
+            "meshes": [
+                { "primitives": [ { "material": 0, "attributes": { "TEXCOORD_0": 0 } } ] }
+            ]
+        });
+
+        assert!(collect_other_channel_accessors(&json).is_empty());
+    }
+
+    #[test]
+    fn given_material_with_emissive_texture_when_collecting_other_channel_accessors_then_its_texcoord_is_included()
+     {
+        let json = serde_json::json!({
+            "materials": [
+                { "emissiveTexture": { "index": 2 } }
+            ],
+            "meshes": [
+                { "primitives": [ { "material": 0, "attributes": { "TEXCOORD_0": 3 } } ] }
+            ]
+        });
+
+        let accessors = collect_other_channel_accessors(&json);
+        assert!(accessors.contains(&3));
+    }
+}
@@ -86,6 +86,50 @@ pub(super) const BENTO_BONE_MAP: [(&str, &str); 33] = [
     ("rightLittleDistal", "mHandPinky3Right"),
 ];
 
+/// Fitted-mesh collision-volume bones to synthesize once the standard SL
+/// joint skeleton is in place: `(volume name, host SL bone name, local
+/// translation offset from the host in meters, fraction of the host bone's
+/// vertex weight to blend onto the volume)`.
+///
+/// These aren't part of the regular `mBone` joint chain — Second Life reads
+/// them as `COLLISION_VOLUME` bones that shape sliders deform independently,
+/// which is what lets SL "fitted mesh" garments follow avatar shape changes.
+/// A VRM humanoid skeleton has no equivalent, so they're synthesized here
+/// rather than mapped from a source bone.
+pub(super) const FITTED_MESH_COLLISION_VOLUMES: [(&str, &str, [f32; 3], f32); 14] = [
+    ("BELLY", "mTorso", [0.08, 0.0, 0.05], 0.35),
+    ("BUTT", "mPelvis", [-0.06, 0.0, -0.05], 0.35),
+    ("LEFT_PEC", "mChest", [0.04, 0.10, 0.02], 0.30),
+    ("RIGHT_PEC", "mChest", [0.04, -0.10, 0.02], 0.30),
+    ("LEFT_HANDLE", "mTorso", [-0.02, 0.12, -0.05], 0.25),
+    ("RIGHT_HANDLE", "mTorso", [-0.02, -0.12, -0.05], 0.25),
+    ("LEFT_UPPER_ARM", "mShoulderLeft", [0.0, 0.12, 0.0], 0.30),
+    ("RIGHT_UPPER_ARM", "mShoulderRight", [0.0, -0.12, 0.0], 0.30),
+    ("LEFT_LOWER_ARM", "mElbowLeft", [0.0, 0.12, 0.0], 0.30),
+    ("RIGHT_LOWER_ARM", "mElbowRight", [0.0, -0.12, 0.0], 0.30),
+    ("LEFT_UPPER_LEG", "mHipLeft", [0.0, 0.0, -0.20], 0.30),
+    ("RIGHT_UPPER_LEG", "mHipRight", [0.0, 0.0, -0.20], 0.30),
+    ("LEFT_LOWER_LEG", "mKneeLeft", [0.0, 0.0, -0.20], 0.30),
+    ("RIGHT_LOWER_LEG", "mKneeRight", [0.0, 0.0, -0.20], 0.30),
+];
+
+/// Far-end SL bone for each limb collision volume in
+/// [`FITTED_MESH_COLLISION_VOLUMES`]: `(volume name, far bone name)`. Used to
+/// size the volume's `scale` from the actual host→far bone length, mirroring
+/// how a physics engine derives a capsule body's length from its two
+/// endpoint joints, rather than leaving every avatar's volumes a fixed size
+/// regardless of limb proportions.
+pub(super) const LIMB_COLLISION_VOLUME_LENGTH_BONES: [(&str, &str); 8] = [
+    ("LEFT_UPPER_ARM", "mElbowLeft"),
+    ("RIGHT_UPPER_ARM", "mElbowRight"),
+    ("LEFT_LOWER_ARM", "mWristLeft"),
+    ("RIGHT_LOWER_ARM", "mWristRight"),
+    ("LEFT_UPPER_LEG", "mKneeLeft"),
+    ("RIGHT_UPPER_LEG", "mKneeRight"),
+    ("LEFT_LOWER_LEG", "mAnkleLeft"),
+    ("RIGHT_LOWER_LEG", "mAnkleRight"),
+];
+
 /// Core hierarchy edges to reconstruct for SL-compatible humanoid skeleton.
 ///
 /// **Fallback** relations (`chest → leftUpperArm`, `chest → rightUpperArm`) are
@@ -155,10 +199,150 @@ pub(super) const BENTO_HIERARCHY_RELATIONS: [(&str, &str); 33] = [
     ("rightLittleIntermediate", "rightLittleDistal"),
 ];
 
+/// Optional non-humanoid SL bone mapping: tail, wings, hind limbs, and groin.
+/// VRM has no humanoid semantic for any of these, so they're matched by
+/// literal glTF node name (see `validation::extract_extended_bone_nodes`)
+/// rather than through `humanoid_bone_nodes`. Only consulted when
+/// [`ConvertOptions::include_extended_bones`] is set.
+pub(super) const EXTENDED_BONE_MAP: [(&str, &str); 24] = [
+    ("tail1", "mTail1"),
+    ("tail2", "mTail2"),
+    ("tail3", "mTail3"),
+    ("tail4", "mTail4"),
+    ("tail5", "mTail5"),
+    ("tail6", "mTail6"),
+    ("wingsRoot", "mWingsRoot"),
+    ("wing1Left", "mWing1Left"),
+    ("wing2Left", "mWing2Left"),
+    ("wing3Left", "mWing3Left"),
+    ("wing4Left", "mWing4Left"),
+    ("wing1Right", "mWing1Right"),
+    ("wing2Right", "mWing2Right"),
+    ("wing3Right", "mWing3Right"),
+    ("wing4Right", "mWing4Right"),
+    ("hindLimb1Left", "mHindLimb1Left"),
+    ("hindLimb2Left", "mHindLimb2Left"),
+    ("hindLimb3Left", "mHindLimb3Left"),
+    ("hindLimb4Left", "mHindLimb4Left"),
+    ("hindLimb1Right", "mHindLimb1Right"),
+    ("hindLimb2Right", "mHindLimb2Right"),
+    ("hindLimb3Right", "mHindLimb3Right"),
+    ("hindLimb4Right", "mHindLimb4Right"),
+    ("groin", "mGroin"),
+];
+
+/// Hierarchy edges for [`EXTENDED_BONE_MAP`], in source (matched node) names.
+/// The tail and hind limb chains root under `hips` (SL's `mPelvis`) alongside
+/// the leg chains; the wing chain roots under `spine` (SL's `mTorso`).
+pub(super) const EXTENDED_HIERARCHY_RELATIONS: [(&str, &str); 24] = [
+    ("hips", "tail1"),
+    ("tail1", "tail2"),
+    ("tail2", "tail3"),
+    ("tail3", "tail4"),
+    ("tail4", "tail5"),
+    ("tail5", "tail6"),
+    ("spine", "wingsRoot"),
+    ("wingsRoot", "wing1Left"),
+    ("wing1Left", "wing2Left"),
+    ("wing2Left", "wing3Left"),
+    ("wing3Left", "wing4Left"),
+    ("wingsRoot", "wing1Right"),
+    ("wing1Right", "wing2Right"),
+    ("wing2Right", "wing3Right"),
+    ("wing3Right", "wing4Right"),
+    ("hips", "hindLimb1Left"),
+    ("hindLimb1Left", "hindLimb2Left"),
+    ("hindLimb2Left", "hindLimb3Left"),
+    ("hindLimb3Left", "hindLimb4Left"),
+    ("hips", "hindLimb1Right"),
+    ("hindLimb1Right", "hindLimb2Right"),
+    ("hindLimb2Right", "hindLimb3Right"),
+    ("hindLimb3Right", "hindLimb4Right"),
+    ("hips", "groin"),
+];
+
+/// Reference avatar height (centimeters) that [`CANONICAL_SL_REST_POSITIONS`]
+/// was authored against. Measured positions/tolerances are scaled by
+/// `estimated_height_cm / CANONICAL_REFERENCE_HEIGHT_CM` before comparison so
+/// the check stays proportion-relative rather than tied to one absolute size.
+pub(super) const CANONICAL_REFERENCE_HEIGHT_CM: f32 = 200.0;
+
+/// Second Life's canonical Bento rest-skeleton joint offsets: world-space
+/// `[x, y, z]` position in meters (SL axis convention, T-pose) at
+/// [`CANONICAL_REFERENCE_HEIGHT_CM`], plus the per-bone deviation tolerance
+/// in meters at that same reference height.
+pub(super) const CANONICAL_SL_REST_POSITIONS: [(&str, [f32; 3], f32); 19] = [
+    ("mPelvis", [0.0, 0.0, 1.00], 0.05),
+    ("mTorso", [0.0, 0.0, 1.18], 0.05),
+    ("mChest", [0.0, 0.0, 1.38], 0.05),
+    ("mNeck", [0.0, 0.0, 1.56], 0.04),
+    ("mHead", [0.0, 0.0, 1.66], 0.05),
+    ("mCollarLeft", [0.0, 0.09, 1.50], 0.04),
+    ("mShoulderLeft", [0.0, 0.19, 1.47], 0.06),
+    ("mElbowLeft", [0.0, 0.46, 1.47], 0.08),
+    ("mWristLeft", [0.0, 0.72, 1.47], 0.08),
+    ("mCollarRight", [0.0, -0.09, 1.50], 0.04),
+    ("mShoulderRight", [0.0, -0.19, 1.47], 0.06),
+    ("mElbowRight", [0.0, -0.46, 1.47], 0.08),
+    ("mWristRight", [0.0, -0.72, 1.47], 0.08),
+    ("mHipLeft", [0.0, 0.10, 0.92], 0.06),
+    ("mKneeLeft", [0.0, 0.10, 0.50], 0.08),
+    ("mAnkleLeft", [0.0, 0.10, 0.08], 0.06),
+    ("mHipRight", [0.0, -0.10, 0.92], 0.06),
+    ("mKneeRight", [0.0, -0.10, 0.50], 0.08),
+    ("mAnkleRight", [0.0, -0.10, 0.08], 0.06),
+];
+
 // ─── Public types ─────────────────────────────────────────────────────────────
 
+/// How a [`BoneRemapRule`] matches a glTF node's literal `name`, evaluated
+/// against every node once up front (see
+/// `super::skinning::rebake_skin_weights_for_sl_compliance`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BoneRemapMatcher {
+    /// Matches only a node whose name equals this string exactly.
+    Exact(String),
+    /// Matches any node whose name starts with this string (e.g. `"J_Sec_"`
+    /// for VRoid spring bones).
+    Prefix(String),
+    /// Matches any node whose name matches this regular expression.
+    Regex(String),
+}
+
+/// What to do with a vertex influence bound to a node outside the mapped SL
+/// skeleton, once a [`BoneRemapRule`]'s matcher has matched that node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BoneRemapAction {
+    /// Force the influence onto a specific SL bone's joint slot, falling
+    /// back to [`BoneRemapAction::CollapseToAncestor`] when that bone isn't
+    /// present in the skin's `joints` array.
+    MapTo(String),
+    /// Re-bind the influence to the node's nearest mapped-SL ancestor. This
+    /// is the behavior every unmapped bone got before rules existed.
+    CollapseToAncestor,
+    /// Zero the influence out; its weight is redistributed among the
+    /// vertex's remaining influences by the usual renormalization pass.
+    Drop,
+}
+
+/// A single bone-remapping policy rule. Rules are evaluated in declaration
+/// order against a node's name; the first match wins, and a node matching no
+/// rule defaults to [`BoneRemapAction::CollapseToAncestor`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoneRemapRule {
+    pub matcher: BoneRemapMatcher,
+    pub action: BoneRemapAction,
+}
+
+/// Default for [`ConvertOptions::force_tpose`] on requests saved before the
+/// field existed, so older saved settings keep getting the automatic
+/// straightening they implicitly relied on rather than silently losing it.
+fn default_force_tpose() -> bool {
+    true
+}
+
 /// Conversion options shared by CLI and Tauri IPC entry points.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConvertOptions {
     /// Target avatar height in centimeters.
     pub target_height_cm: f32,
@@ -168,6 +352,74 @@ pub struct ConvertOptions {
     pub texture_auto_resize: bool,
     /// Interpolation method used for texture resize operations.
     pub texture_resize_method: ResizeInterpolation,
+    /// Optional path to a user-editable skeleton profile (`.toml`/`.json`).
+    /// When `None`, [`super::profile::SkeletonProfile::default_sl_bento`] is used.
+    pub skeleton_profile_path: Option<String>,
+    /// Optional path to a JSON `{ role: sourceNodeNameOrIndex }` bone-mapping
+    /// override (see `validation::load_bone_map_override`), merged over the
+    /// auto-detected humanoid bone mapping and taking precedence for any role
+    /// it lists. Lets a rig exported from a tool other than VRoid, with
+    /// missing or non-standard VRM humanoid metadata, still be converted by
+    /// hand-authoring the correspondence. `None` relies solely on VRM
+    /// extension data and node-name heuristics.
+    #[serde(default)]
+    pub bone_map_override_path: Option<String>,
+    /// Straighten each arm's shoulder/upperArm/lowerArm/hand segments onto
+    /// Second Life's horizontal T-pose rest axis, on top of the fixed-bone
+    /// correction that always runs. Disable to preserve more of the model's
+    /// originally authored A-pose.
+    #[serde(default = "default_force_tpose")]
+    pub force_tpose: bool,
+    /// Straighten arms and legs into Second Life's T-pose rest direction with
+    /// analytic two-bone IK, on top of the baseline per-bone arm correction.
+    /// Disable to preserve more of the model's originally authored pose.
+    pub repose_limbs_to_t_pose: bool,
+    /// Instead of relying solely on SL's default rest skeleton, record each
+    /// mapped bone's final bind-pose translation relative to its SL-named
+    /// parent as a per-joint SL position override on the skin, so the viewer
+    /// deforms toward the avatar's authored proportions rather than its own
+    /// default skeleton. Suppresses the canonical-rest-position deviation
+    /// warning, since the deviation is now intentional and recorded rather
+    /// than a defect.
+    pub preserve_custom_proportions: bool,
+    /// Also write the converted, SL-named skeleton (and any VRM animation
+    /// clip baked onto it) out as a `.bvh` file alongside the `.glb`, for
+    /// import into SL's animation uploader.
+    pub export_bvh_animation: bool,
+    /// Retarget VRM spring/secondary bones (hair, skirt, tail, ...) named in
+    /// the active skeleton profile's `secondary_bones` table onto their
+    /// listed SL Bento extension joint, keeping their skin weights live
+    /// instead of collapsing them into the nearest mapped SL ancestor.
+    /// No-op when the active profile defines no `secondary_bones`.
+    pub retarget_secondary_bones: bool,
+    /// User-configurable bone-remapping policy consulted whenever an
+    /// influence is bound to a bone outside the mapped SL skeleton, in place
+    /// of always collapsing it onto the nearest mapped ancestor. Empty by
+    /// default, so existing conversions are unaffected.
+    #[serde(default)]
+    pub bone_remap_rules: Vec<BoneRemapRule>,
+    /// Bin-pack eligible small `baseColorTexture` source images into shared
+    /// 1024x1024 atlas sheets instead of uploading each separately, cutting
+    /// per-texture SL upload fees. Off by default: it only ever helps models
+    /// with several small textures, and skips (rather than corrupts) any
+    /// texture it can't safely atlas. See [`super::texture_atlas`].
+    #[serde(default)]
+    pub enable_texture_atlas: bool,
+    /// Map and reconstruct extra non-humanoid SL bones (tail, wings, hind
+    /// limbs, groin) from [`super::types::EXTENDED_BONE_MAP`] when a VRM node
+    /// name matches one. Off by default, since most VRoid exports have none
+    /// of these and the table is matched by literal node name rather than
+    /// VRM humanoid metadata.
+    #[serde(default)]
+    pub include_extended_bones: bool,
+    /// Instead of hard-failing a primitive with more than 65,535 vertices
+    /// (Second Life's per-mesh vertex limit), partition it into sibling
+    /// primitives that each stay within the limit, sharing the original
+    /// material. Off by default: the extra sub-meshes add draw calls, so
+    /// models already within the limit are unaffected either way. See
+    /// [`super::geometry::split_oversized_primitives`].
+    #[serde(default)]
+    pub split_oversized_primitives: bool,
 }
 
 impl Default for ConvertOptions {
@@ -177,10 +429,42 @@ impl Default for ConvertOptions {
             manual_scale: 1.0,
             texture_auto_resize: true,
             texture_resize_method: ResizeInterpolation::Bilinear,
+            skeleton_profile_path: None,
+            bone_map_override_path: None,
+            force_tpose: true,
+            repose_limbs_to_t_pose: true,
+            preserve_custom_proportions: false,
+            export_bvh_animation: false,
+            retarget_secondary_bones: false,
+            bone_remap_rules: Vec::new(),
+            enable_texture_atlas: false,
+            include_extended_bones: false,
+            split_oversized_primitives: false,
         }
     }
 }
 
+/// The checkpoints [`super::analyze_vrm`]/[`super::convert_vrm_to_gdb`]
+/// report progress at while processing one file, in pipeline order. See
+/// [`crate::ipc::convert_vrm_batch_ipc`] for how a batch conversion surfaces
+/// these to the frontend alongside `file_index`/`total_files`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversionStage {
+    Validate,
+    RemapUnmappedBoneWeights,
+    OptimizeSkinningWeightsAndJoints,
+    TextureProcessing,
+    WriteGlb,
+}
+
+/// Per-file conversion progress callback: the active [`ConversionStage`] and
+/// that stage's own completion fraction (`0.0` on entry, `1.0` once
+/// finished; stages with natural sub-checkpoints, like the per-skin loop in
+/// [`super::skinning::optimize_skinning_weights_and_joints`], report
+/// intermediate fractions in between).
+pub type ProgressCallback<'a> = dyn FnMut(ConversionStage, f32) + 'a;
+
 /// Severity level used by validation issues.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Severity {
@@ -203,6 +487,21 @@ pub struct TextureInfo {
     pub index: usize,
     pub width: u32,
     pub height: u32,
+    /// Channel count of the source image (1 grayscale, 2 grayscale+alpha, 3
+    /// RGB, 4 RGBA).
+    pub channel_count: u8,
+    /// Whether the source format stores an alpha channel at all, independent
+    /// of whether any sampled pixel actually uses it.
+    pub has_alpha_channel: bool,
+    /// Whether a subsampled scan found any pixel with alpha below 255. False
+    /// on a `has_alpha_channel` texture means the alpha channel is dead
+    /// weight that could be dropped.
+    pub alpha_channel_used: bool,
+    /// True when the texture has no effective transparency: either it never
+    /// had an alpha channel, or it did but no sampled pixel used it.
+    pub is_opaque: bool,
+    /// Coarse average color of the sampled pixels, `[r, g, b]`.
+    pub average_color: [u8; 3],
 }
 
 /// Lightweight upload fee estimate before and after resize policy.
@@ -213,6 +512,63 @@ pub struct UploadFeeEstimate {
     pub reduction_percent: u32,
 }
 
+/// Counts of per-vertex skin weight fixes applied while rebaking skinning
+/// data for Second Life's 4-influence, normalized-weight requirement.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SkinWeightRebakeStats {
+    /// Vertices that had more than 4 non-zero influences, clamped to the 4
+    /// heaviest with the dropped weight redistributed among the rest.
+    pub clamped_vertex_count: usize,
+    /// Vertices whose influence weights did not sum to ~1.0 and were
+    /// renormalized.
+    pub renormalized_vertex_count: usize,
+    /// Vertices that had an influence bound to a bone outside the mapped SL
+    /// skeleton, re-bound per the active [`BoneRemapRule`] list (or the
+    /// nearest mapped ancestor, when no rule matched or `MapTo` fell back).
+    pub remapped_orphan_vertex_count: usize,
+    /// Vertices that had an influence dropped by a [`BoneRemapAction::Drop`]
+    /// rule, with the remaining influences renormalized to sum to 1.0.
+    pub dropped_vertex_count: usize,
+}
+
+/// Counts of per-vertex fixes applied by [`super::repair_skinning`], at the
+/// raw glTF skinning level rather than Second Life's mapped-bone rebake (see
+/// [`SkinWeightRebakeStats`] for that pass).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SkinRepairStats {
+    /// Vertices that had more than 4 non-zero influences, clamped to the 4
+    /// heaviest with the dropped weight redistributed among the rest.
+    pub clamped_vertex_count: usize,
+    /// Vertices whose influence weights did not sum to ~1.0 and were
+    /// renormalized.
+    pub renormalized_vertex_count: usize,
+    /// Vertices that had a `JOINTS_0`/`JOINTS_1` slot outside the skin's
+    /// `joints` array, clamped to the fallback slot (`0`).
+    pub out_of_range_joint_count: usize,
+    /// Vertices that had a NaN or negative weight, treated as zero weight.
+    pub invalid_weight_count: usize,
+}
+
+/// Per-skin joint usage and influence statistics produced by
+/// [`super::dump_skinning`] for understanding why a mesh might deform
+/// incorrectly in Second Life before committing to a full conversion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkinDumpEntry {
+    pub skin_index: usize,
+    pub joint_count: usize,
+    pub used_joint_slot_count: usize,
+    pub unused_joint_slot_count: usize,
+    /// `influence_histogram[n]` is the number of vertices with exactly `n`
+    /// non-zero joint influences, for `n` in `0..=4`.
+    pub influence_histogram: [usize; 5],
+}
+
+/// Machine-readable report emitted by [`super::dump_skinning`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkinDumpReport {
+    pub skins: Vec<SkinDumpEntry>,
+}
+
 /// Analysis-only report generated without writing output files.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisReport {
@@ -224,6 +580,9 @@ pub struct AnalysisReport {
     pub total_vertices: usize,
     pub total_polygons: usize,
     pub mapped_bones: Vec<(String, String)>,
+    /// Extended non-humanoid bones matched from [`EXTENDED_BONE_MAP`], empty
+    /// unless [`ConvertOptions::include_extended_bones`] was set.
+    pub mapped_extended_bones: Vec<(String, String)>,
     pub missing_required_bones: Vec<String>,
     pub texture_infos: Vec<TextureInfo>,
     pub fee_estimate: UploadFeeEstimate,
@@ -243,10 +602,23 @@ pub struct ConversionReport {
     pub total_vertices: usize,
     pub total_polygons: usize,
     pub mapped_bones: Vec<(String, String)>,
+    /// Extended non-humanoid bones matched from [`EXTENDED_BONE_MAP`], empty
+    /// unless [`ConvertOptions::include_extended_bones`] was set.
+    pub mapped_extended_bones: Vec<(String, String)>,
     pub texture_count: usize,
     pub texture_over_1024_count: usize,
     pub output_texture_infos: Vec<TextureInfo>,
     pub output_texture_over_1024_count: usize,
     pub fee_estimate: UploadFeeEstimate,
+    /// Vertices clamped to 4 joint influences during the skin weight rebake.
+    pub clamped_weight_vertex_count: usize,
+    /// Vertices whose joint influence weights were renormalized to sum to 1.0.
+    pub renormalized_weight_vertex_count: usize,
+    /// Source textures folded into an atlas sheet by
+    /// [`super::texture_atlas::apply_texture_atlas`], `0` when
+    /// [`ConvertOptions::enable_texture_atlas`] was off.
+    pub atlased_texture_count: usize,
+    /// Atlas sheets written when `atlased_texture_count > 0`.
+    pub atlas_sheet_count: usize,
     pub issues: Vec<ValidationIssue>,
 }
@@ -1,31 +1,19 @@
 use std::collections::{HashMap, HashSet};
+use std::{fs, path::Path};
 
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
 use gltf::Document;
+use nalgebra::{Matrix4, Vector3};
 use serde_json::Value;
 
+use super::gltf_utils::node_index_by_name;
+use super::profile::SkeletonProfile;
+use super::texture_atlas::{SHEET_SIZE, TextureAtlasStats};
 use super::types::{
-    BENTO_BONE_MAP, BONE_MAP, REQUIRED_BONES, Severity, TextureInfo, UploadFeeEstimate,
+    BENTO_BONE_MAP, BONE_MAP, EXTENDED_BONE_MAP, Severity, TextureInfo, UploadFeeEstimate,
     ValidationIssue,
 };
 
-// ─── Required parent-child relationships ──────────────────────────────────────
-
-const REQUIRED_PARENT_RELATIONS: [(&str, &str); 12] = [
-    ("hips", "spine"),
-    ("spine", "chest"),
-    ("chest", "neck"),
-    ("neck", "head"),
-    ("leftUpperArm", "leftLowerArm"),
-    ("leftLowerArm", "leftHand"),
-    ("rightUpperArm", "rightLowerArm"),
-    ("rightLowerArm", "rightHand"),
-    ("leftUpperLeg", "leftLowerLeg"),
-    ("leftLowerLeg", "leftFoot"),
-    ("rightUpperLeg", "rightLowerLeg"),
-    ("rightLowerLeg", "rightFoot"),
-];
-
 // ─── Source model validation ──────────────────────────────────────────────────
 
 /// Validate that the source appears to be a supported VRoid/VRM model.
@@ -75,29 +63,39 @@ pub(super) fn collect_parent_index_map(document: &Document) -> HashMap<usize, us
     parent_map
 }
 
-/// Return missing required bones from the humanoid bone node map.
+/// Return missing required bones from the humanoid bone node map, per the
+/// active skeleton profile's `required` flags.
 pub(super) fn collect_missing_required_bones(
     humanoid_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
 ) -> Vec<String> {
-    REQUIRED_BONES
-        .iter()
-        .filter(|bone_name| !humanoid_bone_nodes.contains_key(**bone_name))
+    profile
+        .required_sources()
+        .into_iter()
+        .filter(|bone_name| !humanoid_bone_nodes.contains_key(*bone_name))
         .map(|bone_name| bone_name.to_string())
         .collect()
 }
 
-/// Validate required humanoid hierarchy relationships.
+/// Validate required humanoid hierarchy relationships from the active
+/// skeleton profile, restricted to edges between required bones.
 pub(super) fn validate_hierarchy(
     humanoid_bone_nodes: &HashMap<String, usize>,
     parent_map: &HashMap<usize, usize>,
+    profile: &SkeletonProfile,
 ) -> Vec<ValidationIssue> {
-    REQUIRED_PARENT_RELATIONS
+    let required: HashSet<&str> = profile.required_sources().into_iter().collect();
+
+    profile
+        .hierarchy
         .iter()
-        .filter_map(|(parent, child)| {
-            let Some(parent_index) = humanoid_bone_nodes.get(*parent).copied() else {
+        .filter(|edge| required.contains(edge.parent.as_str()) && required.contains(edge.child.as_str()))
+        .filter_map(|edge| {
+            let (parent, child) = (edge.parent.as_str(), edge.child.as_str());
+            let Some(parent_index) = humanoid_bone_nodes.get(parent).copied() else {
                 return None;
             };
-            let Some(child_index) = humanoid_bone_nodes.get(*child).copied() else {
+            let Some(child_index) = humanoid_bone_nodes.get(child).copied() else {
                 return None;
             };
 
@@ -112,7 +110,7 @@ pub(super) fn validate_hierarchy(
                 });
             };
 
-            let is_valid_parent = if *parent == "chest" && *child == "neck" {
+            let is_valid_parent = if parent == "chest" && child == "neck" {
                 let upper_chest_index = humanoid_bone_nodes.get("upperChest").copied();
                 actual_parent_index == parent_index
                     || upper_chest_index
@@ -138,6 +136,153 @@ pub(super) fn validate_hierarchy(
         .collect()
 }
 
+/// Tolerance, in meters, for how far a mapped left/right bone pair's world
+/// positions may deviate from exact mirror symmetry across the sagittal
+/// (x = 0) plane before [`validate_bone_left_right_symmetry`] warns.
+const BONE_SYMMETRY_POSITION_TOLERANCE_M: f32 = 0.02;
+
+/// Tolerance, in meters, for how far a mapped left/right bone pair's
+/// parent-to-bone lengths may differ before [`validate_bone_left_right_symmetry`]
+/// warns.
+const BONE_SYMMETRY_LENGTH_TOLERANCE_M: f32 = 0.015;
+
+/// Check that every mapped `left*` humanoid bone has its `right*` counterpart
+/// present, and that the pair's rest-pose world positions and bone lengths
+/// are mirror-symmetric across the sagittal plane within tolerance. Both
+/// [`BONE_MAP`] and [`BENTO_BONE_MAP`] source names use a uniform `left`/
+/// `right` prefix convention, so side pairing is derived directly from that
+/// prefix rather than from signed position on the symmetry axis.
+///
+/// Catches VRoid exports with a missing or offset hand/leg chain on one side
+/// before an upload fee is wasted converting a lopsided skeleton.
+///
+/// `node_worlds` and `parent_map` must describe the same (pre-conversion)
+/// node indices as `humanoid_bone_nodes`.
+pub(super) fn validate_bone_left_right_symmetry(
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    parent_map: &HashMap<usize, usize>,
+    node_worlds: &[Matrix4<f32>],
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    let mut left_sources: Vec<&str> = humanoid_bone_nodes
+        .keys()
+        .map(String::as_str)
+        .filter(|name| name.starts_with("left"))
+        .collect();
+    left_sources.sort_unstable();
+
+    for left_source in left_sources {
+        let right_source = format!("right{}", &left_source["left".len()..]);
+        let left_index = humanoid_bone_nodes[left_source];
+
+        let Some(&right_index) = humanoid_bone_nodes.get(right_source.as_str()) else {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "BONE_ASYMMETRY".to_string(),
+                message: format!(
+                    "⚠️ '{}' has no mapped '{}' counterpart — one side of this pair is missing",
+                    left_source, right_source
+                ),
+            });
+            continue;
+        };
+
+        let (Some(left_world), Some(right_world)) =
+            (node_worlds.get(left_index), node_worlds.get(right_index))
+        else {
+            continue;
+        };
+        let left_position = world_translation(left_world);
+        let right_position = world_translation(right_world);
+        let mirrored_left_position =
+            Vector3::new(-left_position.x, left_position.y, left_position.z);
+        let position_deviation = (mirrored_left_position - right_position).norm();
+
+        if position_deviation > BONE_SYMMETRY_POSITION_TOLERANCE_M {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "BONE_ASYMMETRY".to_string(),
+                message: format!(
+                    "⚠️ '{}'/'{}' are {:.3}m off from mirror symmetry across the sagittal plane (tolerance {:.3}m)",
+                    left_source, right_source, position_deviation, BONE_SYMMETRY_POSITION_TOLERANCE_M
+                ),
+            });
+        }
+
+        let (Some(&left_parent_index), Some(&right_parent_index)) =
+            (parent_map.get(&left_index), parent_map.get(&right_index))
+        else {
+            continue;
+        };
+        let (Some(left_parent_world), Some(right_parent_world)) = (
+            node_worlds.get(left_parent_index),
+            node_worlds.get(right_parent_index),
+        ) else {
+            continue;
+        };
+
+        let left_length = (left_position - world_translation(left_parent_world)).norm();
+        let right_length = (right_position - world_translation(right_parent_world)).norm();
+        let length_deviation = (left_length - right_length).abs();
+
+        if length_deviation > BONE_SYMMETRY_LENGTH_TOLERANCE_M {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                code: "BONE_ASYMMETRY".to_string(),
+                message: format!(
+                    "⚠️ '{}' ({:.3}m) and '{}' ({:.3}m) differ in bone length by {:.3}m (tolerance {:.3}m)",
+                    left_source,
+                    left_length,
+                    right_source,
+                    right_length,
+                    length_deviation,
+                    BONE_SYMMETRY_LENGTH_TOLERANCE_M
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Extract a world matrix's translation column as a vector.
+fn world_translation(world: &Matrix4<f32>) -> Vector3<f32> {
+    Vector3::new(world[(0, 3)], world[(1, 3)], world[(2, 3)])
+}
+
+/// A required bone missing from `humanoid_bone_nodes` that the converter can
+/// repair by splicing in a synthesized pass-through node, rather than
+/// treating the model as unconvertible: returns its nearest required
+/// ancestor and descendant source names, but only if both are themselves
+/// present (so a position can be interpolated between them).
+pub(super) fn synthesizable_intermediate_bone(
+    bone_source: &str,
+    humanoid_bone_nodes: &HashMap<String, usize>,
+    profile: &SkeletonProfile,
+) -> Option<(String, String)> {
+    let required: HashSet<&str> = profile.required_sources().into_iter().collect();
+
+    let parent_source = profile
+        .hierarchy
+        .iter()
+        .find(|edge| edge.child == bone_source && required.contains(edge.parent.as_str()))
+        .map(|edge| edge.parent.clone())?;
+    let child_source = profile
+        .hierarchy
+        .iter()
+        .find(|edge| edge.parent == bone_source && required.contains(edge.child.as_str()))
+        .map(|edge| edge.child.clone())?;
+
+    if humanoid_bone_nodes.contains_key(&parent_source)
+        && humanoid_bone_nodes.contains_key(&child_source)
+    {
+        Some((parent_source, child_source))
+    } else {
+        None
+    }
+}
+
 // ─── Bone mapping helpers ─────────────────────────────────────────────────────
 
 /// Return mapped source→target bone pairs present in the input model.
@@ -152,8 +297,47 @@ pub(super) fn collect_mapped_bones(
         .collect()
 }
 
+/// Literal glTF node-name → extended (non-humanoid) SL bone node mapping,
+/// consulted only when `ConvertOptions::include_extended_bones` is set.
+/// Unlike [`extract_humanoid_bone_nodes`], there's no VRM extension data for
+/// tail/wing/hind-limb/groin bones to prefer, so this is name matching alone
+/// — mirrors [`extract_secondary_bone_nodes`]'s literal-name lookup, but
+/// against the fixed [`EXTENDED_BONE_MAP`] table rather than a profile.
+pub(super) fn extract_extended_bone_nodes(json: &Value) -> HashMap<String, usize> {
+    let Some(nodes) = json.get("nodes").and_then(Value::as_array) else {
+        return HashMap::new();
+    };
+
+    let wanted_sources: HashSet<&str> =
+        EXTENDED_BONE_MAP.iter().map(|(source, _)| *source).collect();
+
+    nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(node_index, node)| {
+            let name = node.get("name").and_then(Value::as_str)?;
+            wanted_sources
+                .contains(name)
+                .then(|| (name.to_string(), node_index))
+        })
+        .collect()
+}
+
+/// Extended (non-humanoid) bones from [`EXTENDED_BONE_MAP`] actually matched
+/// in `extended_bone_nodes`, as `(source, target)` pairs — the extended-bone
+/// counterpart to [`collect_mapped_bones`].
+pub(super) fn collect_mapped_extended_bones(
+    extended_bone_nodes: &HashMap<String, usize>,
+) -> Vec<(String, String)> {
+    EXTENDED_BONE_MAP
+        .iter()
+        .filter(|(source, _)| extended_bone_nodes.contains_key(*source))
+        .map(|(source, target)| (source.to_string(), target.to_string()))
+        .collect()
+}
+
 /// Extract humanoid-bone semantic to node-index mapping from VRM extensions.
-pub(super) fn extract_humanoid_bone_nodes(json: &Value) -> HashMap<String, usize> {
+pub(super) fn extract_vrm_extension_bone_nodes(json: &Value) -> HashMap<String, usize> {
     let mut mapping = HashMap::<String, usize>::new();
 
     if let Some(vrmc_humanoid) = json
@@ -194,6 +378,227 @@ pub(super) fn extract_humanoid_bone_nodes(json: &Value) -> HashMap<String, usize
     mapping
 }
 
+/// Node-name substrings (lowercased, with `_`/`-`/` `/`.` stripped) that
+/// heuristically identify a canonical VRM humanoid bone on rigs exported
+/// without VRM metadata (e.g. raw Mixamo/Unity humanoid rigs). Checked in
+/// array order, so more specific aliases should be listed first.
+const HEURISTIC_BONE_ALIASES: [(&str, &[&str]); 17] = [
+    ("hips", &["hips", "pelvis"]),
+    ("spine", &["spine1", "spine01", "spine"]),
+    ("chest", &["chest", "upperchest", "spine2", "spine02"]),
+    ("neck", &["neck"]),
+    ("head", &["head"]),
+    (
+        "leftUpperArm",
+        &["leftupperarm", "upperarml", "larm", "leftarm"],
+    ),
+    (
+        "leftLowerArm",
+        &["leftlowerarm", "lowerarml", "leftforearm", "forearml"],
+    ),
+    ("leftHand", &["lefthand", "handl"]),
+    (
+        "rightUpperArm",
+        &["rightupperarm", "upperarmr", "rarm", "rightarm"],
+    ),
+    (
+        "rightLowerArm",
+        &["rightlowerarm", "lowerarmr", "rightforearm", "forearmr"],
+    ),
+    ("rightHand", &["righthand", "handr"]),
+    (
+        "leftUpperLeg",
+        &["leftupperleg", "upperlegl", "leftupleg", "thighl"],
+    ),
+    (
+        "leftLowerLeg",
+        &["leftlowerleg", "lowerlegl", "leftleg", "calfl", "shinl"],
+    ),
+    ("leftFoot", &["leftfoot", "footl"]),
+    (
+        "rightUpperLeg",
+        &["rightupperleg", "upperlegr", "rightupleg", "thighr"],
+    ),
+    (
+        "rightLowerLeg",
+        &["rightlowerleg", "lowerlegr", "rightleg", "calfr", "shinr"],
+    ),
+    ("rightFoot", &["rightfoot", "footr"]),
+];
+
+/// Lowercase a node/bone name and strip common word separators so aliases can
+/// be matched regardless of `snake_case`, `Title Case` or `dotted.case` source
+/// naming (e.g. `"Armature|mixamorig:LeftArm"` -> `"armaturemixamorigleftarm"`).
+fn normalize_bone_token(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Heuristically match canonical humanoid bone names onto scene graph node
+/// names by substring search, for rigs whose VRM humanoid extension is
+/// missing or only partially populated.
+///
+/// Only canonical names present in `profile.bones` are searched, and each
+/// node is matched to at most one canonical name (first alias hit wins).
+/// This is a best-effort fallback: callers should still prefer an explicit
+/// [`extract_vrm_extension_bone_nodes`] mapping when available.
+pub(super) fn heuristic_match_humanoid_bones(
+    json: &Value,
+    profile: &SkeletonProfile,
+) -> HashMap<String, usize> {
+    let mut matches = HashMap::<String, usize>::new();
+
+    let Some(nodes) = json.get("nodes").and_then(Value::as_array) else {
+        return matches;
+    };
+
+    let wanted_sources: HashSet<&str> = profile
+        .bones
+        .iter()
+        .map(|bone| bone.source.as_str())
+        .collect();
+
+    let normalized_names: Vec<(usize, String)> = nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(node_index, node)| {
+            node.get("name")
+                .and_then(Value::as_str)
+                .map(|name| (node_index, normalize_bone_token(name)))
+        })
+        .collect();
+
+    let mut claimed_nodes = HashSet::<usize>::new();
+    for (canonical, aliases) in HEURISTIC_BONE_ALIASES {
+        if !wanted_sources.contains(canonical) {
+            continue;
+        }
+
+        let matched_node = aliases.iter().find_map(|alias| {
+            normalized_names
+                .iter()
+                .find(|(node_index, normalized)| {
+                    !claimed_nodes.contains(node_index) && normalized.contains(alias)
+                })
+                .map(|(node_index, _)| *node_index)
+        });
+
+        if let Some(node_index) = matched_node {
+            claimed_nodes.insert(node_index);
+            matches.insert(canonical.to_string(), node_index);
+        }
+    }
+
+    matches
+}
+
+/// Resolve the humanoid-bone to node-index mapping for a model, preferring
+/// the VRM humanoid extension and filling any bones it leaves unmapped (or
+/// the whole table, when the extension is absent entirely) with
+/// [`heuristic_match_humanoid_bones`].
+pub(super) fn extract_humanoid_bone_nodes(
+    json: &Value,
+    profile: &SkeletonProfile,
+) -> HashMap<String, usize> {
+    let mut mapping = extract_vrm_extension_bone_nodes(json);
+
+    for (canonical, node_index) in heuristic_match_humanoid_bones(json, profile) {
+        mapping.entry(canonical).or_insert(node_index);
+    }
+
+    mapping
+}
+
+/// Load a user-authored bone-mapping override file: a JSON object mapping
+/// VRM humanoid role names (the same names [`extract_humanoid_bone_nodes`]
+/// produces, e.g. `leftUpperArm`) to either a source node's literal glTF
+/// `name` or its node index written as a string (e.g. `"12"`). Lets a rig
+/// exported from a tool other than VRoid, with missing or non-standard VRM
+/// humanoid metadata, still be converted by hand-authoring the
+/// correspondence.
+pub(super) fn load_bone_map_override(path: &Path) -> Result<HashMap<String, String>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read bone map override: {}", path.display()))?;
+    serde_json::from_str(&content).context("failed to parse bone map override JSON")
+}
+
+/// Resolve a bone-map override's `{ role: sourceNodeNameOrIndex }` entries
+/// against `json`'s nodes and merge them over `humanoid_bone_nodes`,
+/// overwriting any auto-detected mapping for the same role so the override
+/// can also satisfy a role the automatic detection left missing entirely.
+/// An entry whose value resolves to neither a node name nor a valid index is
+/// reported as a Warning and otherwise ignored, rather than failing the
+/// whole merge.
+pub(super) fn merge_bone_map_override(
+    json: &Value,
+    humanoid_bone_nodes: &mut HashMap<String, usize>,
+    overrides: &HashMap<String, String>,
+) -> Vec<ValidationIssue> {
+    let node_count = json
+        .get("nodes")
+        .and_then(Value::as_array)
+        .map(Vec::len)
+        .unwrap_or(0);
+    let mut issues = Vec::new();
+
+    for (role, source) in overrides {
+        let resolved = source
+            .parse::<usize>()
+            .ok()
+            .filter(|&index| index < node_count)
+            .or_else(|| node_index_by_name(json, source));
+
+        match resolved {
+            Some(node_index) => {
+                humanoid_bone_nodes.insert(role.clone(), node_index);
+            }
+            None => {
+                issues.push(ValidationIssue {
+                    severity: Severity::Warning,
+                    code: "BONE_MAP_OVERRIDE_UNRESOLVED".to_string(),
+                    message: format!(
+                        "⚠️ Bone map override for '{}' references unknown node '{}'; ignoring this override",
+                        role, source
+                    ),
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Resolve the active profile's `secondary_bones` source node names to node
+/// indices, by exact `name` match. Unlike humanoid bones, spring/secondary
+/// bone node names have no VRM extension or alias table to consult — VRoid
+/// and similar exporters name these nodes (e.g. `J_Sec_Tail1`) consistently,
+/// so an exact match is sufficient.
+pub(super) fn extract_secondary_bone_nodes(
+    json: &Value,
+    profile: &SkeletonProfile,
+) -> HashMap<String, usize> {
+    let Some(nodes) = json.get("nodes").and_then(Value::as_array) else {
+        return HashMap::new();
+    };
+
+    let wanted_sources: HashSet<&str> = profile
+        .secondary_bone_pairs()
+        .map(|(source, _)| source)
+        .collect();
+
+    nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(node_index, node)| {
+            let name = node.get("name").and_then(Value::as_str)?;
+            wanted_sources.contains(name).then(|| (name.to_string(), node_index))
+        })
+        .collect()
+}
+
 // ─── Metadata extraction ──────────────────────────────────────────────────────
 
 /// Extract model name from VRM metadata or asset generator.
@@ -226,24 +631,38 @@ pub(super) fn extract_author(json: &Value) -> Option<String> {
 
 // ─── Texture fee estimation ───────────────────────────────────────────────────
 
-/// Estimate texture upload fees before/after resize policy.
+/// Estimate texture upload fees before/after resize (and, when `atlas` is
+/// given, texture-atlasing) policy. `atlas` reflects a pass that has already
+/// run: atlased textures are billed once per sheet instead of individually,
+/// and every other texture falls back to the usual resize-only projection.
 pub(super) fn estimate_texture_fee(
     texture_infos: &[TextureInfo],
     auto_resize_to_1024: bool,
+    atlas: Option<&TextureAtlasStats>,
 ) -> UploadFeeEstimate {
     let before = texture_infos
         .iter()
         .map(|texture| fee_per_texture(texture.width, texture.height))
         .sum::<u32>();
 
-    let after = texture_infos
-        .iter()
-        .map(|texture| {
-            let (projected_width, projected_height) =
-                projected_texture_size(texture.width, texture.height, auto_resize_to_1024);
-            fee_per_texture(projected_width, projected_height)
-        })
-        .sum::<u32>();
+    let projected_fee = |texture: &TextureInfo| {
+        let (projected_width, projected_height) =
+            projected_texture_size(texture.width, texture.height, auto_resize_to_1024);
+        fee_per_texture(projected_width, projected_height)
+    };
+
+    let after = match atlas {
+        Some(atlas) if !atlas.atlased_texture_indices.is_empty() => {
+            let sheet_fee = atlas.sheet_count as u32 * fee_per_texture(SHEET_SIZE, SHEET_SIZE);
+            let non_atlased_fee: u32 = texture_infos
+                .iter()
+                .filter(|texture| !atlas.atlased_texture_indices.contains(&texture.index))
+                .map(projected_fee)
+                .sum();
+            sheet_fee + non_atlased_fee
+        }
+        _ => texture_infos.iter().map(projected_fee).sum(),
+    };
 
     let reduction_percent = if before > 0 {
         ((before.saturating_sub(after)) * 100) / before
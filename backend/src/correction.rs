@@ -0,0 +1,261 @@
+use nalgebra::{Matrix4, Point3, UnitQuaternion, Vector3};
+
+/// Computes a correction quaternion that moves a bone from the current local
+/// pose to the target local T-pose rotation.
+///
+/// # Arguments
+///
+/// * `current_pose` - Current local-space rotation of the bone.
+/// * `target_t_pose` - Desired local-space rotation for the T-pose.
+///
+/// # Returns
+///
+/// A quaternion that can be multiplied with `current_pose` to obtain the
+/// target rotation.
+pub fn compute_pose_correction(
+    current_pose: UnitQuaternion<f32>,
+    target_t_pose: UnitQuaternion<f32>,
+) -> UnitQuaternion<f32> {
+    target_t_pose * current_pose.inverse()
+}
+
+/// Like [`compute_pose_correction`], but blends from identity toward the full
+/// correction by `weight` (`0.0` leaves the bone as-is, `1.0` matches
+/// [`compute_pose_correction`] exactly) and, when `max_angle` is given,
+/// clamps the blended rotation's angle so a single bone can't be rotated
+/// further than that limit. Lets a per-bone weight map correct
+/// shoulders/collars gently while fingers snap fully to their target T-pose.
+///
+/// # Arguments
+///
+/// * `current_pose` - Current local-space rotation of the bone.
+/// * `target_t_pose` - Desired local-space rotation for the T-pose.
+/// * `weight` - Blend factor from identity (`0.0`) to the full correction (`1.0`).
+/// * `max_angle` - Optional clamp, in radians, on the blended rotation's angle.
+///
+/// # Returns
+///
+/// A quaternion that can be multiplied with `current_pose` to obtain the
+/// blended, optionally clamped, corrected rotation.
+pub fn compute_pose_correction_weighted(
+    current_pose: UnitQuaternion<f32>,
+    target_t_pose: UnitQuaternion<f32>,
+    weight: f32,
+    max_angle: Option<f32>,
+) -> UnitQuaternion<f32> {
+    let full_correction = compute_pose_correction(current_pose, target_t_pose);
+    let blended = UnitQuaternion::identity().slerp(&full_correction, weight);
+
+    let Some(max_angle) = max_angle else {
+        return blended;
+    };
+    let Some((axis, angle)) = blended.axis_angle() else {
+        return blended;
+    };
+    if angle <= max_angle {
+        return blended;
+    }
+    UnitQuaternion::from_axis_angle(&axis, max_angle)
+}
+
+/// Applies a correction quaternion to the current local rotation.
+///
+/// # Arguments
+///
+/// * `current_pose` - Current local-space rotation.
+/// * `correction` - Correction quaternion from A-pose to T-pose.
+///
+/// # Returns
+///
+/// Corrected local-space rotation.
+pub fn apply_corrected_rotation(
+    current_pose: UnitQuaternion<f32>,
+    correction: UnitQuaternion<f32>,
+) -> UnitQuaternion<f32> {
+    correction * current_pose
+}
+
+/// Applies the inverse correction to a vertex so mesh appearance stays stable
+/// while the bone rotation is corrected.
+///
+/// # Arguments
+///
+/// * `vertex` - Vertex position to be visually compensated.
+/// * `correction` - Bone correction quaternion applied to the skeleton.
+///
+/// # Returns
+///
+/// Vertex position transformed by the inverse correction.
+pub fn correct_vertex_with_inverse(
+    vertex: Vector3<f32>,
+    correction: UnitQuaternion<f32>,
+) -> Vector3<f32> {
+    correction.inverse_transform_vector(&vertex)
+}
+
+/// Applies a correction matrix directly to a vertex position.
+///
+/// # Arguments
+///
+/// * `vertex` - Vertex position to transform.
+/// * `correction_matrix` - 4x4 transform matrix applied to the vertex.
+///
+/// # Returns
+///
+/// Transformed vertex position.
+pub fn correct_vertex_with_matrix(
+    vertex: Vector3<f32>,
+    correction_matrix: Matrix4<f32>,
+) -> Vector3<f32> {
+    let p = Point3::from(vertex);
+    let corrected = correction_matrix.transform_point(&p);
+    corrected.coords
+}
+
+/// Rebuilds an inverse bind matrix from parent/world and local transforms.
+/// Returns `None` when the bind matrix is not invertible.
+///
+/// # Arguments
+///
+/// * `parent_world` - Parent node world transform matrix.
+/// * `local_transform` - Current node local transform matrix.
+///
+/// # Returns
+///
+/// `Some(inverse_bind_matrix)` when invertible, otherwise `None`.
+pub fn rebuild_inverse_bind_matrix(
+    parent_world: Matrix4<f32>,
+    local_transform: Matrix4<f32>,
+) -> Option<Matrix4<f32>> {
+    let bind_matrix = parent_world * local_transform;
+    bind_matrix.try_inverse()
+}
+
+/// Builds a Moore-Penrose pseudo-inverse of `bind_matrix` via SVD, for use as
+/// a fallback inverse bind matrix when [`rebuild_inverse_bind_matrix`]
+/// returns `None` (e.g. a zero-scale or otherwise collapsed bone imported
+/// from a bad VRM). Singular values at or below
+/// `singular_tolerance * largest_singular_value` are treated as zero instead
+/// of reciprocated, so near-singular directions don't blow up into huge
+/// spurious values.
+///
+/// # Arguments
+///
+/// * `bind_matrix` - The (possibly non-invertible) bind matrix to pseudo-invert.
+/// * `singular_tolerance` - Fraction of the largest singular value below which
+///   a singular value is treated as zero.
+///
+/// # Returns
+///
+/// The pseudo-inverse matrix. Always returns a value, unlike
+/// [`rebuild_inverse_bind_matrix`], since SVD is defined for any matrix.
+pub fn pseudo_inverse_bind_matrix(bind_matrix: Matrix4<f32>, singular_tolerance: f32) -> Matrix4<f32> {
+    let svd = bind_matrix.svd(true, true);
+    let largest_singular_value = svd.singular_values.max();
+    let epsilon = singular_tolerance * largest_singular_value;
+
+    let mut reciprocal_singular_values = svd.singular_values;
+    for value in reciprocal_singular_values.iter_mut() {
+        *value = if *value > epsilon { 1.0 / *value } else { 0.0 };
+    }
+
+    let u = svd.u.expect("requested u from svd(true, true)");
+    let v_t = svd.v_t.expect("requested v_t from svd(true, true)");
+    v_t.transpose() * Matrix4::from_diagonal(&reciprocal_singular_values) * u.transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Translation3, UnitQuaternion, Vector3};
+
+    #[test]
+    fn given_a_pose_when_applying_correction_then_rotation_matches_target() {
+        let current = UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4);
+        let target = UnitQuaternion::identity();
+        let correction = compute_pose_correction(current, target);
+
+        let corrected = apply_corrected_rotation(current, correction);
+        let q = corrected.quaternion();
+        assert!(q.w > 0.9999);
+        assert!(q.i.abs() < 0.0001);
+        assert!(q.j.abs() < 0.0001);
+        assert!(q.k.abs() < 0.0001);
+    }
+
+    #[test]
+    fn given_deformed_vertex_when_applying_inverse_correction_then_shape_is_preserved() {
+        let current = UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4);
+        let target = UnitQuaternion::identity();
+        let correction = compute_pose_correction(current, target);
+
+        let v = Vector3::new(1.0, 0.0, 0.0);
+        let deformed = correction.transform_vector(&v);
+        let restored = correct_vertex_with_inverse(deformed, correction);
+
+        assert!((restored - v).norm() < 0.0001);
+    }
+
+    #[test]
+    fn given_full_weight_and_no_clamp_when_computing_weighted_correction_then_it_matches_unweighted()
+     {
+        let current = UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4);
+        let target = UnitQuaternion::identity();
+
+        let correction = compute_pose_correction(current, target);
+        let weighted = compute_pose_correction_weighted(current, target, 1.0, None);
+
+        assert!((correction.quaternion() - weighted.quaternion()).norm() < 0.0001);
+    }
+
+    #[test]
+    fn given_zero_weight_when_computing_weighted_correction_then_it_leaves_pose_unchanged() {
+        let current = UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4);
+        let target = UnitQuaternion::identity();
+
+        let correction = compute_pose_correction_weighted(current, target, 0.0, None);
+        let corrected = apply_corrected_rotation(current, correction);
+
+        assert!((corrected.quaternion() - current.quaternion()).norm() < 0.0001);
+    }
+
+    #[test]
+    fn given_max_angle_when_computing_weighted_correction_then_blended_angle_is_clamped() {
+        let current = UnitQuaternion::from_euler_angles(0.0, 0.0, -1.2);
+        let target = UnitQuaternion::identity();
+        let max_angle = 0.2;
+
+        let correction = compute_pose_correction_weighted(current, target, 1.0, Some(max_angle));
+
+        assert!(correction.angle() <= max_angle + 0.0001);
+    }
+
+    #[test]
+    fn given_singular_bind_matrix_when_building_pseudo_inverse_then_rank_preserving_directions_are_recovered()
+     {
+        // Zero-scale along Z: non-invertible, but X/Y should still round-trip.
+        let bind_matrix = Matrix4::<f32>::new_nonuniform_scaling(&Vector3::new(2.0, 3.0, 0.0));
+        assert!(rebuild_inverse_bind_matrix(Matrix4::identity(), bind_matrix).is_none());
+
+        let pseudo_inverse = pseudo_inverse_bind_matrix(bind_matrix, 1e-6);
+        let p = Point3::new(1.0, 1.0, 0.0);
+        let round_tripped = pseudo_inverse.transform_point(&bind_matrix.transform_point(&p));
+
+        assert!((round_tripped.coords - p.coords).norm() < 0.0001);
+    }
+
+    #[test]
+    fn given_bind_transforms_when_rebuilding_inverse_bind_then_identity_is_recovered() {
+        let parent_world = Translation3::new(0.0, 1.0, 0.0).to_homogeneous();
+        let local_transform = Translation3::new(2.0, 0.0, 0.0).to_homogeneous();
+
+        let inverse = rebuild_inverse_bind_matrix(parent_world, local_transform)
+            .expect("inverse bind matrix should be invertible");
+
+        let bind = parent_world * local_transform;
+        let identity = bind * inverse;
+        let expected = nalgebra::Matrix4::<f32>::identity();
+
+        assert!((identity - expected).norm() < 0.0001);
+    }
+}
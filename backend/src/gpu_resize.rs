@@ -0,0 +1,478 @@
+//! GPU-accelerated texture resizing, behind the `gpu` feature.
+//!
+//! Mirrors [`crate::texture::resize_texture_to_max`] but dispatches the
+//! actual resampling to a wgpu compute shader instead of `image`'s CPU
+//! filters, which matters when a VRM ships dozens of 4K textures that all
+//! need downscaling before SL upload. [`crate::texture::resize_texture_to_max`]
+//! tries this path first and falls back to the CPU filters whenever no
+//! adapter is available, so callers never need to know which path ran.
+
+use std::sync::OnceLock;
+
+use image::{DynamicImage, RgbaImage};
+
+use crate::texture::ResizeInterpolation;
+
+const WORKGROUP_SIZE: u32 = 8;
+
+/// Filter kind understood by the resize shader. Mirrors
+/// [`ResizeInterpolation`] but as a `u32` the shader can switch on. Each
+/// variant gets its own code (rather than aliasing two CPU filters onto one
+/// GPU kernel) so `RESIZE_SHADER` stays in parity with
+/// [`crate::texture::resize_texture_to_max`]'s CPU `FilterType` mapping.
+fn filter_mode(interpolation: ResizeInterpolation) -> u32 {
+    match interpolation {
+        ResizeInterpolation::Nearest => 0,
+        ResizeInterpolation::Bilinear => 1,
+        ResizeInterpolation::Bicubic => 2,
+        ResizeInterpolation::Lanczos3 => 3,
+        ResizeInterpolation::Gaussian => 4,
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct ResizeParams {
+    src_width: u32,
+    src_height: u32,
+    dst_width: u32,
+    dst_height: u32,
+    filter_mode: u32,
+    kernel_radius: u32,
+    _padding: [u32; 2],
+}
+
+const RESIZE_SHADER: &str = r#"
+struct ResizeParams {
+    src_size: vec2<u32>,
+    dst_size: vec2<u32>,
+    filter_mode: u32,
+    kernel_radius: u32,
+};
+
+@group(0) @binding(0) var<uniform> params: ResizeParams;
+@group(0) @binding(1) var src_texture: texture_2d<f32>;
+@group(0) @binding(2) var src_sampler: sampler;
+@group(0) @binding(3) var dst_texture: texture_storage_2d<rgba8unorm, write>;
+
+fn sinc(x: f32) -> f32 {
+    if (abs(x) < 1e-6) {
+        return 1.0;
+    }
+    let px = 3.14159265 * x;
+    return sin(px) / px;
+}
+
+fn lanczos3_weight(x: f32) -> f32 {
+    if (abs(x) >= 3.0) {
+        return 0.0;
+    }
+    return sinc(x) * sinc(x / 3.0);
+}
+
+fn cubic_weight(x: f32) -> f32 {
+    // Catmull-Rom, matching the CPU path's `CatmullRom` filter.
+    let a = -0.5;
+    let ax = abs(x);
+    if (ax <= 1.0) {
+        return (a + 2.0) * ax * ax * ax - (a + 3.0) * ax * ax + 1.0;
+    } else if (ax < 2.0) {
+        return a * ax * ax * ax - 5.0 * a * ax * ax + 8.0 * a * ax - 4.0 * a;
+    }
+    return 0.0;
+}
+
+fn gaussian_weight(x: f32) -> f32 {
+    // sigma = 1.0 in normalized (offset / scale) space, matching the other
+    // kernels' convention of taking an already-scale-divided offset.
+    let sigma = 1.0;
+    return exp(-(x * x) / (2.0 * sigma * sigma));
+}
+
+fn load_clamped(coord: vec2<i32>) -> vec4<f32> {
+    let clamped = clamp(coord, vec2<i32>(0, 0), vec2<i32>(params.src_size) - vec2<i32>(1, 1));
+    return textureLoad(src_texture, clamped, 0);
+}
+
+fn separable_sample(center: vec2<f32>, scale: vec2<f32>) -> vec4<f32> {
+    let radius = i32(params.kernel_radius);
+    let center_pixel = vec2<i32>(floor(center));
+
+    var accum = vec4<f32>(0.0);
+    var weight_sum = 0.0;
+    for (var dy = -radius; dy <= radius; dy = dy + 1) {
+        for (var dx = -radius; dx <= radius; dx = dx + 1) {
+            let sample_pixel = center_pixel + vec2<i32>(dx, dy);
+            let offset = vec2<f32>(sample_pixel) + vec2<f32>(0.5) - center;
+            var wx: f32;
+            var wy: f32;
+            if (params.filter_mode == 3u) {
+                wx = lanczos3_weight(offset.x / scale.x);
+                wy = lanczos3_weight(offset.y / scale.y);
+            } else if (params.filter_mode == 4u) {
+                wx = gaussian_weight(offset.x / scale.x);
+                wy = gaussian_weight(offset.y / scale.y);
+            } else {
+                wx = cubic_weight(offset.x / scale.x);
+                wy = cubic_weight(offset.y / scale.y);
+            }
+            let weight = wx * wy;
+            accum = accum + load_clamped(sample_pixel) * weight;
+            weight_sum = weight_sum + weight;
+        }
+    }
+    if (weight_sum <= 1e-6) {
+        return load_clamped(center_pixel);
+    }
+    return accum / weight_sum;
+}
+
+@compute @workgroup_size(8, 8)
+fn resize(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if (global_id.x >= params.dst_size.x || global_id.y >= params.dst_size.y) {
+        return;
+    }
+
+    let dst_pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+    let uv = (vec2<f32>(global_id.xy) + vec2<f32>(0.5)) / vec2<f32>(params.dst_size);
+
+    var color: vec4<f32>;
+    if (params.filter_mode == 0u) {
+        color = textureSampleLevel(src_texture, src_sampler, uv, 0.0);
+    } else if (params.filter_mode == 1u) {
+        color = textureSampleLevel(src_texture, src_sampler, uv, 0.0);
+    } else {
+        let scale = max(vec2<f32>(1.0), vec2<f32>(params.src_size) / vec2<f32>(params.dst_size));
+        let center = uv * vec2<f32>(params.src_size);
+        color = separable_sample(center, scale);
+    }
+
+    textureStore(dst_texture, dst_pixel, color);
+}
+"#;
+
+/// Lazily-initialized wgpu handles shared by every GPU resize call. Built on
+/// first use so the common headless/no-adapter case never pays for device
+/// creation.
+struct GpuResizer {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+static RESIZER: OnceLock<Option<GpuResizer>> = OnceLock::new();
+
+impl GpuResizer {
+    fn get() -> Option<&'static GpuResizer> {
+        RESIZER.get_or_init(Self::new).as_ref()
+    }
+
+    fn new() -> Option<GpuResizer> {
+        let instance = wgpu::Instance::default();
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            compatible_surface: None,
+            force_fallback_adapter: false,
+        }))
+        .ok()?;
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            label: Some("vrm2sl texture resize device"),
+            ..Default::default()
+        }))
+        .ok()?;
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("vrm2sl texture resize shader"),
+            source: wgpu::ShaderSource::Wgsl(RESIZE_SHADER.into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("vrm2sl texture resize bind group layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::StorageTexture {
+                        access: wgpu::StorageTextureAccess::WriteOnly,
+                        format: wgpu::TextureFormat::Rgba8Unorm,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("vrm2sl texture resize pipeline layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("vrm2sl texture resize pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: Some("resize"),
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+            cache: None,
+        });
+
+        Some(GpuResizer {
+            device,
+            queue,
+            pipeline,
+            bind_group_layout,
+        })
+    }
+}
+
+/// Attempts to resize `image` to `(dst_width, dst_height)` on the GPU.
+/// Returns `None` whenever no adapter is available or any step of the
+/// pipeline fails, so the caller can transparently fall back to the CPU
+/// path in [`crate::texture::resize_texture_to_max`].
+pub(crate) fn try_gpu_resize(
+    image: &DynamicImage,
+    dst_width: u32,
+    dst_height: u32,
+    interpolation: ResizeInterpolation,
+) -> Option<DynamicImage> {
+    let resizer = GpuResizer::get()?;
+    let rgba = image.to_rgba8();
+    let (src_width, src_height) = rgba.dimensions();
+
+    let src_texture = resizer.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("vrm2sl resize src texture"),
+        size: wgpu::Extent3d {
+            width: src_width,
+            height: src_height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    resizer.queue.write_texture(
+        wgpu::TexelCopyTextureInfo {
+            texture: &src_texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        &rgba,
+        wgpu::TexelCopyBufferLayout {
+            offset: 0,
+            bytes_per_row: Some(4 * src_width),
+            rows_per_image: Some(src_height),
+        },
+        wgpu::Extent3d {
+            width: src_width,
+            height: src_height,
+            depth_or_array_layers: 1,
+        },
+    );
+
+    let dst_texture = resizer.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("vrm2sl resize dst texture"),
+        size: wgpu::Extent3d {
+            width: dst_width,
+            height: dst_height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+
+    let sampler = resizer.device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some("vrm2sl resize sampler"),
+        address_mode_u: wgpu::AddressMode::ClampToEdge,
+        address_mode_v: wgpu::AddressMode::ClampToEdge,
+        address_mode_w: wgpu::AddressMode::ClampToEdge,
+        mag_filter: match interpolation {
+            ResizeInterpolation::Nearest => wgpu::FilterMode::Nearest,
+            _ => wgpu::FilterMode::Linear,
+        },
+        min_filter: match interpolation {
+            ResizeInterpolation::Nearest => wgpu::FilterMode::Nearest,
+            _ => wgpu::FilterMode::Linear,
+        },
+        ..Default::default()
+    });
+
+    // Kernel radius of 3 source pixels for Lanczos3/Bicubic, scaled up when
+    // downscaling so enough taps are integrated to avoid aliasing.
+    let downscale_factor = (src_width / dst_width.max(1)).max(src_height / dst_height.max(1)).max(1);
+    let kernel_radius = 3 * downscale_factor;
+
+    let params = ResizeParams {
+        src_width,
+        src_height,
+        dst_width,
+        dst_height,
+        filter_mode: filter_mode(interpolation),
+        kernel_radius,
+        _padding: [0; 2],
+    };
+    let params_buffer = wgpu::util::DeviceExt::create_buffer_init(
+        &resizer.device,
+        &wgpu::util::BufferInitDescriptor {
+            label: Some("vrm2sl resize params"),
+            contents: bytemuck::bytes_of(&params),
+            usage: wgpu::BufferUsages::UNIFORM,
+        },
+    );
+
+    let src_view = src_texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let dst_view = dst_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let bind_group = resizer.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("vrm2sl resize bind group"),
+        layout: &resizer.bind_group_layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: params_buffer.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::TextureView(&src_view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 2,
+                resource: wgpu::BindingResource::Sampler(&sampler),
+            },
+            wgpu::BindGroupEntry {
+                binding: 3,
+                resource: wgpu::BindingResource::TextureView(&dst_view),
+            },
+        ],
+    });
+
+    let mut encoder = resizer
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("vrm2sl resize encoder") });
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("vrm2sl resize pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&resizer.pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(dst_width.div_ceil(WORKGROUP_SIZE), dst_height.div_ceil(WORKGROUP_SIZE), 1);
+    }
+
+    let bytes_per_row = (4 * dst_width).div_ceil(256) * 256;
+    let output_buffer = resizer.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("vrm2sl resize readback buffer"),
+        size: (bytes_per_row * dst_height) as u64,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+    encoder.copy_texture_to_buffer(
+        wgpu::TexelCopyTextureInfo {
+            texture: &dst_texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::TexelCopyBufferInfo {
+            buffer: &output_buffer,
+            layout: wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: Some(dst_height),
+            },
+        },
+        wgpu::Extent3d {
+            width: dst_width,
+            height: dst_height,
+            depth_or_array_layers: 1,
+        },
+    );
+
+    resizer.queue.submit(Some(encoder.finish()));
+
+    let slice = output_buffer.slice(..);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    resizer.device.poll(wgpu::Maintain::Wait);
+    receiver.recv().ok()?.ok()?;
+
+    let mapped = slice.get_mapped_range();
+    let mut pixels = Vec::with_capacity((dst_width * dst_height * 4) as usize);
+    for row in 0..dst_height {
+        let start = (row * bytes_per_row) as usize;
+        let end = start + (dst_width * 4) as usize;
+        pixels.extend_from_slice(&mapped[start..end]);
+    }
+    drop(mapped);
+    output_buffer.unmap();
+
+    let resized = RgbaImage::from_raw(dst_width, dst_height, pixels)?;
+    Some(DynamicImage::ImageRgba8(resized))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_each_interpolation_variant_when_mapping_filter_mode_then_every_code_is_distinct() {
+        let modes = [
+            filter_mode(ResizeInterpolation::Nearest),
+            filter_mode(ResizeInterpolation::Bilinear),
+            filter_mode(ResizeInterpolation::Bicubic),
+            filter_mode(ResizeInterpolation::Lanczos3),
+            filter_mode(ResizeInterpolation::Gaussian),
+        ];
+
+        for (i, &a) in modes.iter().enumerate() {
+            for (j, &b) in modes.iter().enumerate() {
+                assert_eq!(i == j, a == b, "modes at {i} and {j} should only match when equal");
+            }
+        }
+    }
+
+    #[test]
+    fn given_gaussian_interpolation_when_mapping_filter_mode_then_it_does_not_alias_bicubic() {
+        assert_ne!(
+            filter_mode(ResizeInterpolation::Gaussian),
+            filter_mode(ResizeInterpolation::Bicubic)
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fs,
     path::PathBuf,
     time::{SystemTime, UNIX_EPOCH},
@@ -7,7 +8,12 @@ use std::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    convert::{AnalysisReport, ConversionReport, ConvertOptions, analyze_vrm, convert_vrm_to_gdb},
+    cancellation,
+    convert::{
+        AnalysisReport, ConversionReport, ConversionStage, ConvertError, ConvertOptions,
+        SkinDumpReport, SkinRepairStats, ValidationIssue, analyze_vrm, convert_vrm_to_gdb,
+        dump_bone_map, dump_skinning, repair_skinning, validate_skinning,
+    },
     notify::send_desktop_notification,
     project::{ProjectSettings, load_project_settings, save_project_settings},
 };
@@ -18,6 +24,9 @@ pub struct AnalyzeRequest {
     pub input_path: String,
     pub options: ConvertOptions,
     pub notify_on_complete: bool,
+    /// Identifies this analysis to [`cancel_conversion_ipc`] for as long as
+    /// it's in flight, same as [`ConvertRequest::request_id`].
+    pub request_id: String,
 }
 
 /// IPC payload for conversion requests.
@@ -27,6 +36,9 @@ pub struct ConvertRequest {
     pub output_path: String,
     pub options: ConvertOptions,
     pub notify_on_complete: bool,
+    /// Identifies this conversion to [`cancel_conversion_ipc`] for as long as
+    /// it's in flight. Unused once the request returns.
+    pub request_id: String,
 }
 
 /// IPC payload for saving project settings.
@@ -49,10 +61,63 @@ pub struct PreviewRequest {
     pub options: ConvertOptions,
 }
 
-/// Analyze a source model through the IPC boundary.
-pub fn analyze_vrm_ipc(request: AnalyzeRequest) -> Result<AnalysisReport, String> {
+/// IPC payload for read-only skin diagnostics (validate/dump).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkinDiagnosticsRequest {
+    pub input_path: String,
+}
+
+/// IPC payload for repairing a file's skin data in place.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepairSkinningRequest {
+    pub input_path: String,
+    pub output_path: String,
+}
+
+/// One file within a [`ConvertBatchRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchConvertFile {
+    pub input_path: String,
+    pub output_path: String,
+}
+
+/// IPC payload for converting several files under one set of options.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertBatchRequest {
+    pub files: Vec<BatchConvertFile>,
+    pub options: ConvertOptions,
+    pub notify_on_complete: bool,
+}
+
+/// Per-file outcome of a batch conversion: the report on success, or the
+/// structured error on failure, so one failing file doesn't abort the rest
+/// of the batch (c.f. [`convert_vrm_to_gdb_ipc`], which fails outright).
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchConversionOutcome {
+    pub input_path: String,
+    pub output_path: String,
+    pub report: Option<ConversionReport>,
+    pub error: Option<ConvertError>,
+}
+
+/// Progress callback signature for [`convert_vrm_batch_ipc`]: the file's
+/// index and the batch's total file count, plus that file's own
+/// [`ConversionStage`] and stage-local completion fraction.
+pub type BatchProgressCallback<'a> = dyn FnMut(usize, usize, ConversionStage, f32) + 'a;
+
+/// Analyze a source model through the IPC boundary. Registers a
+/// [`cancellation::CancellationToken`] under `request.request_id` for the
+/// duration of the call, same as [`convert_vrm_to_gdb_ipc`].
+pub fn analyze_vrm_ipc(
+    request: AnalyzeRequest,
+    progress: Option<&mut dyn FnMut(ConversionStage, f32)>,
+) -> Result<AnalysisReport, ConvertError> {
     let input = PathBuf::from(&request.input_path);
-    let report = analyze_vrm(&input, request.options).map_err(|err| err.to_string())?;
+
+    let token = cancellation::register(&request.request_id);
+    let result = analyze_vrm(&input, request.options, progress, Some(&token));
+    cancellation::unregister(&request.request_id);
+    let report = result.map_err(ConvertError::from)?;
 
     if request.notify_on_complete {
         let _ = send_desktop_notification("vrm2sl", "Analysis completed");
@@ -61,13 +126,21 @@ pub fn analyze_vrm_ipc(request: AnalyzeRequest) -> Result<AnalysisReport, String
     Ok(report)
 }
 
-/// Convert a source model through the IPC boundary.
-pub fn convert_vrm_to_gdb_ipc(request: ConvertRequest) -> Result<ConversionReport, String> {
+/// Convert a source model through the IPC boundary. Registers a
+/// [`cancellation::CancellationToken`] under `request.request_id` for the
+/// duration of the call so a concurrent [`cancel_conversion_ipc`] call can
+/// stop it, unregistering it on every exit path.
+pub fn convert_vrm_to_gdb_ipc(
+    request: ConvertRequest,
+    progress: Option<&mut dyn FnMut(ConversionStage, f32)>,
+) -> Result<ConversionReport, ConvertError> {
     let input = PathBuf::from(&request.input_path);
     let output = PathBuf::from(&request.output_path);
 
-    let report =
-        convert_vrm_to_gdb(&input, &output, request.options).map_err(|err| err.to_string())?;
+    let token = cancellation::register(&request.request_id);
+    let result = convert_vrm_to_gdb(&input, &output, request.options, progress, Some(&token));
+    cancellation::unregister(&request.request_id);
+    let report = result.map_err(ConvertError::from)?;
 
     if request.notify_on_complete {
         let _ = send_desktop_notification("vrm2sl", "Conversion completed");
@@ -76,12 +149,113 @@ pub fn convert_vrm_to_gdb_ipc(request: ConvertRequest) -> Result<ConversionRepor
     Ok(report)
 }
 
+/// Signal cancellation for an in-flight [`convert_vrm_to_gdb_ipc`] call
+/// through the IPC boundary. Returns `false` if no conversion is currently
+/// registered under `request_id` (e.g. it already finished).
+pub fn cancel_conversion_ipc(request_id: &str) -> bool {
+    cancellation::cancel(request_id)
+}
+
+/// Convert a batch of files through the IPC boundary under one shared set of
+/// options, continuing past a single file's failure instead of aborting the
+/// rest (c.f. [`convert_vrm_to_gdb_ipc`], which fails the whole request).
+pub fn convert_vrm_batch_ipc(
+    request: ConvertBatchRequest,
+    mut progress: Option<&mut BatchProgressCallback>,
+) -> Vec<BatchConversionOutcome> {
+    let total_files = request.files.len();
+    let mut outcomes = Vec::with_capacity(total_files);
+
+    for (file_index, file) in request.files.into_iter().enumerate() {
+        let convert_request = ConvertRequest {
+            input_path: file.input_path.clone(),
+            output_path: file.output_path.clone(),
+            options: request.options.clone(),
+            notify_on_complete: false,
+            // Batch files aren't individually cancellable yet, so a
+            // throwaway id that can't collide across this loop's sequential
+            // register/unregister calls is enough.
+            request_id: format!("batch-file-{file_index}"),
+        };
+
+        let mut stage_progress = progress.as_deref_mut().map(|cb| {
+            move |stage: ConversionStage, fraction: f32| {
+                cb(file_index, total_files, stage, fraction)
+            }
+        });
+
+        let result = convert_vrm_to_gdb_ipc(
+            convert_request,
+            stage_progress
+                .as_mut()
+                .map(|cb| cb as &mut dyn FnMut(ConversionStage, f32)),
+        );
+
+        outcomes.push(match result {
+            Ok(report) => BatchConversionOutcome {
+                input_path: file.input_path,
+                output_path: file.output_path,
+                report: Some(report),
+                error: None,
+            },
+            Err(error) => BatchConversionOutcome {
+                input_path: file.input_path,
+                output_path: file.output_path,
+                report: None,
+                error: Some(error),
+            },
+        });
+    }
+
+    if request.notify_on_complete {
+        let _ = send_desktop_notification("vrm2sl", "Batch conversion completed");
+    }
+
+    outcomes
+}
+
+/// Validate a file's skin data through the IPC boundary, without requiring
+/// it to be convertible for SL.
+pub fn validate_skinning_ipc(
+    request: SkinDiagnosticsRequest,
+) -> Result<Vec<ValidationIssue>, ConvertError> {
+    let input = PathBuf::from(&request.input_path);
+    validate_skinning(&input).map_err(ConvertError::from)
+}
+
+/// Repair a file's skin data through the IPC boundary and write it to
+/// `output_path`.
+pub fn repair_skinning_ipc(
+    request: RepairSkinningRequest,
+) -> Result<SkinRepairStats, ConvertError> {
+    let input = PathBuf::from(&request.input_path);
+    let output = PathBuf::from(&request.output_path);
+    repair_skinning(&input, &output).map_err(ConvertError::from)
+}
+
+/// Dump a file's skin usage statistics through the IPC boundary.
+pub fn dump_skinning_ipc(request: SkinDiagnosticsRequest) -> Result<SkinDumpReport, ConvertError> {
+    let input = PathBuf::from(&request.input_path);
+    dump_skinning(&input).map_err(ConvertError::from)
+}
+
+/// Dump a file's auto-detected humanoid bone mapping through the IPC
+/// boundary, in the `{ role: sourceNodeNameOrIndex }` shape a user can save,
+/// hand-edit, and feed back in as [`ConvertOptions::bone_map_override_path`].
+pub fn dump_bone_map_ipc(
+    request: SkinDiagnosticsRequest,
+) -> Result<HashMap<String, String>, ConvertError> {
+    let input = PathBuf::from(&request.input_path);
+    dump_bone_map(&input).map_err(ConvertError::from)
+}
+
 /// Build a preview GLB file through the IPC boundary and return its path.
 pub fn build_preview_glb_ipc(request: PreviewRequest) -> Result<String, String> {
     let input = PathBuf::from(&request.input_path);
     let output = create_preview_output_path().map_err(|err| err.to_string())?;
 
-    convert_vrm_to_gdb(&input, &output, request.options).map_err(|err| err.to_string())?;
+    convert_vrm_to_gdb(&input, &output, request.options, None, None)
+        .map_err(|err| err.to_string())?;
 
     Ok(output.to_string_lossy().to_string())
 }
@@ -2,9 +2,14 @@
 //!
 //! A modern desktop application built with Tauri v2 and Vue 3.
 
+pub mod batch;
+pub mod bench;
+pub mod cancellation;
 pub mod convert;
 pub mod correction;
 mod error;
+#[cfg(feature = "gpu")]
+pub mod gpu_resize;
 pub mod ipc;
 mod logging;
 pub mod notify;
@@ -16,7 +16,13 @@ fn main() {
         .invoke_handler(tauri::generate_handler![
             command::analyze_vrm_command,
             command::convert_vrm_command,
+            command::cancel_conversion_command,
+            command::convert_vrm_batch_command,
             command::build_preview_glb_command,
+            command::validate_skinning_command,
+            command::repair_skinning_command,
+            command::dump_skinning_command,
+            command::dump_bone_map_command,
             command::save_project_settings_command,
             command::load_project_settings_command,
             command::get_app_version,
@@ -3,6 +3,10 @@ use std::process::Command;
 use anyhow::Result;
 
 /// Send a desktop notification for the current platform.
+///
+/// Best-effort: a missing notification daemon/binary on the target platform
+/// is not treated as a conversion failure, so this only ever returns `Err`
+/// for programmer errors in argument handling, not "no notifier available".
 pub fn send_desktop_notification(title: &str, body: &str) -> Result<()> {
     #[cfg(target_os = "macos")]
     {
@@ -15,11 +19,46 @@ pub fn send_desktop_notification(title: &str, body: &str) -> Result<()> {
         let _ = Command::new("osascript").arg("-e").arg(script).status();
     }
 
+    #[cfg(target_os = "linux")]
+    {
+        // Passed as separate argv entries (no shell involved), so no
+        // escaping is needed here unlike the AppleScript/PowerShell paths
+        // below, which interpolate into a script string.
+        let _ = Command::new("notify-send").arg(title).arg(body).status();
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let script = format!(
+            "[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null; \
+             $template = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02); \
+             $text = $template.GetElementsByTagName('text'); \
+             $text.Item(0).AppendChild($template.CreateTextNode('{}')) > $null; \
+             $text.Item(1).AppendChild($template.CreateTextNode('{}')) > $null; \
+             $toast = [Windows.UI.Notifications.ToastNotification]::new($template); \
+             [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('vrm2sl').Show($toast)",
+            escape_powershell_string(title),
+            escape_powershell_string(body)
+        );
+
+        let _ = Command::new("powershell")
+            .arg("-NoProfile")
+            .arg("-Command")
+            .arg(script)
+            .status();
+    }
+
     Ok(())
 }
 
 #[cfg(target_os = "macos")]
-/// Escape text for safe insertion into AppleScript string literals.
+/// Escape text for safe insertion into AppleScript double-quoted string literals.
 fn escape_applescript_string(input: &str) -> String {
     input.replace('\\', "\\\\").replace('"', "\\\"")
 }
+
+#[cfg(target_os = "windows")]
+/// Escape text for safe insertion into PowerShell single-quoted string literals.
+fn escape_powershell_string(input: &str) -> String {
+    input.replace('\'', "''")
+}
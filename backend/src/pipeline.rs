@@ -1,13 +1,88 @@
 use std::collections::HashMap;
 
-use nalgebra::{Matrix4, UnitQuaternion, Vector3};
+use nalgebra::{Matrix4, Quaternion, UnitQuaternion, Vector3};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::correction::{
-    apply_corrected_rotation, compute_pose_correction, correct_vertex_with_inverse,
-    rebuild_inverse_bind_matrix,
+    apply_corrected_rotation, compute_pose_correction_weighted, correct_vertex_with_inverse,
+    pseudo_inverse_bind_matrix, rebuild_inverse_bind_matrix,
 };
 
+/// Options controlling how the correction pipeline rebuilds inverse bind
+/// matrices, shared by every node in a [`correct_nodes_to_t_pose`] batch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CorrectionOptions {
+    /// Fraction of the largest singular value below which a singular value
+    /// is treated as zero when falling back to
+    /// [`crate::correction::pseudo_inverse_bind_matrix`].
+    pub singular_tolerance: f32,
+    /// When `true`, a node whose bind matrix isn't cleanly invertible falls
+    /// back to an SVD pseudo-inverse instead of failing the whole batch with
+    /// [`PipelineError::NonInvertibleBindMatrix`].
+    pub allow_pseudo_inverse: bool,
+}
+
+impl Default for CorrectionOptions {
+    fn default() -> Self {
+        Self {
+            singular_tolerance: 1e-6,
+            allow_pseudo_inverse: false,
+        }
+    }
+}
+
+/// Rebuilds an inverse bind matrix, falling back to an SVD pseudo-inverse per
+/// `options` when the exact inverse doesn't exist. Returns `None` only when
+/// the exact inverse fails and the fallback is disallowed.
+///
+/// # Returns
+///
+/// `Some((inverse_bind_matrix, used_pseudo_inverse))`.
+fn rebuild_inverse_bind_matrix_with_options(
+    parent_world_matrix: Matrix4<f32>,
+    local_transform_matrix: Matrix4<f32>,
+    options: &CorrectionOptions,
+) -> Option<(Matrix4<f32>, bool)> {
+    if let Some(inverse) = rebuild_inverse_bind_matrix(parent_world_matrix, local_transform_matrix) {
+        return Some((inverse, false));
+    }
+    if !options.allow_pseudo_inverse {
+        return None;
+    }
+    let bind_matrix = parent_world_matrix * local_transform_matrix;
+    Some((
+        pseudo_inverse_bind_matrix(bind_matrix, options.singular_tolerance),
+        true,
+    ))
+}
+
+/// Recomputes `local_transform_matrix` with its rotation swapped from
+/// `current_local_rotation` to `corrected_local_rotation`, leaving
+/// translation and scale untouched.
+///
+/// For a glTF-style `local_transform_matrix = T * R * S`, left-multiplying
+/// the whole 4x4 matrix by a rotation delta would also rotate the
+/// translation column, so only the upper-left 3x3 linear block is replaced
+/// by `delta * linear_block`, where
+/// `delta = corrected_local_rotation * current_local_rotation.inverse()`.
+fn apply_rotation_delta_to_local_matrix(
+    local_transform_matrix: Matrix4<f32>,
+    current_local_rotation: UnitQuaternion<f32>,
+    corrected_local_rotation: UnitQuaternion<f32>,
+) -> Matrix4<f32> {
+    let delta = (corrected_local_rotation * current_local_rotation.inverse()).to_rotation_matrix();
+    let mut corrected = local_transform_matrix;
+    for row in 0..3 {
+        for col in 0..3 {
+            corrected[(row, col)] = (0..3)
+                .map(|k| delta[(row, k)] * local_transform_matrix[(k, col)])
+                .sum();
+        }
+    }
+    corrected
+}
+
 #[derive(Debug, Clone)]
 /// Input payload for correcting a single bone from A-pose to T-pose.
 pub struct BoneCorrectionInput {
@@ -15,6 +90,12 @@ pub struct BoneCorrectionInput {
     pub current_local_rotation: UnitQuaternion<f32>,
     /// Desired local-space rotation representing target T-pose.
     pub target_t_pose_rotation: UnitQuaternion<f32>,
+    /// Blend factor from identity (`0.0`) to the full correction (`1.0`); see
+    /// [`crate::correction::compute_pose_correction_weighted`]. `1.0` matches
+    /// this struct's pre-weighting behavior exactly.
+    pub weight: f32,
+    /// Optional clamp, in radians, on the blended correction's rotation angle.
+    pub max_angle: Option<f32>,
     /// Parent node world transform matrix.
     pub parent_world_matrix: Matrix4<f32>,
     /// Current node local transform matrix.
@@ -32,6 +113,10 @@ pub struct BoneCorrectionResult {
     pub corrected_vertices: Vec<Vector3<f32>>,
     /// Rebuilt inverse bind matrix for skinning.
     pub inverse_bind_matrix: Matrix4<f32>,
+    /// True when `inverse_bind_matrix` came from
+    /// [`crate::correction::pseudo_inverse_bind_matrix`] rather than an exact
+    /// inverse, because the bind matrix wasn't cleanly invertible.
+    pub used_pseudo_inverse: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -41,11 +126,26 @@ pub struct NodeCorrectionInput {
     pub node_index: usize,
     /// Node name used for target-map lookup.
     pub node_name: String,
+    /// `node_index` of this node's parent in the bone hierarchy, or `None`
+    /// for a root. Only consulted by [`correct_skeleton_to_t_pose`]; ignored
+    /// by [`correct_nodes_to_t_pose`], which treats `parent_world_matrix` as
+    /// already authoritative.
+    pub parent_index: Option<usize>,
     /// Current local-space node rotation.
     pub current_local_rotation: UnitQuaternion<f32>,
     /// Optional target local rotation; when `None`, rotation is kept as-is.
     pub target_t_pose_rotation: Option<UnitQuaternion<f32>>,
-    /// Parent node world transform matrix.
+    /// Blend factor from identity (`0.0`) to the full correction (`1.0`); see
+    /// [`BoneCorrectionInput::weight`]. Ignored when `target_t_pose_rotation`
+    /// is `None`.
+    pub weight: f32,
+    /// Optional clamp, in radians, on the blended correction's rotation angle.
+    /// Ignored when `target_t_pose_rotation` is `None`.
+    pub max_angle: Option<f32>,
+    /// Parent node world transform matrix. Used directly by
+    /// [`correct_nodes_to_t_pose`]; used only as the root fallback (when
+    /// `parent_index` is `None`) by [`correct_skeleton_to_t_pose`], which
+    /// otherwise recomputes it from the parent's corrected world matrix.
     pub parent_world_matrix: Matrix4<f32>,
     /// Current node local transform matrix.
     pub local_transform_matrix: Matrix4<f32>,
@@ -60,6 +160,9 @@ pub struct ParsedNodeTransform {
     pub node_index: usize,
     /// Parsed node name.
     pub node_name: String,
+    /// `node_index` of this node's parent in the bone hierarchy, or `None`
+    /// for a root.
+    pub parent_index: Option<usize>,
     /// Parsed current local-space rotation.
     pub current_local_rotation: UnitQuaternion<f32>,
     /// Parsed parent world matrix.
@@ -92,6 +195,17 @@ pub struct NodeCorrectionResult {
     pub inverse_bind_matrix: Matrix4<f32>,
     /// True when a T-pose rotation correction was applied.
     pub was_corrected: bool,
+    /// True when `inverse_bind_matrix` came from an SVD pseudo-inverse
+    /// fallback rather than an exact inverse; see
+    /// [`BoneCorrectionResult::used_pseudo_inverse`]. The frontend should
+    /// warn on this rather than treat the node as fully reliable.
+    pub used_pseudo_inverse: bool,
+    /// World transform matrix after correction, i.e.
+    /// `parent_corrected_world_matrix * corrected_local_transform_matrix`.
+    /// Only meaningfully propagated down a chain by
+    /// [`correct_skeleton_to_t_pose`]; [`correct_nodes_to_t_pose`] computes it
+    /// per-node from the given (unpropagated) `parent_world_matrix`.
+    pub corrected_world_matrix: Matrix4<f32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
@@ -108,6 +222,37 @@ pub enum PipelineError {
         /// Name of the node whose bind matrix could not be inverted.
         node_name: String,
     },
+    /// Returned by [`correct_skeleton_to_t_pose`] when a node's
+    /// `parent_index` does not match the `node_index` of any node in the
+    /// same batch.
+    #[error("node {node_index} ({node_name}) declares missing parent_index {parent_index}")]
+    MissingParentNode {
+        /// Index of the node with the dangling parent reference.
+        node_index: usize,
+        /// Name of the node with the dangling parent reference.
+        node_name: String,
+        /// The `parent_index` that could not be resolved.
+        parent_index: usize,
+    },
+    /// Returned by [`correct_skeleton_to_t_pose`] when following
+    /// `parent_index` links forms a cycle instead of reaching a root.
+    #[error("node {node_index} ({node_name}) is part of a cyclic bone hierarchy")]
+    CyclicHierarchy {
+        /// Index of a node on the detected cycle.
+        node_index: usize,
+        /// Name of a node on the detected cycle.
+        node_name: String,
+    },
+    /// Returned by [`apply_correction_clip`] when the clip's skeleton
+    /// fingerprint doesn't match the incoming nodes' names, meaning the clip
+    /// was baked against a different skeleton and can't be safely replayed.
+    #[error("correction clip skeleton fingerprint mismatch: expected {expected:?}, got {actual:?}")]
+    SkeletonFingerprintMismatch {
+        /// Ordered node names the clip was baked against.
+        expected: Vec<String>,
+        /// Ordered node names of the nodes the clip was applied to.
+        actual: Vec<String>,
+    },
 }
 
 /// Applies A-pose to T-pose correction for a single bone and regenerates
@@ -116,14 +261,22 @@ pub enum PipelineError {
 /// # Arguments
 ///
 /// * `input` - Single-bone correction payload containing transforms and vertices.
+/// * `options` - Controls the SVD pseudo-inverse fallback; see [`CorrectionOptions`].
 ///
 /// # Returns
 ///
-/// `Some(BoneCorrectionResult)` when the bind matrix is invertible,
-/// otherwise `None`.
-pub fn correct_bone_to_t_pose(input: BoneCorrectionInput) -> Option<BoneCorrectionResult> {
-    let correction =
-        compute_pose_correction(input.current_local_rotation, input.target_t_pose_rotation);
+/// `Some(BoneCorrectionResult)` when the bind matrix is invertible (or
+/// `options.allow_pseudo_inverse` is set), otherwise `None`.
+pub fn correct_bone_to_t_pose(
+    input: BoneCorrectionInput,
+    options: &CorrectionOptions,
+) -> Option<BoneCorrectionResult> {
+    let correction = compute_pose_correction_weighted(
+        input.current_local_rotation,
+        input.target_t_pose_rotation,
+        input.weight,
+        input.max_angle,
+    );
 
     let corrected_local_rotation =
         apply_corrected_rotation(input.current_local_rotation, correction);
@@ -134,13 +287,17 @@ pub fn correct_bone_to_t_pose(input: BoneCorrectionInput) -> Option<BoneCorrecti
         .map(|v| correct_vertex_with_inverse(v, correction))
         .collect();
 
-    let inverse_bind_matrix =
-        rebuild_inverse_bind_matrix(input.parent_world_matrix, input.local_transform_matrix)?;
+    let (inverse_bind_matrix, used_pseudo_inverse) = rebuild_inverse_bind_matrix_with_options(
+        input.parent_world_matrix,
+        input.local_transform_matrix,
+        options,
+    )?;
 
     Some(BoneCorrectionResult {
         corrected_local_rotation,
         corrected_vertices,
         inverse_bind_matrix,
+        used_pseudo_inverse,
     })
 }
 
@@ -153,6 +310,7 @@ pub fn correct_bone_to_t_pose(input: BoneCorrectionInput) -> Option<BoneCorrecti
 /// # Arguments
 ///
 /// * `nodes` - Node correction inputs produced from parsed scene data.
+/// * `options` - Controls the SVD pseudo-inverse fallback; see [`CorrectionOptions`].
 ///
 /// # Returns
 ///
@@ -161,9 +319,10 @@ pub fn correct_bone_to_t_pose(input: BoneCorrectionInput) -> Option<BoneCorrecti
 /// # Errors
 ///
 /// Returns `PipelineError::NonInvertibleBindMatrix` when a bind matrix cannot
-/// be inverted for any node.
+/// be inverted for any node and `options.allow_pseudo_inverse` is `false`.
 pub fn correct_nodes_to_t_pose(
     nodes: Vec<NodeCorrectionInput>,
+    options: &CorrectionOptions,
 ) -> Result<Vec<NodeCorrectionResult>, PipelineError> {
     nodes
         .into_iter()
@@ -172,12 +331,14 @@ pub fn correct_nodes_to_t_pose(
                 let input = BoneCorrectionInput {
                     current_local_rotation: node.current_local_rotation,
                     target_t_pose_rotation,
+                    weight: node.weight,
+                    max_angle: node.max_angle,
                     parent_world_matrix: node.parent_world_matrix,
                     local_transform_matrix: node.local_transform_matrix,
                     vertices: node.vertices,
                 };
 
-                let result = correct_bone_to_t_pose(input).ok_or(
+                let result = correct_bone_to_t_pose(input, options).ok_or(
                     PipelineError::NonInvertibleBindMatrix {
                         phase: "targeted_node_correction",
                         node_index: node.node_index,
@@ -185,24 +346,36 @@ pub fn correct_nodes_to_t_pose(
                     },
                 )?;
 
+                let corrected_local_matrix = apply_rotation_delta_to_local_matrix(
+                    node.local_transform_matrix,
+                    node.current_local_rotation,
+                    result.corrected_local_rotation,
+                );
+
                 Ok(NodeCorrectionResult {
                     node_index: node.node_index,
                     node_name: node.node_name,
                     corrected_local_rotation: result.corrected_local_rotation,
                     corrected_vertices: result.corrected_vertices,
                     inverse_bind_matrix: result.inverse_bind_matrix,
-                    was_corrected: true,
+                    // A zero-weight target blends to an identity correction
+                    // (i.e. a no-op), so it shouldn't be reported as corrected.
+                    was_corrected: node.weight > 0.0,
+                    used_pseudo_inverse: result.used_pseudo_inverse,
+                    corrected_world_matrix: node.parent_world_matrix * corrected_local_matrix,
                 })
             } else {
-                let inverse_bind_matrix = rebuild_inverse_bind_matrix(
-                    node.parent_world_matrix,
-                    node.local_transform_matrix,
-                )
-                .ok_or(PipelineError::NonInvertibleBindMatrix {
-                    phase: "passthrough_inverse_bind_rebuild",
-                    node_index: node.node_index,
-                    node_name: node.node_name.clone(),
-                })?;
+                let (inverse_bind_matrix, used_pseudo_inverse) =
+                    rebuild_inverse_bind_matrix_with_options(
+                        node.parent_world_matrix,
+                        node.local_transform_matrix,
+                        options,
+                    )
+                    .ok_or(PipelineError::NonInvertibleBindMatrix {
+                        phase: "passthrough_inverse_bind_rebuild",
+                        node_index: node.node_index,
+                        node_name: node.node_name.clone(),
+                    })?;
 
                 Ok(NodeCorrectionResult {
                     node_index: node.node_index,
@@ -211,12 +384,196 @@ pub fn correct_nodes_to_t_pose(
                     corrected_vertices: node.vertices,
                     inverse_bind_matrix,
                     was_corrected: false,
+                    used_pseudo_inverse,
+                    corrected_world_matrix: node.parent_world_matrix * node.local_transform_matrix,
                 })
             }
         })
         .collect()
 }
 
+/// Like [`correct_nodes_to_t_pose`], but hierarchy-aware: correcting a
+/// parent's local rotation changes the world transform of every descendant,
+/// so this builds the bone tree from each node's `parent_index`, processes
+/// nodes root-first, and feeds each node's recomputed corrected world matrix
+/// as the `parent_world_matrix` of its children before they are corrected.
+/// This makes multi-bone chains (e.g. shoulder -> elbow -> wrist) produce a
+/// geometrically consistent result instead of per-bone-local approximations.
+///
+/// A root node (`parent_index: None`) uses its own `parent_world_matrix` as
+/// given, unmodified.
+///
+/// # Arguments
+///
+/// * `nodes` - Node correction inputs, each carrying its `parent_index` into this same batch.
+/// * `options` - Controls the SVD pseudo-inverse fallback; see [`CorrectionOptions`].
+///
+/// # Returns
+///
+/// Corrected node outputs for all inputs, in original order.
+///
+/// # Errors
+///
+/// Returns [`PipelineError::MissingParentNode`] when a `parent_index` does
+/// not match any node in `nodes`, [`PipelineError::CyclicHierarchy`] when the
+/// `parent_index` links form a cycle, and
+/// [`PipelineError::NonInvertibleBindMatrix`] under the same conditions as
+/// [`correct_nodes_to_t_pose`].
+pub fn correct_skeleton_to_t_pose(
+    nodes: Vec<NodeCorrectionInput>,
+    options: &CorrectionOptions,
+) -> Result<Vec<NodeCorrectionResult>, PipelineError> {
+    let position_by_node_index: HashMap<usize, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, node)| (node.node_index, position))
+        .collect();
+
+    for node in &nodes {
+        if let Some(parent_index) = node.parent_index {
+            if !position_by_node_index.contains_key(&parent_index) {
+                return Err(PipelineError::MissingParentNode {
+                    node_index: node.node_index,
+                    node_name: node.node_name.clone(),
+                    parent_index,
+                });
+            }
+        }
+    }
+
+    let topo_order = topological_order_root_first(&nodes, &position_by_node_index)?;
+
+    let mut corrected_world_matrices: HashMap<usize, Matrix4<f32>> = HashMap::new();
+    let mut results: Vec<Option<NodeCorrectionResult>> = nodes.iter().map(|_| None).collect();
+
+    for position in topo_order {
+        let node = nodes[position].clone();
+        let parent_world_matrix = match node.parent_index {
+            Some(parent_index) => corrected_world_matrices[&parent_index],
+            None => node.parent_world_matrix,
+        };
+
+        let result = if let Some(target_t_pose_rotation) = node.target_t_pose_rotation {
+            let input = BoneCorrectionInput {
+                current_local_rotation: node.current_local_rotation,
+                target_t_pose_rotation,
+                weight: node.weight,
+                max_angle: node.max_angle,
+                parent_world_matrix,
+                local_transform_matrix: node.local_transform_matrix,
+                vertices: node.vertices.clone(),
+            };
+
+            let bone_result = correct_bone_to_t_pose(input, options).ok_or(
+                PipelineError::NonInvertibleBindMatrix {
+                    phase: "skeleton_node_correction",
+                    node_index: node.node_index,
+                    node_name: node.node_name.clone(),
+                },
+            )?;
+
+            let corrected_local_matrix = apply_rotation_delta_to_local_matrix(
+                node.local_transform_matrix,
+                node.current_local_rotation,
+                bone_result.corrected_local_rotation,
+            );
+
+            NodeCorrectionResult {
+                node_index: node.node_index,
+                node_name: node.node_name.clone(),
+                corrected_local_rotation: bone_result.corrected_local_rotation,
+                corrected_vertices: bone_result.corrected_vertices,
+                inverse_bind_matrix: bone_result.inverse_bind_matrix,
+                was_corrected: node.weight > 0.0,
+                used_pseudo_inverse: bone_result.used_pseudo_inverse,
+                corrected_world_matrix: parent_world_matrix * corrected_local_matrix,
+            }
+        } else {
+            let (inverse_bind_matrix, used_pseudo_inverse) =
+                rebuild_inverse_bind_matrix_with_options(
+                    parent_world_matrix,
+                    node.local_transform_matrix,
+                    options,
+                )
+                .ok_or(PipelineError::NonInvertibleBindMatrix {
+                    phase: "skeleton_passthrough_inverse_bind_rebuild",
+                    node_index: node.node_index,
+                    node_name: node.node_name.clone(),
+                })?;
+
+            NodeCorrectionResult {
+                node_index: node.node_index,
+                node_name: node.node_name.clone(),
+                corrected_local_rotation: node.current_local_rotation,
+                corrected_vertices: node.vertices.clone(),
+                inverse_bind_matrix,
+                was_corrected: false,
+                used_pseudo_inverse,
+                corrected_world_matrix: parent_world_matrix * node.local_transform_matrix,
+            }
+        };
+
+        corrected_world_matrices.insert(node.node_index, result.corrected_world_matrix);
+        results[position] = Some(result);
+    }
+
+    Ok(results
+        .into_iter()
+        .map(|result| result.expect("every node is visited exactly once in topological order"))
+        .collect())
+}
+
+/// Orders `nodes` (by position in the slice) so every node appears after its
+/// parent, via a depth-first visit that walks each node's ancestor chain
+/// before emitting it. Assumes every `parent_index` has already been
+/// validated to resolve within `nodes`.
+fn topological_order_root_first(
+    nodes: &[NodeCorrectionInput],
+    position_by_node_index: &HashMap<usize, usize>,
+) -> Result<Vec<usize>, PipelineError> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum VisitState {
+        Unvisited,
+        Visiting,
+        Done,
+    }
+
+    fn visit(
+        position: usize,
+        nodes: &[NodeCorrectionInput],
+        position_by_node_index: &HashMap<usize, usize>,
+        state: &mut [VisitState],
+        order: &mut Vec<usize>,
+    ) -> Result<(), PipelineError> {
+        match state[position] {
+            VisitState::Done => return Ok(()),
+            VisitState::Visiting => {
+                return Err(PipelineError::CyclicHierarchy {
+                    node_index: nodes[position].node_index,
+                    node_name: nodes[position].node_name.clone(),
+                });
+            }
+            VisitState::Unvisited => {}
+        }
+
+        state[position] = VisitState::Visiting;
+        if let Some(parent_index) = nodes[position].parent_index {
+            let parent_position = position_by_node_index[&parent_index];
+            visit(parent_position, nodes, position_by_node_index, state, order)?;
+        }
+        state[position] = VisitState::Done;
+        order.push(position);
+        Ok(())
+    }
+
+    let mut state = vec![VisitState::Unvisited; nodes.len()];
+    let mut order = Vec::with_capacity(nodes.len());
+    for position in 0..nodes.len() {
+        visit(position, nodes, position_by_node_index, &mut state, &mut order)?;
+    }
+    Ok(order)
+}
+
 /// Builds default target local rotations for SL-style upper-limb T-pose bones.
 ///
 /// This map is intended as a practical starter set for shoulder/arm correction
@@ -245,6 +602,77 @@ pub fn build_default_upper_limb_t_pose_targets() -> HashMap<String, UnitQuaterni
     targets
 }
 
+/// Naming conventions recognized by [`mirror_target_across_sagittal`], tried
+/// in order. Each pair covers both directions of the swap.
+const SAGITTAL_NAMING_CONVENTIONS: &[(&str, &str)] = &[("Left", "Right"), ("left", "right")];
+
+/// Mirrors a named target rotation across the sagittal (X-normal) plane,
+/// turning a rotation authored for one limb into its geometric mirror on the
+/// opposite limb, so [`complete_symmetric_targets`] can synthesize a full map
+/// from just one side.
+///
+/// # Arguments
+///
+/// * `name` - Node/bone name, expected to contain a recognized left/right marker
+///   (e.g. `mShoulderLeft`, `leftUpperArm`).
+/// * `rotation` - Target local-space rotation authored for `name`'s side.
+///
+/// # Returns
+///
+/// `Some((mirrored_name, mirrored_rotation))` when `name` contains a
+/// recognized left/right marker, otherwise `None`.
+pub fn mirror_target_across_sagittal(
+    name: &str,
+    rotation: UnitQuaternion<f32>,
+) -> Option<(String, UnitQuaternion<f32>)> {
+    let mirrored_name = SAGITTAL_NAMING_CONVENTIONS.iter().find_map(|(left, right)| {
+        if name.contains(left) {
+            Some(name.replacen(left, right, 1))
+        } else if name.contains(right) {
+            Some(name.replacen(right, left, 1))
+        } else {
+            None
+        }
+    })?;
+
+    let q = rotation.quaternion();
+    let mirrored_rotation =
+        UnitQuaternion::from_quaternion(Quaternion::new(q.w, q.i, -q.j, -q.k));
+
+    Some((mirrored_name, mirrored_rotation))
+}
+
+/// Fills in the opposite side of every left/right-named entry in `targets`
+/// that's missing one, via [`mirror_target_across_sagittal`], so a caller can
+/// author just one side of a symmetric pose and get a guaranteed-symmetric
+/// full map. Entries the caller already specified for both sides are left
+/// untouched; entries with no recognized left/right marker pass through
+/// unmirrored.
+///
+/// # Arguments
+///
+/// * `targets` - Target rotation map, possibly missing one side of some entries.
+///
+/// # Returns
+///
+/// A new map containing every input entry plus any synthesized mirrors.
+pub fn complete_symmetric_targets(
+    targets: &HashMap<String, UnitQuaternion<f32>>,
+) -> HashMap<String, UnitQuaternion<f32>> {
+    let mut completed = targets.clone();
+
+    for (name, rotation) in targets {
+        let Some((mirrored_name, mirrored_rotation)) =
+            mirror_target_across_sagittal(name, *rotation)
+        else {
+            continue;
+        };
+        completed.entry(mirrored_name).or_insert(mirrored_rotation);
+    }
+
+    completed
+}
+
 /// Resolves a node name to an optional target local rotation.
 ///
 /// # Arguments
@@ -266,12 +694,15 @@ pub fn resolve_target_t_pose_rotation(
 /// geometry buffers.
 ///
 /// Any node without an entry in `node_geometries` receives an empty vertex list.
+/// Any node without an entry in `weights` defaults to `1.0` (a full snap to
+/// the target T-pose), matching this function's pre-weighting behavior.
 ///
 /// # Arguments
 ///
 /// * `node_transforms` - Parsed transform records for each node.
 /// * `node_geometries` - Optional parsed geometry grouped by node index.
 /// * `targets` - Target rotation map used to resolve T-pose corrections.
+/// * `weights` - Per-bone blend weight map; see [`BoneCorrectionInput::weight`].
 ///
 /// # Returns
 ///
@@ -280,6 +711,7 @@ pub fn build_node_correction_inputs(
     node_transforms: Vec<ParsedNodeTransform>,
     node_geometries: Vec<ParsedNodeGeometry>,
     targets: &HashMap<String, UnitQuaternion<f32>>,
+    weights: &HashMap<String, f32>,
 ) -> Vec<NodeCorrectionInput> {
     let geometry_map: HashMap<usize, Vec<Vector3<f32>>> = node_geometries
         .into_iter()
@@ -290,7 +722,10 @@ pub fn build_node_correction_inputs(
         .into_iter()
         .map(|transform| NodeCorrectionInput {
             node_index: transform.node_index,
+            parent_index: transform.parent_index,
             target_t_pose_rotation: resolve_target_t_pose_rotation(&transform.node_name, targets),
+            weight: weights.get(&transform.node_name).copied().unwrap_or(1.0),
+            max_angle: None,
             vertices: geometry_map
                 .get(&transform.node_index)
                 .cloned()
@@ -303,6 +738,149 @@ pub fn build_node_correction_inputs(
         .collect()
 }
 
+/// A single node's baked correction, captured independently of the specific
+/// vertex buffers or weight/target maps that produced it, so it can be
+/// replayed onto other nodes sharing the same rest pose (different outfits,
+/// LODs) via [`apply_correction_clip`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrectionClipEntry {
+    /// Node name this entry was baked for.
+    pub node_name: String,
+    /// Correction quaternion, as `(w, i, j, k)`, such that
+    /// `correction * current_local_rotation == corrected_local_rotation`.
+    pub correction: [f32; 4],
+    /// Rebuilt inverse bind matrix, column-major (matches `Matrix4::as_slice`).
+    pub inverse_bind_matrix: [f32; 16],
+    /// Mirrors [`NodeCorrectionResult::was_corrected`] from the bake.
+    pub was_corrected: bool,
+    /// Mirrors [`NodeCorrectionResult::used_pseudo_inverse`] from the bake.
+    pub used_pseudo_inverse: bool,
+}
+
+/// A reusable, serializable set of node corrections, baked once from
+/// [`correct_nodes_to_t_pose`]'s output and replayable onto other VRM
+/// variants sharing the same skeleton via [`apply_correction_clip`], without
+/// recomputing [`crate::correction::compute_pose_correction_weighted`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrectionClip {
+    /// Ordered node names this clip was baked against; `apply_correction_clip`
+    /// rejects any node list whose names don't match exactly, in order.
+    pub skeleton_fingerprint: Vec<String>,
+    /// Per-node baked corrections, in the same order as `skeleton_fingerprint`.
+    pub entries: Vec<CorrectionClipEntry>,
+}
+
+/// Bakes `results`/`inputs` (same order, as produced by and passed to
+/// [`correct_nodes_to_t_pose`]) into a reusable [`CorrectionClip`].
+///
+/// # Arguments
+///
+/// * `results` - Correction outputs to bake, in original node order.
+/// * `inputs` - The inputs that produced `results`, in the same order.
+///
+/// # Returns
+///
+/// A clip capturing each node's correction delta and rebuilt inverse bind
+/// matrix, independent of the vertex buffers used to compute it.
+pub fn bake_correction_clip(
+    results: &[NodeCorrectionResult],
+    inputs: &[NodeCorrectionInput],
+) -> CorrectionClip {
+    let skeleton_fingerprint = inputs.iter().map(|input| input.node_name.clone()).collect();
+
+    let entries = results
+        .iter()
+        .zip(inputs)
+        .map(|(result, input)| {
+            let correction =
+                result.corrected_local_rotation * input.current_local_rotation.inverse();
+            let q = correction.quaternion();
+
+            CorrectionClipEntry {
+                node_name: result.node_name.clone(),
+                correction: [q.w, q.i, q.j, q.k],
+                inverse_bind_matrix: result
+                    .inverse_bind_matrix
+                    .as_slice()
+                    .try_into()
+                    .expect("a 4x4 matrix has exactly 16 elements"),
+                was_corrected: result.was_corrected,
+                used_pseudo_inverse: result.used_pseudo_inverse,
+            }
+        })
+        .collect();
+
+    CorrectionClip { skeleton_fingerprint, entries }
+}
+
+/// Replays a baked [`CorrectionClip`] onto `nodes`, reusing each node's
+/// stored correction quaternion and inverse bind matrix instead of
+/// recomputing them from target/weight maps.
+///
+/// # Arguments
+///
+/// * `clip` - Previously baked correction clip.
+/// * `nodes` - Node inputs to apply the clip to; must match
+///   `clip.skeleton_fingerprint` exactly, in order.
+///
+/// # Returns
+///
+/// Corrected node outputs for all inputs, in original order.
+///
+/// # Errors
+///
+/// Returns [`PipelineError::SkeletonFingerprintMismatch`] when `nodes`' names
+/// don't match `clip.skeleton_fingerprint` exactly, in order.
+pub fn apply_correction_clip(
+    clip: &CorrectionClip,
+    nodes: Vec<NodeCorrectionInput>,
+) -> Result<Vec<NodeCorrectionResult>, PipelineError> {
+    let actual_fingerprint: Vec<String> =
+        nodes.iter().map(|node| node.node_name.clone()).collect();
+    if actual_fingerprint != clip.skeleton_fingerprint {
+        return Err(PipelineError::SkeletonFingerprintMismatch {
+            expected: clip.skeleton_fingerprint.clone(),
+            actual: actual_fingerprint,
+        });
+    }
+
+    Ok(nodes
+        .into_iter()
+        .zip(&clip.entries)
+        .map(|(node, entry)| {
+            let correction = UnitQuaternion::from_quaternion(Quaternion::new(
+                entry.correction[0],
+                entry.correction[1],
+                entry.correction[2],
+                entry.correction[3],
+            ));
+            let corrected_local_rotation =
+                apply_corrected_rotation(node.current_local_rotation, correction);
+            let corrected_vertices = node
+                .vertices
+                .iter()
+                .map(|vertex| correct_vertex_with_inverse(*vertex, correction))
+                .collect();
+            let corrected_local_matrix = apply_rotation_delta_to_local_matrix(
+                node.local_transform_matrix,
+                node.current_local_rotation,
+                corrected_local_rotation,
+            );
+
+            NodeCorrectionResult {
+                node_index: node.node_index,
+                node_name: node.node_name,
+                corrected_local_rotation,
+                corrected_vertices,
+                inverse_bind_matrix: Matrix4::from_column_slice(&entry.inverse_bind_matrix),
+                was_corrected: entry.was_corrected,
+                used_pseudo_inverse: entry.used_pseudo_inverse,
+                corrected_world_matrix: node.parent_world_matrix * corrected_local_matrix,
+            }
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,12 +891,14 @@ mod tests {
         let input = BoneCorrectionInput {
             current_local_rotation: UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4),
             target_t_pose_rotation: UnitQuaternion::identity(),
+            weight: 1.0,
+            max_angle: None,
             parent_world_matrix: Translation3::new(0.0, 1.0, 0.0).to_homogeneous(),
             local_transform_matrix: Translation3::new(2.0, 0.0, 0.0).to_homogeneous(),
             vertices: vec![Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)],
         };
 
-        let result = correct_bone_to_t_pose(input).expect("pipeline should succeed");
+        let result = correct_bone_to_t_pose(input, &CorrectionOptions::default()).expect("pipeline should succeed");
 
         let q = result.corrected_local_rotation.quaternion();
         assert!(q.w > 0.9999);
@@ -341,8 +921,11 @@ mod tests {
             NodeCorrectionInput {
                 node_index: 0,
                 node_name: "mShoulderLeft".to_string(),
+                parent_index: None,
                 current_local_rotation: UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4),
                 target_t_pose_rotation: Some(UnitQuaternion::identity()),
+                weight: 1.0,
+                max_angle: None,
                 parent_world_matrix: Translation3::new(0.0, 1.0, 0.0).to_homogeneous(),
                 local_transform_matrix: Translation3::new(2.0, 0.0, 0.0).to_homogeneous(),
                 vertices: vec![Vector3::new(1.0, 0.0, 0.0)],
@@ -350,15 +933,18 @@ mod tests {
             NodeCorrectionInput {
                 node_index: 1,
                 node_name: "mChest".to_string(),
+                parent_index: None,
                 current_local_rotation: UnitQuaternion::from_euler_angles(0.0, 0.0, 0.1),
                 target_t_pose_rotation: None,
+                weight: 1.0,
+                max_angle: None,
                 parent_world_matrix: Translation3::new(0.0, 1.2, 0.0).to_homogeneous(),
                 local_transform_matrix: Translation3::new(0.0, 0.3, 0.0).to_homogeneous(),
                 vertices: vec![Vector3::new(0.0, 1.0, 0.0)],
             },
         ];
 
-        let results = correct_nodes_to_t_pose(nodes).expect("node loop should succeed");
+        let results = correct_nodes_to_t_pose(nodes, &CorrectionOptions::default()).expect("node loop should succeed");
         assert_eq!(results.len(), 2);
 
         let shoulder = &results[0];
@@ -404,6 +990,7 @@ mod tests {
             ParsedNodeTransform {
                 node_index: 0,
                 node_name: "mShoulderLeft".to_string(),
+                parent_index: None,
                 current_local_rotation: UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4),
                 parent_world_matrix: Translation3::new(0.0, 1.0, 0.0).to_homogeneous(),
                 local_transform_matrix: Translation3::new(2.0, 0.0, 0.0).to_homogeneous(),
@@ -411,6 +998,7 @@ mod tests {
             ParsedNodeTransform {
                 node_index: 1,
                 node_name: "mHead".to_string(),
+                parent_index: None,
                 current_local_rotation: UnitQuaternion::identity(),
                 parent_world_matrix: Translation3::new(0.0, 1.5, 0.0).to_homogeneous(),
                 local_transform_matrix: Translation3::new(0.0, 0.2, 0.0).to_homogeneous(),
@@ -421,16 +1009,121 @@ mod tests {
             vertices: vec![Vector3::new(1.0, 0.0, 0.0)],
         }];
 
-        let inputs = build_node_correction_inputs(node_transforms, node_geometries, &targets);
+        let weights = HashMap::new();
+        let inputs =
+            build_node_correction_inputs(node_transforms, node_geometries, &targets, &weights);
         assert_eq!(inputs.len(), 2);
 
         assert!(inputs[0].target_t_pose_rotation.is_some());
         assert_eq!(inputs[0].vertices.len(), 1);
+        assert_eq!(inputs[0].weight, 1.0);
+        assert!(inputs[0].max_angle.is_none());
 
         assert!(inputs[1].target_t_pose_rotation.is_none());
         assert!(inputs[1].vertices.is_empty());
     }
 
+    #[test]
+    fn given_bone_weight_map_when_building_inputs_then_matching_bone_gets_its_weight() {
+        let targets = build_default_upper_limb_t_pose_targets();
+        let node_transforms = vec![ParsedNodeTransform {
+            node_index: 0,
+            node_name: "mShoulderLeft".to_string(),
+            parent_index: None,
+            current_local_rotation: UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4),
+            parent_world_matrix: Translation3::new(0.0, 1.0, 0.0).to_homogeneous(),
+            local_transform_matrix: Translation3::new(2.0, 0.0, 0.0).to_homogeneous(),
+        }];
+        let mut weights = HashMap::new();
+        weights.insert("mShoulderLeft".to_string(), 0.5);
+
+        let inputs =
+            build_node_correction_inputs(node_transforms, Vec::new(), &targets, &weights);
+
+        assert_eq!(inputs[0].weight, 0.5);
+    }
+
+    #[test]
+    fn given_partial_bone_weight_when_correcting_bone_then_rotation_is_only_partially_corrected() {
+        let current = UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4);
+        let input = BoneCorrectionInput {
+            current_local_rotation: current,
+            target_t_pose_rotation: UnitQuaternion::identity(),
+            weight: 0.5,
+            max_angle: None,
+            parent_world_matrix: Translation3::new(0.0, 1.0, 0.0).to_homogeneous(),
+            local_transform_matrix: Translation3::new(2.0, 0.0, 0.0).to_homogeneous(),
+            vertices: vec![],
+        };
+
+        let result = correct_bone_to_t_pose(input, &CorrectionOptions::default()).expect("pipeline should succeed");
+
+        let delta_from_current = result.corrected_local_rotation.rotation_to(&current);
+        let delta_from_target = result
+            .corrected_local_rotation
+            .rotation_to(&UnitQuaternion::identity());
+        assert!(delta_from_current.angle().abs() > 0.0001);
+        assert!(delta_from_target.angle().abs() > 0.0001);
+    }
+
+    #[test]
+    fn given_singular_bind_matrix_when_pseudo_inverse_disallowed_then_correction_fails() {
+        let input = BoneCorrectionInput {
+            current_local_rotation: UnitQuaternion::identity(),
+            target_t_pose_rotation: UnitQuaternion::identity(),
+            weight: 1.0,
+            max_angle: None,
+            parent_world_matrix: Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 1.0, 0.0)),
+            local_transform_matrix: Matrix4::identity(),
+            vertices: vec![],
+        };
+
+        assert!(correct_bone_to_t_pose(input, &CorrectionOptions::default()).is_none());
+    }
+
+    #[test]
+    fn given_singular_bind_matrix_when_pseudo_inverse_allowed_then_correction_succeeds_and_is_flagged()
+     {
+        let input = BoneCorrectionInput {
+            current_local_rotation: UnitQuaternion::identity(),
+            target_t_pose_rotation: UnitQuaternion::identity(),
+            weight: 1.0,
+            max_angle: None,
+            parent_world_matrix: Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 1.0, 0.0)),
+            local_transform_matrix: Matrix4::identity(),
+            vertices: vec![],
+        };
+        let options = CorrectionOptions {
+            allow_pseudo_inverse: true,
+            ..CorrectionOptions::default()
+        };
+
+        let result =
+            correct_bone_to_t_pose(input, &options).expect("pseudo-inverse fallback should succeed");
+
+        assert!(result.used_pseudo_inverse);
+    }
+
+    #[test]
+    fn given_zero_weight_target_when_running_batch_then_node_is_not_reported_as_corrected() {
+        let nodes = vec![NodeCorrectionInput {
+            node_index: 0,
+            node_name: "mShoulderLeft".to_string(),
+            parent_index: None,
+            current_local_rotation: UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4),
+            target_t_pose_rotation: Some(UnitQuaternion::identity()),
+            weight: 0.0,
+            max_angle: None,
+            parent_world_matrix: Translation3::new(0.0, 1.0, 0.0).to_homogeneous(),
+            local_transform_matrix: Translation3::new(2.0, 0.0, 0.0).to_homogeneous(),
+            vertices: vec![Vector3::new(1.0, 0.0, 0.0)],
+        }];
+
+        let results = correct_nodes_to_t_pose(nodes, &CorrectionOptions::default()).expect("node loop should succeed");
+
+        assert!(!results[0].was_corrected);
+    }
+
     #[test]
     fn given_non_invertible_bind_error_when_formatted_then_phase_is_included() {
         let error = PipelineError::NonInvertibleBindMatrix {
@@ -444,4 +1137,243 @@ mod tests {
         assert!(message.contains("7"));
         assert!(message.contains("mShoulderLeft"));
     }
+
+    #[test]
+    fn given_shoulder_elbow_chain_when_correcting_skeleton_then_elbow_world_matrix_reflects_parent_correction()
+     {
+        let nodes = vec![
+            NodeCorrectionInput {
+                node_index: 0,
+                node_name: "mShoulderLeft".to_string(),
+                parent_index: None,
+                current_local_rotation: UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4),
+                target_t_pose_rotation: Some(UnitQuaternion::identity()),
+                weight: 1.0,
+                max_angle: None,
+                parent_world_matrix: Matrix4::identity(),
+                // T * R with the same rotation as `current_local_rotation`,
+                // as a parsed glTF node's local matrix would carry it.
+                local_transform_matrix: Translation3::new(2.0, 0.0, 0.0).to_homogeneous()
+                    * UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4).to_homogeneous(),
+                vertices: vec![],
+            },
+            NodeCorrectionInput {
+                node_index: 1,
+                node_name: "mElbowLeft".to_string(),
+                parent_index: Some(0),
+                current_local_rotation: UnitQuaternion::identity(),
+                target_t_pose_rotation: None,
+                weight: 1.0,
+                max_angle: None,
+                // Stale on purpose: correct_skeleton_to_t_pose must ignore
+                // this in favor of the shoulder's corrected world matrix.
+                parent_world_matrix: Matrix4::identity(),
+                local_transform_matrix: Translation3::new(1.0, 0.0, 0.0).to_homogeneous(),
+                vertices: vec![],
+            },
+        ];
+
+        let results = correct_skeleton_to_t_pose(nodes, &CorrectionOptions::default())
+            .expect("skeleton batch should succeed");
+
+        let translation_of = |matrix: Matrix4<f32>| {
+            Vector3::new(matrix[(0, 3)], matrix[(1, 3)], matrix[(2, 3)])
+        };
+
+        let shoulder_translation = translation_of(results[0].corrected_world_matrix);
+        assert!((shoulder_translation - Vector3::new(2.0, 0.0, 0.0)).norm() < 0.0001);
+
+        let elbow_translation = translation_of(results[1].corrected_world_matrix);
+        assert!((elbow_translation - Vector3::new(3.0, 0.0, 0.0)).norm() < 0.0001);
+    }
+
+    #[test]
+    fn given_dangling_parent_index_when_correcting_skeleton_then_missing_parent_error_is_returned() {
+        let nodes = vec![NodeCorrectionInput {
+            node_index: 0,
+            node_name: "mElbowLeft".to_string(),
+            parent_index: Some(99),
+            current_local_rotation: UnitQuaternion::identity(),
+            target_t_pose_rotation: None,
+            weight: 1.0,
+            max_angle: None,
+            parent_world_matrix: Matrix4::identity(),
+            local_transform_matrix: Matrix4::identity(),
+            vertices: vec![],
+        }];
+
+        let error = correct_skeleton_to_t_pose(nodes, &CorrectionOptions::default())
+            .expect_err("dangling parent_index should be rejected");
+
+        assert_eq!(
+            error,
+            PipelineError::MissingParentNode {
+                node_index: 0,
+                node_name: "mElbowLeft".to_string(),
+                parent_index: 99,
+            }
+        );
+    }
+
+    #[test]
+    fn given_cyclic_parent_indices_when_correcting_skeleton_then_cyclic_hierarchy_error_is_returned() {
+        let nodes = vec![
+            NodeCorrectionInput {
+                node_index: 0,
+                node_name: "mShoulderLeft".to_string(),
+                parent_index: Some(1),
+                current_local_rotation: UnitQuaternion::identity(),
+                target_t_pose_rotation: None,
+                weight: 1.0,
+                max_angle: None,
+                parent_world_matrix: Matrix4::identity(),
+                local_transform_matrix: Matrix4::identity(),
+                vertices: vec![],
+            },
+            NodeCorrectionInput {
+                node_index: 1,
+                node_name: "mElbowLeft".to_string(),
+                parent_index: Some(0),
+                current_local_rotation: UnitQuaternion::identity(),
+                target_t_pose_rotation: None,
+                weight: 1.0,
+                max_angle: None,
+                parent_world_matrix: Matrix4::identity(),
+                local_transform_matrix: Matrix4::identity(),
+                vertices: vec![],
+            },
+        ];
+
+        let error = correct_skeleton_to_t_pose(nodes, &CorrectionOptions::default())
+            .expect_err("cyclic parent_index chain should be rejected");
+
+        assert!(matches!(error, PipelineError::CyclicHierarchy { .. }));
+    }
+
+    #[test]
+    fn given_left_named_bone_when_mirroring_across_sagittal_then_right_name_and_reflected_rotation_are_returned()
+     {
+        let rotation = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+
+        let (mirrored_name, mirrored_rotation) =
+            mirror_target_across_sagittal("mShoulderLeft", rotation)
+                .expect("left-named bone should mirror");
+
+        assert_eq!(mirrored_name, "mShoulderRight");
+        let q = rotation.quaternion();
+        let mirrored_q = mirrored_rotation.quaternion();
+        assert!((mirrored_q.w - q.w).abs() < 0.0001);
+        assert!((mirrored_q.i - q.i).abs() < 0.0001);
+        assert!((mirrored_q.j + q.j).abs() < 0.0001);
+        assert!((mirrored_q.k + q.k).abs() < 0.0001);
+    }
+
+    #[test]
+    fn given_lowercase_right_named_bone_when_mirroring_across_sagittal_then_left_name_is_returned() {
+        let (mirrored_name, _) =
+            mirror_target_across_sagittal("rightUpperArm", UnitQuaternion::identity())
+                .expect("right-named bone should mirror");
+
+        assert_eq!(mirrored_name, "leftUpperArm");
+    }
+
+    #[test]
+    fn given_unsided_bone_name_when_mirroring_across_sagittal_then_none_is_returned() {
+        assert!(mirror_target_across_sagittal("mChest", UnitQuaternion::identity()).is_none());
+    }
+
+    #[test]
+    fn given_one_sided_map_when_completing_symmetric_targets_then_opposite_side_is_synthesized() {
+        let mut targets = HashMap::new();
+        targets.insert(
+            "mShoulderLeft".to_string(),
+            UnitQuaternion::from_euler_angles(0.1, 0.0, 0.0),
+        );
+        targets.insert("mChest".to_string(), UnitQuaternion::identity());
+
+        let completed = complete_symmetric_targets(&targets);
+
+        assert!(completed.contains_key("mShoulderLeft"));
+        assert!(completed.contains_key("mShoulderRight"));
+        assert!(completed.contains_key("mChest"));
+        assert_eq!(completed.len(), 3);
+    }
+
+    #[test]
+    fn given_both_sides_already_present_when_completing_symmetric_targets_then_existing_values_are_kept()
+     {
+        let mut targets = HashMap::new();
+        let explicit_right = UnitQuaternion::from_euler_angles(0.0, 0.0, 0.5);
+        targets.insert(
+            "mShoulderLeft".to_string(),
+            UnitQuaternion::from_euler_angles(0.1, 0.0, 0.0),
+        );
+        targets.insert("mShoulderRight".to_string(), explicit_right);
+
+        let completed = complete_symmetric_targets(&targets);
+
+        let q = completed["mShoulderRight"].quaternion();
+        let expected = explicit_right.quaternion();
+        assert!((q - expected).norm() < 0.0001);
+    }
+
+    fn sample_shoulder_input() -> NodeCorrectionInput {
+        NodeCorrectionInput {
+            node_index: 0,
+            node_name: "mShoulderLeft".to_string(),
+            parent_index: None,
+            current_local_rotation: UnitQuaternion::from_euler_angles(0.0, 0.0, -0.4),
+            target_t_pose_rotation: Some(UnitQuaternion::identity()),
+            weight: 1.0,
+            max_angle: None,
+            parent_world_matrix: Translation3::new(0.0, 1.0, 0.0).to_homogeneous(),
+            local_transform_matrix: Translation3::new(2.0, 0.0, 0.0).to_homogeneous(),
+            vertices: vec![Vector3::new(1.0, 0.0, 0.0)],
+        }
+    }
+
+    #[test]
+    fn given_baked_clip_when_applied_to_same_skeleton_then_results_match_original_correction() {
+        let inputs = vec![sample_shoulder_input()];
+        let results = correct_nodes_to_t_pose(inputs.clone(), &CorrectionOptions::default())
+            .expect("node loop should succeed");
+
+        let clip = bake_correction_clip(&results, &inputs);
+        assert_eq!(clip.skeleton_fingerprint, vec!["mShoulderLeft".to_string()]);
+
+        let replayed_inputs = vec![NodeCorrectionInput {
+            // Different weight/target on purpose: the clip should reproduce
+            // the baked correction without recomputing from these.
+            weight: 0.0,
+            target_t_pose_rotation: None,
+            ..sample_shoulder_input()
+        }];
+
+        let replayed =
+            apply_correction_clip(&clip, replayed_inputs).expect("clip should apply cleanly");
+
+        let original_q = results[0].corrected_local_rotation.quaternion();
+        let replayed_q = replayed[0].corrected_local_rotation.quaternion();
+        assert!((original_q - replayed_q).norm() < 0.0001);
+        assert_eq!(replayed[0].was_corrected, results[0].was_corrected);
+        assert!((replayed[0].inverse_bind_matrix - results[0].inverse_bind_matrix).norm() < 0.0001);
+    }
+
+    #[test]
+    fn given_mismatched_skeleton_when_applying_clip_then_fingerprint_error_is_returned() {
+        let inputs = vec![sample_shoulder_input()];
+        let results = correct_nodes_to_t_pose(inputs.clone(), &CorrectionOptions::default())
+            .expect("node loop should succeed");
+        let clip = bake_correction_clip(&results, &inputs);
+
+        let mismatched_inputs = vec![NodeCorrectionInput {
+            node_name: "mShoulderRight".to_string(),
+            ..sample_shoulder_input()
+        }];
+
+        let error = apply_correction_clip(&clip, mismatched_inputs)
+            .expect_err("mismatched skeleton should be rejected");
+
+        assert!(matches!(error, PipelineError::SkeletonFingerprintMismatch { .. }));
+    }
 }
@@ -2,9 +2,21 @@ use std::{fs, path::Path};
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
+use crate::convert::BoneRemapRule;
 use crate::texture::ResizeInterpolation;
 
+/// Current [`ProjectSettings::schema_version`]. Bumped whenever a field is
+/// added/renamed in a way `serde`'s own `#[serde(default)]` can't paper
+/// over, with a matching entry appended to [`MIGRATIONS`].
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// Version recorded for project files saved before `schema_version` existed.
+fn legacy_schema_version() -> u32 {
+    0
+}
+
 /// Blink behavior settings used by lightweight face controls.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlinkSettings {
@@ -112,6 +124,19 @@ pub struct ProjectSettings {
     pub texture_resize_method: ResizeInterpolation,
     pub face: FaceSettings,
     pub fingers: FingerSettings,
+    /// User-configurable bone-remapping policy, persisted here and passed
+    /// through to [`crate::convert::ConvertOptions::bone_remap_rules`] when
+    /// a conversion is issued from the saved settings.
+    #[serde(default)]
+    pub bone_remap_rules: Vec<BoneRemapRule>,
+    /// Mirrors [`crate::convert::ConvertOptions::enable_texture_atlas`].
+    #[serde(default)]
+    pub enable_texture_atlas: bool,
+    /// Schema version this file was saved under. Missing entirely on files
+    /// saved before this field existed, which [`load_project_settings`]
+    /// treats as version 0 and upgrades via [`migrate_settings_json`].
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
 }
 
 impl Default for ProjectSettings {
@@ -125,28 +150,125 @@ impl Default for ProjectSettings {
             texture_resize_method: ResizeInterpolation::Bilinear,
             face: FaceSettings::default(),
             fingers: FingerSettings::default(),
+            bone_remap_rules: Vec::new(),
+            enable_texture_atlas: false,
+            schema_version: CURRENT_SCHEMA_VERSION,
         }
     }
 }
 
-/// Save project settings to a JSON file.
+/// Save project settings to a JSON file, always under the current schema
+/// version regardless of what `settings.schema_version` happened to be.
 pub fn save_project_settings(path: &Path, settings: &ProjectSettings) -> Result<()> {
-    let content = serde_json::to_string_pretty(settings)
+    let settings = ProjectSettings {
+        schema_version: CURRENT_SCHEMA_VERSION,
+        ..settings.clone()
+    };
+    let content = serde_json::to_string_pretty(&settings)
         .context("failed to serialize project settings as JSON")?;
     fs::write(path, content)
         .with_context(|| format!("failed to save project settings: {}", path.display()))?;
     Ok(())
 }
 
-/// Load project settings from a JSON file.
+/// Load project settings from a JSON file, migrating it up to
+/// [`CURRENT_SCHEMA_VERSION`] first so files saved by older builds don't
+/// fail to parse just because a field moved or was renamed.
 pub fn load_project_settings(path: &Path) -> Result<ProjectSettings> {
     let content = fs::read_to_string(path)
         .with_context(|| format!("failed to load project settings: {}", path.display()))?;
-    let settings: ProjectSettings =
+    let mut value: Value =
         serde_json::from_str(&content).context("failed to parse project settings JSON")?;
+    migrate_settings_json(&mut value);
+    let settings: ProjectSettings =
+        serde_json::from_value(value).context("failed to parse project settings JSON")?;
     Ok(settings)
 }
 
+/// Migration steps, indexed by the version they migrate *from*
+/// (`MIGRATIONS[0]` takes a version-0 document to version 1, and so on).
+const MIGRATIONS: &[fn(&mut Map<String, Value>)] = &[migrate_v0_to_v1];
+
+/// Upgrade a raw project settings document in place to
+/// [`CURRENT_SCHEMA_VERSION`] by running every migration step from its
+/// recorded `schema_version` (0 if absent) onward, then stamping the result
+/// with the current version.
+fn migrate_settings_json(value: &mut Value) {
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+
+    let mut version = object
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as usize;
+    while let Some(migration) = MIGRATIONS.get(version) {
+        migration(object);
+        version += 1;
+    }
+
+    object.insert(
+        "schema_version".to_string(),
+        Value::from(CURRENT_SCHEMA_VERSION),
+    );
+}
+
+/// Version 0 -> 1: early builds saved blink/lip-sync/eye-tracking fields
+/// flat at the document root; they were grouped under a nested `face` object
+/// once [`FaceSettings`] was introduced. Lift them into that shape so a
+/// project file saved before the grouping still loads with its tuned values
+/// intact instead of falling back to defaults.
+fn migrate_v0_to_v1(object: &mut Map<String, Value>) {
+    if object.contains_key("face") {
+        return;
+    }
+    let has_legacy_fields = ["blink_enabled", "lip_sync_enabled", "eye_tracking_camera_follow"]
+        .iter()
+        .any(|key| object.contains_key(*key));
+    if !has_legacy_fields {
+        return;
+    }
+
+    let blink = BlinkSettings {
+        enabled: take_bool(object, "blink_enabled").unwrap_or(true),
+        interval_sec: take_f32(object, "blink_interval_sec").unwrap_or(4.0),
+        close_duration_sec: take_f32(object, "blink_close_duration_sec").unwrap_or(0.15),
+        wink_enabled: take_bool(object, "blink_wink_enabled").unwrap_or(true),
+    };
+    let lip_sync = LipSyncSettings {
+        enabled: take_bool(object, "lip_sync_enabled").unwrap_or(false),
+        mode: take_string(object, "lip_sync_mode").unwrap_or_else(|| "chat".to_string()),
+        open_angle: take_f32(object, "lip_sync_open_angle").unwrap_or(0.5),
+        speed: take_f32(object, "lip_sync_speed").unwrap_or(0.5),
+    };
+    let eye_tracking = EyeTrackingSettings {
+        camera_follow: take_bool(object, "eye_tracking_camera_follow").unwrap_or(true),
+        random_look: take_bool(object, "eye_tracking_random_look").unwrap_or(true),
+        vertical_range_deg: take_f32(object, "eye_tracking_vertical_range_deg").unwrap_or(25.0),
+        horizontal_range_deg: take_f32(object, "eye_tracking_horizontal_range_deg").unwrap_or(40.0),
+        speed: take_f32(object, "eye_tracking_speed").unwrap_or(0.5),
+    };
+
+    let face = FaceSettings { blink, lip_sync, eye_tracking };
+    if let Ok(face_value) = serde_json::to_value(face) {
+        object.insert("face".to_string(), face_value);
+    }
+}
+
+fn take_bool(object: &mut Map<String, Value>, key: &str) -> Option<bool> {
+    object.remove(key).and_then(|value| value.as_bool())
+}
+
+fn take_f32(object: &mut Map<String, Value>, key: &str) -> Option<f32> {
+    object.remove(key).and_then(|value| value.as_f64()).map(|value| value as f32)
+}
+
+fn take_string(object: &mut Map<String, Value>, key: &str) -> Option<String> {
+    object
+        .remove(key)
+        .and_then(|value| value.as_str().map(str::to_string))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +279,33 @@ mod tests {
         let json = serde_json::to_string(&settings).expect("serialize settings");
         assert!(json.contains("target_height_cm"));
     }
+
+    #[test]
+    fn given_legacy_flat_face_fields_when_migrating_then_they_are_nested_under_face() {
+        let mut value = serde_json::json!({
+            "target_height_cm": 200.0,
+            "manual_scale": 1.0,
+            "blink_enabled": false,
+            "blink_interval_sec": 6.0,
+            "lip_sync_enabled": true,
+            "lip_sync_mode": "mic",
+            "eye_tracking_camera_follow": false,
+        });
+
+        migrate_settings_json(&mut value);
+
+        assert_eq!(value["schema_version"], Value::from(CURRENT_SCHEMA_VERSION));
+        assert_eq!(value["face"]["blink"]["enabled"], Value::from(false));
+        assert_eq!(value["face"]["blink"]["interval_sec"], Value::from(6.0));
+        assert_eq!(value["face"]["lip_sync"]["mode"], Value::from("mic"));
+        assert_eq!(value["face"]["eye_tracking"]["camera_follow"], Value::from(false));
+        assert!(value.get("blink_enabled").is_none());
+    }
+
+    #[test]
+    fn given_document_missing_schema_version_when_migrating_then_current_version_is_stamped() {
+        let mut value = serde_json::json!({ "target_height_cm": 180.0 });
+        migrate_settings_json(&mut value);
+        assert_eq!(value["schema_version"], Value::from(CURRENT_SCHEMA_VERSION));
+    }
 }
@@ -0,0 +1,127 @@
+use image::{DynamicImage, imageops::FilterType};
+
+/// Interpolation method used for texture resizing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeInterpolation {
+    /// Fast nearest-neighbor interpolation.
+    Nearest,
+    /// Bilinear interpolation (`image`'s `Triangle` filter).
+    #[default]
+    Bilinear,
+    /// Bicubic interpolation (`image`'s `CatmullRom` filter).
+    Bicubic,
+    /// Gaussian interpolation.
+    Gaussian,
+    /// Lanczos (windowed sinc) interpolation.
+    Lanczos3,
+}
+
+/// Parse a `--resize-method`-style CLI/config value into a
+/// [`ResizeInterpolation`], matching variant names case-insensitively.
+pub fn parse_resize_method(value: &str) -> Result<ResizeInterpolation, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "nearest" => Ok(ResizeInterpolation::Nearest),
+        "bilinear" => Ok(ResizeInterpolation::Bilinear),
+        "bicubic" => Ok(ResizeInterpolation::Bicubic),
+        "gaussian" => Ok(ResizeInterpolation::Gaussian),
+        "lanczos3" => Ok(ResizeInterpolation::Lanczos3),
+        other => Err(format!(
+            "invalid resize method '{other}' (expected one of: nearest, bilinear, bicubic, gaussian, lanczos3)"
+        )),
+    }
+}
+
+impl From<ResizeInterpolation> for FilterType {
+    fn from(value: ResizeInterpolation) -> Self {
+        match value {
+            ResizeInterpolation::Nearest => FilterType::Nearest,
+            ResizeInterpolation::Bilinear => FilterType::Triangle,
+            ResizeInterpolation::Bicubic => FilterType::CatmullRom,
+            ResizeInterpolation::Gaussian => FilterType::Gaussian,
+            ResizeInterpolation::Lanczos3 => FilterType::Lanczos3,
+        }
+    }
+}
+
+/// Resize image to fit within the specified max size while preserving aspect ratio.
+///
+/// If the image is already smaller than both limits, this function returns an
+/// unchanged clone and does not upscale.
+///
+/// With the `gpu` feature enabled, this tries a wgpu compute-shader resize
+/// first (see [`crate::gpu_resize`]) and transparently falls back to the CPU
+/// filters below whenever no adapter is available, so callers always get a
+/// correctly-sized image either way.
+pub fn resize_texture_to_max(
+    image: &DynamicImage,
+    max_width: u32,
+    max_height: u32,
+    interpolation: ResizeInterpolation,
+) -> DynamicImage {
+    if image.width() <= max_width && image.height() <= max_height {
+        return image.clone();
+    }
+
+    let (dst_width, dst_height) = scaled_dimensions(image.width(), image.height(), max_width, max_height);
+
+    #[cfg(feature = "gpu")]
+    if let Some(resized) = crate::gpu_resize::try_gpu_resize(image, dst_width, dst_height, interpolation) {
+        return resized;
+    }
+
+    image.resize(max_width, max_height, interpolation.into())
+}
+
+/// The `(width, height)` that `image::resize` would produce for these
+/// bounds, computed up front so the GPU path can allocate its destination
+/// texture at the exact target size.
+fn scaled_dimensions(width: u32, height: u32, max_width: u32, max_height: u32) -> (u32, u32) {
+    let width_ratio = max_width as f64 / width as f64;
+    let height_ratio = max_height as f64 / height as f64;
+    let ratio = width_ratio.min(height_ratio);
+
+    (
+        (width as f64 * ratio).round().max(1.0) as u32,
+        (height as f64 * ratio).round().max(1.0) as u32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, RgbaImage};
+
+    #[test]
+    fn given_large_texture_when_resize_with_lanczos_then_image_fits_bounds() {
+        let source = DynamicImage::ImageRgba8(RgbaImage::new(2048, 1024));
+
+        let resized = resize_texture_to_max(&source, 1024, 1024, ResizeInterpolation::Lanczos3);
+
+        assert_eq!(resized.dimensions(), (1024, 512));
+    }
+
+    #[test]
+    fn given_small_texture_when_resize_then_original_size_is_kept() {
+        let source = DynamicImage::ImageRgba8(RgbaImage::new(512, 512));
+
+        let resized = resize_texture_to_max(&source, 1024, 1024, ResizeInterpolation::Bilinear);
+
+        assert_eq!(resized.dimensions(), (512, 512));
+    }
+
+    #[test]
+    fn given_known_names_when_parsing_resize_method_then_matching_variant_is_returned() {
+        assert_eq!(parse_resize_method("Bilinear"), Ok(ResizeInterpolation::Bilinear));
+        assert_eq!(parse_resize_method("LANCZOS3"), Ok(ResizeInterpolation::Lanczos3));
+        assert!(parse_resize_method("sinc").is_err());
+    }
+
+    #[test]
+    fn given_interpolation_enum_when_converting_then_filter_type_matches() {
+        assert_eq!(FilterType::from(ResizeInterpolation::Nearest), FilterType::Nearest);
+        assert_eq!(FilterType::from(ResizeInterpolation::Bilinear), FilterType::Triangle);
+        assert_eq!(FilterType::from(ResizeInterpolation::Bicubic), FilterType::CatmullRom);
+        assert_eq!(FilterType::from(ResizeInterpolation::Gaussian), FilterType::Gaussian);
+        assert_eq!(FilterType::from(ResizeInterpolation::Lanczos3), FilterType::Lanczos3);
+    }
+}